@@ -0,0 +1,354 @@
+/*
+==================================================================================
+| This module reads animated images structure (GIF, APNG, animated WebP): frames |
+| count, loop count, total duration and every frame delay, dimensions, offset,   |
+| disposal and blend modes. Structure is read from container blocks (GIF blocks, |
+| APNG acTL/fcTL chunks, WebP ANIM/ANMF chunks), frames are decoded only for     |
+| extraction (composited full canvas frames, as image viewers show them).        |
+| {Enums:}                                                                       |
+| 1) FrameSelection - animation frames selected for extraction                   |
+| {Functions:}                                                                   |
+| 1) skip_gif_sub_blocks() - skips GIF data sub-blocks                           |
+| 2) get_gif_animation_info() - reads GIF frames from GIF blocks                 |
+| 3) get_apng_animation_info() - reads APNG frames from acTL and fcTL chunks     |
+| 4) get_webp_animation_info() - reads WebP frames from ANIM and ANMF chunks     |
+| 5) get_disposal_name() - gets frame disposal mode name                         |
+| 6) get_file_animation_info() - reads image file animation info                 |
+| 6.1) get_animation_info_from_data() - reads animation info from image bytes    |
+| 7) extract_animation_frames() - decodes selected animation frames             |
+==================================================================================
+*/
+
+use image::{ AnimationDecoder, Frames, ImageError, RgbaImage };
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::image_analysis::image_manager::check_image_file;
+use crate::image_analysis::format_detector::ImageContainer;
+use crate::image_analysis::container_reader::*;
+use crate::image_analysis::image_reports::*;
+
+//GIF blocks introducers
+const GIF_EXTENSION_INTRODUCER: u8 = 0x21;
+const GIF_IMAGE_DESCRIPTOR: u8 = 0x2C;
+const GIF_TRAILER: u8 = 0x3B;
+
+//GIF extensions labels (graphic control extension -> frame delay and disposal, application extension -> loop count)
+const GIF_GRAPHIC_CONTROL_LABEL: u8 = 0xF9;
+const GIF_APPLICATION_LABEL: u8 = 0xFF;
+
+//application extensions identifiers with loop count
+const GIF_LOOP_IDENTIFIERS: &[&[u8]] = &[b"NETSCAPE2.0", b"ANIMEXTS1.0"];
+
+//WebP VP8X header animation flag
+const WEBP_ANIMATION_FLAG: u8 = 0x02;
+
+//animation frames selected for extraction
+pub enum FrameSelection {
+    All,
+    Numbers(Vec<usize>) //frames numbers (from 1), sorted without repeats
+}
+
+//skips GIF data sub-blocks (size byte, data), returns position after blocks terminator (None -> file is truncated)
+fn skip_gif_sub_blocks(
+    data: &[u8],
+    mut position: usize
+) -> Option<usize> {
+    loop {
+        let block_size = *data.get(position)? as usize;
+
+        position += 1 + block_size;
+
+        if block_size == 0 {
+            return Some(position)
+        }
+    }
+}
+
+//reads GIF frames from GIF blocks (None -> GIF has one frame), truncated file gives frames read before break
+fn get_gif_animation_info(data: &[u8]) -> Option<AnimationInfo> {
+    if !data.starts_with(b"GIF87a") && !data.starts_with(b"GIF89a") {
+        return None
+    }
+
+    let mut frames = Vec::new();
+    let mut loop_count = 1; //animation without loop extension is played once
+    let mut frame_control = (0, 0); //next frame delay (in 1/100 s) and disposal method
+
+    //header, logical screen descriptor and global color table (if flag is set)
+    let screen_flags = *data.get(10)?;
+    let mut position = 13;
+
+    if screen_flags & 0x80 != 0 {
+        position += 3 * (2 << (screen_flags & 0x07));
+    }
+
+    while let Some(introducer) = data.get(position) {
+        match *introducer {
+            GIF_EXTENSION_INTRODUCER => {
+                let label = data.get(position + 1).copied();
+                let block = data.get(position + 2..).unwrap_or_default();
+
+                if label == Some(GIF_GRAPHIC_CONTROL_LABEL) && block.len() >= 5 {
+                    //block size, packed fields (disposal in bits 2-4), delay
+                    frame_control = (u16::from_le_bytes([block[2], block[3]]), (block[1] >> 2) & 0x07);
+                } else if label == Some(GIF_APPLICATION_LABEL) && block.len() >= 17 && GIF_LOOP_IDENTIFIERS.contains(&&block[1..12]) && block[13] == 1 {
+                    //loop count means repeats after first play (0 -> infinite)
+                    let repeats = u16::from_le_bytes([block[14], block[15]]) as u32;
+
+                    loop_count = if repeats == 0 { 0 } else { repeats + 1 };
+                }
+
+                let Some(next_position) = skip_gif_sub_blocks(data, position + 2) else { break };
+                position = next_position;
+            },
+            GIF_IMAGE_DESCRIPTOR => {
+                let Some(descriptor) = data.get(position + 1..position + 10) else { break };
+                let read_u16 = |offset: usize| u16::from_le_bytes([descriptor[offset], descriptor[offset + 1]]) as u32;
+
+                let (delay, disposal) = frame_control;
+
+                frames.push(
+                    AnimationFrameInfo {
+                        number: frames.len() + 1,
+                        width: read_u16(4),
+                        height: read_u16(6),
+                        x_offset: read_u16(0),
+                        y_offset: read_u16(2),
+                        delay_ms: delay as u32 * 10,
+                        disposal: get_disposal_name(ImageContainer::Gif, disposal),
+                        blend: "over".to_string() //GIF frames are always drawn over canvas (transparent pixels keep canvas)
+                    }
+                );
+
+                frame_control = (0, 0);
+
+                //local color table (if flag is set), LZW minimum code size, image data sub-blocks
+                let image_flags = descriptor[8];
+                let mut image_data_position = position + 10;
+
+                if image_flags & 0x80 != 0 {
+                    image_data_position += 3 * (2 << (image_flags & 0x07));
+                }
+
+                let Some(next_position) = skip_gif_sub_blocks(data, image_data_position + 1) else { break };
+                position = next_position;
+            },
+            GIF_TRAILER => break,
+            _ => break //broken block
+        }
+    }
+
+    if frames.len() < 2 {
+        return None
+    }
+
+    return Some(
+        AnimationInfo {
+            frames_count: frames.len(),
+            loop_count,
+            duration_ms: frames.iter().map(|frame| frame.delay_ms as u64).sum(),
+            frames
+        }
+    )
+}
+
+//reads APNG frames from acTL (frames count, plays count) and fcTL (frame control) chunks (None -> PNG isn't animated)
+fn get_apng_animation_info(data: &[u8]) -> Option<AnimationInfo> {
+    let chunks = get_png_chunks(data);
+
+    let (_, animation_control) = chunks.iter().find(|(chunk_type, _)| chunk_type == b"acTL")?;
+    let animation_control = data.get(animation_control.clone())?;
+
+    //acTL: frames count, plays count (0 -> infinite)
+    let loop_count = u32::from_be_bytes(animation_control.get(4..8)?.try_into().ok()?);
+
+    let mut frames = Vec::new();
+
+    for (_, payload) in chunks.iter().filter(|(chunk_type, _)| chunk_type == b"fcTL") {
+        //fcTL: sequence number, width, height, x offset, y offset, delay numerator and denominator, dispose and blend operations
+        let Some(frame_control) = data.get(payload.clone()).filter(|frame_control| frame_control.len() >= 26) else { continue };
+        let read_u32 = |offset: usize| u32::from_be_bytes([frame_control[offset], frame_control[offset + 1], frame_control[offset + 2], frame_control[offset + 3]]);
+
+        let delay_numerator = u16::from_be_bytes([frame_control[20], frame_control[21]]) as u32;
+        let delay_denominator = match u16::from_be_bytes([frame_control[22], frame_control[23]]) {
+            0 => 100, //0 denominator means 1/100 s
+            denominator => denominator as u32
+        };
+
+        frames.push(
+            AnimationFrameInfo {
+                number: frames.len() + 1,
+                width: read_u32(4),
+                height: read_u32(8),
+                x_offset: read_u32(12),
+                y_offset: read_u32(16),
+                delay_ms: delay_numerator * 1000 / delay_denominator,
+                disposal: get_disposal_name(ImageContainer::Png, frame_control[24]),
+                blend: if frame_control[25] == 1 { "over" } else { "source" }.to_string()
+            }
+        );
+    }
+
+    return Some(
+        AnimationInfo {
+            frames_count: frames.len(),
+            loop_count,
+            duration_ms: frames.iter().map(|frame| frame.delay_ms as u64).sum(),
+            frames
+        }
+    )
+}
+
+//reads WebP frames from ANIM (loop count) and ANMF (frame) chunks (None -> WebP isn't animated)
+fn get_webp_animation_info(data: &[u8]) -> Option<AnimationInfo> {
+    let chunks = get_webp_chunks(data);
+
+    //VP8X: flags, reserved bytes, canvas size
+    let (_, header) = chunks.first().filter(|(fourcc, _)| fourcc == b"VP8X")?;
+
+    if data.get(header.start)? & WEBP_ANIMATION_FLAG == 0 {
+        return None
+    }
+
+    //ANIM: background color, loop count (0 -> infinite)
+    let loop_count = chunks
+        .iter()
+        .find(|(fourcc, _)| fourcc == b"ANIM")
+        .and_then(|(_, payload)| data.get(payload.start + 4..payload.start + 6))
+        .map(|loop_count| u16::from_le_bytes([loop_count[0], loop_count[1]]) as u32)
+        .unwrap_or(0);
+
+    let mut frames = Vec::new();
+
+    for (_, payload) in chunks.iter().filter(|(fourcc, _)| fourcc == b"ANMF") {
+        //ANMF: x offset / 2, y offset / 2, width - 1, height - 1, duration (24-bit numbers), flags (bit 1 -> don't blend, bit 0 -> dispose)
+        let Some(frame_header) = data.get(payload.start..payload.start + 16) else { continue };
+        let read_u24 = |offset: usize| u32::from_le_bytes([frame_header[offset], frame_header[offset + 1], frame_header[offset + 2], 0]);
+
+        let flags = frame_header[15];
+
+        frames.push(
+            AnimationFrameInfo {
+                number: frames.len() + 1,
+                width: read_u24(6) + 1,
+                height: read_u24(9) + 1,
+                x_offset: read_u24(0) * 2,
+                y_offset: read_u24(3) * 2,
+                delay_ms: read_u24(12),
+                disposal: get_disposal_name(ImageContainer::WebP, flags & 0x01),
+                blend: if flags & 0x02 != 0 { "source" } else { "over" }.to_string()
+            }
+        );
+    }
+
+    if frames.is_empty() {
+        return None
+    }
+
+    return Some(
+        AnimationInfo {
+            frames_count: frames.len(),
+            loop_count,
+            duration_ms: frames.iter().map(|frame| frame.delay_ms as u64).sum(),
+            frames
+        }
+    )
+}
+
+//gets frame disposal mode name (what happens with frame area before next frame is drawn)
+fn get_disposal_name(
+    container: ImageContainer,
+    disposal: u8
+) -> String {
+    let name = match (container, disposal) {
+        (ImageContainer::Gif, 0) => "unspecified",
+        (ImageContainer::Gif, 1) | (ImageContainer::Png, 0) | (ImageContainer::WebP, 0) => "none",
+        (ImageContainer::Gif, 2) | (ImageContainer::Png, 1) | (ImageContainer::WebP, 1) => "background",
+        (ImageContainer::Gif, 3) | (ImageContainer::Png, 2) => "previous",
+        _ => return format!("unknown ({})", disposal)
+    };
+
+    return name.to_string()
+}
+
+//reads image file animation info (None -> file can't be read or image isn't animated)
+pub(crate) fn get_file_animation_info(
+    path: &Path,
+    container: Option<ImageContainer>
+) -> Option<AnimationInfo> {
+    let data = std::fs::read(path).ok()?;
+
+    return get_animation_info_from_data(&data, container?)
+}
+
+//reads animation info from already read image file bytes (None -> image isn't animated)
+pub(crate) fn get_animation_info_from_data(
+    data: &[u8],
+    container: ImageContainer
+) -> Option<AnimationInfo> {
+    match container {
+        ImageContainer::Gif => get_gif_animation_info(data),
+        ImageContainer::Png => get_apng_animation_info(data),
+        ImageContainer::WebP => get_webp_animation_info(data),
+        _ => None
+    }
+}
+
+//decodes selected animation frames (composited full canvas frames) and passes them to save_frame with frame number
+//returns extracted frames count
+pub fn extract_animation_frames(
+    path: String,
+    selection: &FrameSelection,
+    mut save_frame: impl FnMut(usize, RgbaImage) -> Result<(), ImageManagerError>
+) -> Result<usize, ImageManagerError> {
+    let (file_name, _, container) = check_image_file(&path)?;
+    let image_path = Path::new(path.trim());
+
+    let Some(animation_info) = get_file_animation_info(image_path, container) else {
+        return Err(ImageManagerError::NotAnimated { file_name })
+    };
+
+    //checking selected frames numbers before decoding
+    let last_frame_number = match selection {
+        FrameSelection::All => animation_info.frames_count,
+        FrameSelection::Numbers(numbers) => *numbers.last().unwrap_or(&0)
+    };
+
+    if last_frame_number > animation_info.frames_count {
+        return Err(ImageManagerError::FrameOutOfRange { frame: last_frame_number, frames_count: animation_info.frames_count })
+    }
+
+    let open_error = |error: ImageError| ImageManagerError::ImageOpen { path: path.clone(), error };
+
+    let reader = BufReader::new(File::open(image_path).map_err(|error| open_error(ImageError::IoError(error)))?);
+
+    let frames: Frames = match container {
+        Some(ImageContainer::Gif) => GifDecoder::new(reader).map_err(open_error)?.into_frames(),
+        Some(ImageContainer::Png) => PngDecoder::new(reader).and_then(|decoder| decoder.apng()).map_err(open_error)?.into_frames(),
+        _ => WebPDecoder::new(reader).map_err(open_error)?.into_frames()
+    };
+
+    let mut extracted_frames_count = 0;
+
+    for (index, frame) in frames.take(last_frame_number).enumerate() {
+        let frame_number = index + 1;
+        let frame = frame.map_err(open_error)?;
+
+        if let FrameSelection::Numbers(numbers) = selection {
+            if numbers.binary_search(&frame_number).is_err() {
+                continue
+            }
+        }
+
+        save_frame(frame_number, frame.into_buffer())?;
+        extracted_frames_count += 1;
+    }
+
+    return Ok(extracted_frames_count)
+}
@@ -0,0 +1,348 @@
+/*
+==================================================================================
+| This module converts and resizes all matching images in directory tree. Every  |
+| image is saved to output directory with same relative path (mirrored tree),    |
+| output images, which are newer than source images, can be skipped. Images are  |
+| resized to fit in size (aspect ratio is kept), to fill size (cropped) or to    |
+| exact size (stretched) with chosen resampling filter.                          |
+| used libs: [image-resizing and encoding, walkdir-for directory crawling]       |
+| {Structs:}                                                                     |
+| 1) ResizeOptions - resize size, mode and filter                                |
+| 2) BatchOptions - batch target format, resizing, quality and skip options      |
+| {Enums:}                                                                       |
+| 1) ResizeMode - resize modes (fit, fill, exact)                                |
+| 2) ResizeFilter - resampling filters                                           |
+| {Functions:}                                                                   |
+| 1) resize_image() - resizes image with resize options                          |
+| 2) get_output_path() - builds image output path in mirrored directory tree     |
+| 3) is_output_up_to_date() - checks if output image is newer than source image  |
+| 4) convert_batch_image() - converts and saves one batch image                  |
+| 5) batch_convert_images() - converts and resizes all images in directory tree  |
+==================================================================================
+*/
+
+use image::DynamicImage;
+use image::imageops::FilterType;
+use serde::Serialize;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use crate::image_analysis::image_manager::*;
+use crate::image_analysis::image_converter::{ encode_image, ConversionFormat };
+use crate::image_analysis::format_detector::ImageContainer;
+use crate::image_analysis::image_reports::*;
+
+//images formats, which are converted by default (formats, which can be decoded)
+pub const DEFAULT_BATCH_FORMATS: &[&str; 7] = &[
+    "png", "jpg", "gif", "webp", "tiff", "bmp", "ico"
+];
+
+//resize modes
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ResizeMode {
+    Fit, //image fits in size, aspect ratio is kept
+    Fill, //image fills size, aspect ratio is kept, image is cropped
+    Exact //image is stretched to size
+}
+
+impl ResizeMode {
+    //returns resize mode name (same as in JSON output)
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResizeMode::Fit => "fit",
+            ResizeMode::Fill => "fill",
+            ResizeMode::Exact => "exact"
+        }
+    }
+
+    //parses resize mode name
+    pub fn from_name(name: &str) -> Option<ResizeMode> {
+        match name.trim().to_lowercase().as_str() {
+            "fit" => Some(ResizeMode::Fit),
+            "fill" => Some(ResizeMode::Fill),
+            "exact" => Some(ResizeMode::Exact),
+            _ => None
+        }
+    }
+}
+
+//resampling filters (from the fastest to the sharpest)
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ResizeFilter {
+    Nearest,
+    Triangle,
+    CatmullRom,
+    Gaussian,
+    Lanczos
+}
+
+impl ResizeFilter {
+    //returns resampling filter name (same as in JSON output)
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResizeFilter::Nearest => "nearest",
+            ResizeFilter::Triangle => "triangle",
+            ResizeFilter::CatmullRom => "catmullrom",
+            ResizeFilter::Gaussian => "gaussian",
+            ResizeFilter::Lanczos => "lanczos"
+        }
+    }
+
+    //parses resampling filter name
+    pub fn from_name(name: &str) -> Option<ResizeFilter> {
+        match name.trim().to_lowercase().as_str() {
+            "nearest" => Some(ResizeFilter::Nearest),
+            "triangle" | "linear" => Some(ResizeFilter::Triangle),
+            "catmullrom" | "cubic" => Some(ResizeFilter::CatmullRom),
+            "gaussian" => Some(ResizeFilter::Gaussian),
+            "lanczos" => Some(ResizeFilter::Lanczos),
+            _ => None
+        }
+    }
+
+    //returns image library filter type
+    fn filter_type(&self) -> FilterType {
+        match self {
+            ResizeFilter::Nearest => FilterType::Nearest,
+            ResizeFilter::Triangle => FilterType::Triangle,
+            ResizeFilter::CatmullRom => FilterType::CatmullRom,
+            ResizeFilter::Gaussian => FilterType::Gaussian,
+            ResizeFilter::Lanczos => FilterType::Lanczos3
+        }
+    }
+}
+
+//resize size, mode and filter
+#[derive(Serialize, Clone, Copy)]
+pub struct ResizeOptions {
+    pub width: u32,
+    pub height: u32,
+    pub mode: ResizeMode,
+    pub filter: ResizeFilter
+}
+
+//batch target format, resizing, quality and skip options
+pub struct BatchOptions {
+    pub format: Option<ConversionFormat>, //None -> images keep their formats
+    pub resize: Option<ResizeOptions>, //None -> images aren't resized
+    pub quality: Option<u8>, //JPEG and AVIF quality 1-100 (None -> default quality)
+    pub extensions: Vec<String>, //converted images extensions (empty -> DEFAULT_BATCH_FORMATS)
+    pub skip_up_to_date: bool //skip images, which output image is newer than source image
+}
+
+//resizes image with resize options
+fn resize_image(
+    image: DynamicImage,
+    resize: &ResizeOptions
+) -> DynamicImage {
+    let filter = resize.filter.filter_type();
+
+    match resize.mode {
+        ResizeMode::Fit => image.resize(resize.width, resize.height, filter),
+        ResizeMode::Fill => image.resize_to_fill(resize.width, resize.height, filter),
+        ResizeMode::Exact => image.resize_exact(resize.width, resize.height, filter)
+    }
+}
+
+//builds image output path in mirrored directory tree (input/a/photo.png -> output/a/photo.webp)
+//target format None -> source extension is kept
+fn get_output_path(
+    source_path: &Path,
+    input_root: &Path,
+    output_root: &Path,
+    format: Option<ConversionFormat>
+) -> PathBuf {
+    let relative_path = source_path.strip_prefix(input_root).unwrap_or(source_path);
+    let output_path = output_root.join(relative_path);
+
+    match format {
+        Some(format) => output_path.with_extension(format.extension()),
+        None => output_path
+    }
+}
+
+//checks if output image exists and is newer than source image (or same age)
+fn is_output_up_to_date(
+    source_path: &Path,
+    output_path: &Path
+) -> bool {
+    let modified_time = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+
+    match (modified_time(source_path), modified_time(output_path)) {
+        (Some(source_time), Some(output_time)) => output_time >= source_time,
+        _ => false
+    }
+}
+
+//converts one batch image and saves it to output path, returns converted image size and dimensions
+fn convert_batch_image(
+    source_path: &Path,
+    output_path: &Path,
+    container: Option<ImageContainer>,
+    options: &BatchOptions
+) -> Result<(u64, u32, u32), String> {
+    //image keeps its format, if target format isn't set
+    let format = match options.format {
+        Some(format) => format,
+        None => container
+            .and_then(|container| ConversionFormat::from_name(container.name()))
+            .ok_or_else(|| format!("image format ({}) can't be written, set target format", container.map_or("unknown", |container| container.name())))?
+    };
+
+    let mut image = load_image(&source_path.to_string_lossy())
+        .map_err(|error| ImageManagerError::ImageOpen { path: source_path.to_string_lossy().to_string(), error }.to_string())?;
+
+    if let Some(resize) = &options.resize {
+        image = resize_image(image, resize);
+    }
+
+    let (width, height) = (image.width(), image.height());
+
+    //quality is ignored by lossless formats
+    let quality = format.default_quality().map(|default_quality| options.quality.unwrap_or(default_quality));
+    let converted_data = encode_image(image, format, quality.unwrap_or(100)).map_err(|error| error.to_string())?;
+
+    //mirrored tree directories are created for every image
+    let write_result = match output_path.parent() {
+        Some(parent) => fs::create_dir_all(parent).and_then(|_| fs::write(output_path, &converted_data)),
+        None => fs::write(output_path, &converted_data)
+    };
+
+    if let Err(error) = write_result {
+        return Err(ImageManagerError::OutputFile(error).to_string())
+    }
+
+    return Ok((converted_data.len() as u64, width, height))
+}
+
+//converts and resizes all matching images in input directory tree and saves them to output directory with same relative paths
+//scan options roots are ignored (input directory is scanned), output directory inside input directory isn't scanned
+//on_progress is called before every image processing
+pub fn batch_convert_images(
+    input_dir: &Path,
+    output_dir: &Path,
+    options: &BatchOptions,
+    scan_options: &ScanOptions,
+    mut on_progress: impl FnMut(&BatchProgress)
+) -> Result<BatchReport, ImageManagerError> {
+    let extensions: Vec<String> = if options.extensions.is_empty() {
+        DEFAULT_BATCH_FORMATS.iter().map(|extension| extension.to_string()).collect()
+    } else {
+        options.extensions.clone()
+    };
+
+    let batch_formats = get_scan_formats(&extensions)?;
+
+    let input_root = match input_dir.canonicalize() {
+        Ok(path) if path.is_dir() => path,
+        Ok(path) => return Err(
+            ImageManagerError::ScanRoot {
+                path,
+                error: std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a directory")
+            }
+        ),
+        Err(error) => return Err(ImageManagerError::ScanRoot { path: input_dir.to_path_buf(), error })
+    };
+
+    fs::create_dir_all(output_dir).map_err(ImageManagerError::OutputFile)?;
+    let output_root = output_dir.canonicalize().map_err(ImageManagerError::OutputFile)?;
+
+    //images, which keep their format, would overwrite source images
+    if output_root == input_root {
+        return Err(ImageManagerError::BatchOutputDir { path: output_root })
+    }
+
+    //collecting matching images first (progress bar needs images count)
+    let mut images: Vec<(PathBuf, Option<ImageContainer>, u64)> = walk_scan_root(&input_root, scan_options)
+        .filter(|entry| !entry.path().starts_with(&output_root))
+        .filter_map(|entry| {
+            let file_format = detect_scanned_file_format(entry.path())?;
+
+            if !file_format.format.is_some_and(|format| batch_formats.contains(&format)) {
+                return None
+            }
+
+            let file_size = entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+
+            Some((entry.path().to_path_buf(), file_format.container, file_size))
+        })
+        .collect();
+
+    images.sort_by(|(first_path, _, _), (second_path, _, _)| first_path.cmp(second_path));
+
+    let mut files = Vec::new();
+    let mut written_paths: HashMap<PathBuf, PathBuf> = HashMap::new(); //output path -> source path
+
+    for (image_index, (source_path, container, source_size)) in images.iter().enumerate() {
+        on_progress(
+            &BatchProgress {
+                processed_images: image_index,
+                images_count: images.len(),
+                current_file: source_path
+            }
+        );
+
+        let output_path = get_output_path(source_path, &input_root, &output_root, options.format);
+
+        let mut file_info = BatchFileInfo {
+            source_path: source_path.clone(),
+            output_path: output_path.clone(),
+            status: BatchFileStatus::Failed,
+            source_size: *source_size,
+            output_size: None,
+            width: None,
+            height: None,
+            error: None
+        };
+
+        //photo.png and photo.jpg can't be both converted to photo.webp
+        if let Some(first_source_path) = written_paths.get(&output_path) {
+            file_info.error = Some(format!("output file is already written from [{}]", first_source_path.display()));
+        } else if options.skip_up_to_date && is_output_up_to_date(source_path, &output_path) {
+            file_info.status = BatchFileStatus::Skipped;
+        } else {
+            match convert_batch_image(source_path, &output_path, *container, options) {
+                Ok((output_size, width, height)) => {
+                    file_info.status = BatchFileStatus::Converted;
+                    file_info.output_size = Some(output_size);
+                    file_info.width = Some(width);
+                    file_info.height = Some(height);
+                },
+                Err(error) => file_info.error = Some(error)
+            }
+        }
+
+        if file_info.status != BatchFileStatus::Failed {
+            written_paths.insert(output_path, source_path.clone());
+        }
+
+        files.push(file_info);
+    }
+
+    let count_status = |status: BatchFileStatus| files.iter().filter(|file| file.status == status).count() as u64;
+    let converted_files = files.iter().filter(|file| file.status == BatchFileStatus::Converted);
+
+    let source_size: u64 = converted_files.clone().map(|file| file.source_size).sum();
+    let output_size: u64 = converted_files.filter_map(|file| file.output_size).sum();
+
+    return Ok(
+        BatchReport {
+            input_dir: input_root,
+            output_dir: output_root,
+            target_format: options.format,
+            resize: options.resize,
+            images_count: files.len() as u64,
+            converted_count: count_status(BatchFileStatus::Converted),
+            skipped_count: count_status(BatchFileStatus::Skipped),
+            failed_count: count_status(BatchFileStatus::Failed),
+            source_size,
+            output_size,
+            saved_size: source_size as i64 - output_size as i64,
+            files
+        }
+    )
+}
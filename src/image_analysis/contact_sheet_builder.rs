@@ -0,0 +1,345 @@
+/*
+==================================================================================
+| This module builds contact sheet: one PNG image with thumbnails of all images  |
+| in directory tree, placed in grid (sorted by path). Every tile is labelled     |
+| with filename, image dimensions and file size (same data as in gii command).   |
+| Labels are drawn with built-in 5x7 pixels font (ASCII only, others -> ?).      |
+| {Structs:}                                                                     |
+| 1) ContactSheetOptions - contact sheet columns count and thumbnails size       |
+| {Functions:}                                                                   |
+| 1) get_label_scale() - gets label font scale for thumbnails size               |
+| 2) draw_label_text() - draws label text line with built-in font                |
+| 3) fit_label_text() - shortens label text to tile width                        |
+| 4) draw_checkerboard() - draws transparent thumbnail background                |
+| 5) draw_sheet_tile() - draws one tile: thumbnail and label                     |
+| 6) build_contact_sheet() - builds contact sheet of directory images            |
+==================================================================================
+*/
+
+use image::{ Rgba, RgbaImage };
+use image::imageops;
+
+use std::path::{ Path, PathBuf };
+
+use crate::image_analysis::image_manager::*;
+use crate::image_analysis::image_reports::*;
+
+//max images count on contact sheet (the first images by path are placed)
+pub const MAX_SHEET_IMAGES: usize = 1000;
+
+//max contact sheet pixels count (100 megapixels -> 400 MB RGBA sheet), images, which don't fit, aren't placed
+pub const MAX_SHEET_PIXELS: u64 = 100_000_000;
+
+//tile padding, label lines count and gap between thumbnail and label (in pixels, without font scale)
+const TILE_PADDING: u32 = 8;
+const LABEL_LINES_COUNT: u32 = 3;
+const LABEL_GAP: u32 = 6;
+
+//font glyph height, glyph advance (5 pixels glyph width and spacing) and line height (in pixels, without font scale)
+const GLYPH_HEIGHT: u32 = 7;
+const GLYPH_ADVANCE: u32 = 6;
+const LINE_HEIGHT: u32 = 10;
+
+//sheet colors
+const SHEET_BACKGROUND: Rgba<u8> = Rgba([24, 24, 28, 255]);
+const TILE_BACKGROUND: Rgba<u8> = Rgba([40, 40, 46, 255]);
+const CHECKERBOARD_LIGHT: Rgba<u8> = Rgba([204, 204, 204, 255]);
+const CHECKERBOARD_DARK: Rgba<u8> = Rgba([153, 153, 153, 255]);
+const FILE_NAME_COLOR: Rgba<u8> = Rgba([240, 240, 240, 255]);
+const DETAILS_COLOR: Rgba<u8> = Rgba([160, 170, 185, 255]);
+
+//built-in 5x7 font glyphs of ASCII characters 32-126: 5 columns, every column byte -> 7 pixels (lowest bit is top pixel)
+const FONT_GLYPHS: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x5F, 0x00, 0x00], [0x00, 0x07, 0x00, 0x07, 0x00], [0x14, 0x7F, 0x14, 0x7F, 0x14], //space ! " #
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], [0x23, 0x13, 0x08, 0x64, 0x62], [0x36, 0x49, 0x55, 0x22, 0x50], [0x00, 0x05, 0x03, 0x00, 0x00], //$ % & '
+    [0x00, 0x1C, 0x22, 0x41, 0x00], [0x00, 0x41, 0x22, 0x1C, 0x00], [0x08, 0x2A, 0x1C, 0x2A, 0x08], [0x08, 0x08, 0x3E, 0x08, 0x08], //( ) * +
+    [0x00, 0x50, 0x30, 0x00, 0x00], [0x08, 0x08, 0x08, 0x08, 0x08], [0x00, 0x60, 0x60, 0x00, 0x00], [0x20, 0x10, 0x08, 0x04, 0x02], //, - . /
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], [0x00, 0x42, 0x7F, 0x40, 0x00], [0x42, 0x61, 0x51, 0x49, 0x46], [0x21, 0x41, 0x45, 0x4B, 0x31], //0 1 2 3
+    [0x18, 0x14, 0x12, 0x7F, 0x10], [0x27, 0x45, 0x45, 0x45, 0x39], [0x3C, 0x4A, 0x49, 0x49, 0x30], [0x01, 0x71, 0x09, 0x05, 0x03], //4 5 6 7
+    [0x36, 0x49, 0x49, 0x49, 0x36], [0x06, 0x49, 0x49, 0x29, 0x1E], [0x00, 0x36, 0x36, 0x00, 0x00], [0x00, 0x56, 0x36, 0x00, 0x00], //8 9 : ;
+    [0x08, 0x14, 0x22, 0x41, 0x00], [0x14, 0x14, 0x14, 0x14, 0x14], [0x00, 0x41, 0x22, 0x14, 0x08], [0x02, 0x01, 0x51, 0x09, 0x06], //< = > ?
+    [0x32, 0x49, 0x79, 0x41, 0x3E], [0x7E, 0x11, 0x11, 0x11, 0x7E], [0x7F, 0x49, 0x49, 0x49, 0x36], [0x3E, 0x41, 0x41, 0x41, 0x22], //@ A B C
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], [0x7F, 0x49, 0x49, 0x49, 0x41], [0x7F, 0x09, 0x09, 0x01, 0x01], [0x3E, 0x41, 0x41, 0x51, 0x32], //D E F G
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], [0x00, 0x41, 0x7F, 0x41, 0x00], [0x20, 0x40, 0x41, 0x3F, 0x01], [0x7F, 0x08, 0x14, 0x22, 0x41], //H I J K
+    [0x7F, 0x40, 0x40, 0x40, 0x40], [0x7F, 0x02, 0x04, 0x02, 0x7F], [0x7F, 0x04, 0x08, 0x10, 0x7F], [0x3E, 0x41, 0x41, 0x41, 0x3E], //L M N O
+    [0x7F, 0x09, 0x09, 0x09, 0x06], [0x3E, 0x41, 0x51, 0x21, 0x5E], [0x7F, 0x09, 0x19, 0x29, 0x46], [0x46, 0x49, 0x49, 0x49, 0x31], //P Q R S
+    [0x01, 0x01, 0x7F, 0x01, 0x01], [0x3F, 0x40, 0x40, 0x40, 0x3F], [0x1F, 0x20, 0x40, 0x20, 0x1F], [0x7F, 0x20, 0x18, 0x20, 0x7F], //T U V W
+    [0x63, 0x14, 0x08, 0x14, 0x63], [0x03, 0x04, 0x78, 0x04, 0x03], [0x61, 0x51, 0x49, 0x45, 0x43], [0x00, 0x7F, 0x41, 0x41, 0x00], //X Y Z [
+    [0x02, 0x04, 0x08, 0x10, 0x20], [0x00, 0x41, 0x41, 0x7F, 0x00], [0x04, 0x02, 0x01, 0x02, 0x04], [0x40, 0x40, 0x40, 0x40, 0x40], //\ ] ^ _
+    [0x00, 0x01, 0x02, 0x04, 0x00], [0x20, 0x54, 0x54, 0x54, 0x78], [0x7F, 0x48, 0x44, 0x44, 0x38], [0x38, 0x44, 0x44, 0x44, 0x20], //` a b c
+    [0x38, 0x44, 0x44, 0x48, 0x7F], [0x38, 0x54, 0x54, 0x54, 0x18], [0x08, 0x7E, 0x09, 0x01, 0x02], [0x08, 0x14, 0x54, 0x54, 0x3C], //d e f g
+    [0x7F, 0x08, 0x04, 0x04, 0x78], [0x00, 0x44, 0x7D, 0x40, 0x00], [0x20, 0x40, 0x44, 0x3D, 0x00], [0x00, 0x7F, 0x10, 0x28, 0x44], //h i j k
+    [0x00, 0x41, 0x7F, 0x40, 0x00], [0x7C, 0x04, 0x18, 0x04, 0x78], [0x7C, 0x08, 0x04, 0x04, 0x78], [0x38, 0x44, 0x44, 0x44, 0x38], //l m n o
+    [0x7C, 0x14, 0x14, 0x14, 0x08], [0x08, 0x14, 0x14, 0x18, 0x7C], [0x7C, 0x08, 0x04, 0x04, 0x08], [0x48, 0x54, 0x54, 0x54, 0x20], //p q r s
+    [0x04, 0x3F, 0x44, 0x40, 0x20], [0x3C, 0x40, 0x40, 0x20, 0x7C], [0x1C, 0x20, 0x40, 0x20, 0x1C], [0x3C, 0x40, 0x30, 0x40, 0x3C], //t u v w
+    [0x44, 0x28, 0x10, 0x28, 0x44], [0x0C, 0x50, 0x50, 0x50, 0x3C], [0x44, 0x64, 0x54, 0x4C, 0x44], [0x00, 0x08, 0x36, 0x41, 0x00], //x y z {
+    [0x00, 0x00, 0x7F, 0x00, 0x00], [0x00, 0x41, 0x36, 0x08, 0x00], [0x02, 0x01, 0x02, 0x04, 0x02] //| } ~
+];
+
+//contact sheet columns count and thumbnails size
+pub struct ContactSheetOptions {
+    pub columns: u32, //tiles in row
+    pub thumbnail_size: u32 //thumbnails fit in thumbnail_size x thumbnail_size square
+}
+
+//gets label font scale for thumbnails size (bigger thumbnails -> bigger labels)
+fn get_label_scale(thumbnail_size: u32) -> u32 {
+    return (thumbnail_size / 160).max(1)
+}
+
+//draws label text line with built-in font (x, y -> text top left corner), text outside image is clipped
+fn draw_label_text(
+    sheet: &mut RgbaImage,
+    text: &str,
+    x: u32,
+    y: u32,
+    scale: u32,
+    color: Rgba<u8>
+) {
+    for (index, character) in text.chars().enumerate() {
+        //not printable ASCII characters are drawn as "?"
+        let glyph_index = match character {
+            ' '..='~' => character as usize - ' ' as usize,
+            _ => '?' as usize - ' ' as usize
+        };
+
+        let glyph_x = x + index as u32 * GLYPH_ADVANCE * scale;
+
+        for (column, column_bits) in FONT_GLYPHS[glyph_index].iter().enumerate() {
+            for row in 0..GLYPH_HEIGHT {
+                if column_bits >> row & 1 == 0 {
+                    continue
+                }
+
+                //every font pixel is scale x scale square
+                for dx in 0..scale {
+                    for dy in 0..scale {
+                        let pixel_x = glyph_x + column as u32 * scale + dx;
+                        let pixel_y = y + row * scale + dy;
+
+                        if pixel_x < sheet.width() && pixel_y < sheet.height() {
+                            sheet.put_pixel(pixel_x, pixel_y, color);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+//shortens label text to max characters count (long_photo_name.jpg -> long_ph...jpg), extension end is kept
+fn fit_label_text(
+    text: &str,
+    max_characters: usize
+) -> String {
+    let characters: Vec<char> = text.chars().collect();
+
+    if characters.len() <= max_characters {
+        return text.to_string()
+    }
+
+    if max_characters <= 3 {
+        return characters[..max_characters].iter().collect()
+    }
+
+    let tail_length = ((max_characters - 3) / 3).min(4);
+    let head_length = max_characters - 3 - tail_length;
+
+    let head: String = characters[..head_length].iter().collect();
+    let tail: String = characters[characters.len() - tail_length..].iter().collect();
+
+    return format!("{}...{}", head, tail)
+}
+
+//draws checkerboard under thumbnail, so transparent images areas are visible
+fn draw_checkerboard(
+    sheet: &mut RgbaImage,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32
+) {
+    const CELL_SIZE: u32 = 8;
+
+    for dy in 0..height {
+        for dx in 0..width {
+            let color = if (dx / CELL_SIZE + dy / CELL_SIZE).is_multiple_of(2) {
+                CHECKERBOARD_LIGHT
+            } else {
+                CHECKERBOARD_DARK
+            };
+
+            sheet.put_pixel(x + dx, y + dy, color);
+        }
+    }
+}
+
+//draws one tile (x, y -> tile top left corner): tile background, centered thumbnail and label (filename, dimensions, file size)
+fn draw_sheet_tile(
+    sheet: &mut RgbaImage,
+    x: u32,
+    y: u32,
+    (tile_width, tile_height): (u32, u32),
+    thumbnail_size: u32,
+    tile: &ContactSheetTile,
+    thumbnail: &RgbaImage
+) {
+    let scale = get_label_scale(thumbnail_size);
+
+    for dy in 0..tile_height {
+        for dx in 0..tile_width {
+            sheet.put_pixel(x + dx, y + dy, TILE_BACKGROUND);
+        }
+    }
+
+    //thumbnail is centered in thumbnail square
+    let thumbnail_x = x + TILE_PADDING + (thumbnail_size - thumbnail.width()) / 2;
+    let thumbnail_y = y + TILE_PADDING + (thumbnail_size - thumbnail.height()) / 2;
+
+    if thumbnail.pixels().any(|pixel| pixel[3] < 255) {
+        draw_checkerboard(sheet, thumbnail_x, thumbnail_y, thumbnail.width(), thumbnail.height());
+    }
+
+    imageops::overlay(sheet, thumbnail, thumbnail_x as i64, thumbnail_y as i64);
+
+    //label lines under thumbnail
+    let max_characters = (thumbnail_size / (GLYPH_ADVANCE * scale)) as usize;
+    let label_x = x + TILE_PADDING;
+    let label_y = y + TILE_PADDING + thumbnail_size + LABEL_GAP * scale;
+
+    let label_lines = [
+        (fit_label_text(&tile.file_name, max_characters), FILE_NAME_COLOR),
+        (fit_label_text(&format!("{}x{} px", tile.width, tile.height), max_characters), DETAILS_COLOR),
+        (fit_label_text(&adapt_file_size(tile.file_size as f64), max_characters), DETAILS_COLOR)
+    ];
+
+    for (line_index, (text, color)) in label_lines.iter().enumerate() {
+        draw_label_text(sheet, text, label_x, label_y + line_index as u32 * LINE_HEIGHT * scale, scale, *color);
+    }
+}
+
+//builds contact sheet of images in directory tree (scan options roots are ignored, directory is scanned), returns report and sheet image
+//images, which couldn't be decoded, are skipped (no decoded images -> error), on_progress is called before every image decoding
+//sheet has up to MAX_SHEET_IMAGES images and MAX_SHEET_PIXELS pixels (the first images by path, which fit, are placed)
+pub fn build_contact_sheet(
+    directory: &Path,
+    options: &ContactSheetOptions,
+    scan_options: &ScanOptions,
+    mut on_progress: impl FnMut(&BatchProgress)
+) -> Result<(ContactSheetReport, RgbaImage), ImageManagerError> {
+    let root = match directory.canonicalize() {
+        Ok(path) if path.is_dir() => path,
+        Ok(path) => return Err(
+            ImageManagerError::ScanRoot {
+                path,
+                error: std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a directory")
+            }
+        ),
+        Err(error) => return Err(ImageManagerError::ScanRoot { path: directory.to_path_buf(), error })
+    };
+
+    let mut images_paths: Vec<PathBuf> = walk_scan_root(&root, scan_options)
+        .filter(|entry| detect_scanned_file_format(entry.path()).is_some_and(|file_format| file_format.format.is_some()))
+        .map(|entry| entry.path().to_path_buf())
+        .collect();
+
+    images_paths.sort();
+
+    let images_count = images_paths.len() as u64;
+
+    //sheet grid: tiles with thumbnail square and label lines under it (u64, so big options don't overflow)
+    let scale = get_label_scale(options.thumbnail_size) as u64;
+    let tile_width = options.thumbnail_size as u64 + TILE_PADDING as u64 * 2;
+    let tile_height = options.thumbnail_size as u64 + TILE_PADDING as u64 * 2 + (LABEL_GAP + LABEL_LINES_COUNT * LINE_HEIGHT) as u64 * scale;
+
+    //images count is limited by pixel budget: rows of full width sheet, which fit in budget
+    let full_sheet_width = (options.columns.max(1) as u64)
+        .saturating_mul(tile_width + TILE_PADDING as u64)
+        .saturating_add(TILE_PADDING as u64);
+    let max_rows = (MAX_SHEET_PIXELS / full_sheet_width).saturating_sub(TILE_PADDING as u64) / (tile_height + TILE_PADDING as u64);
+
+    if max_rows == 0 {
+        return Err(ImageManagerError::ContactSheetTooBig { max_pixels: MAX_SHEET_PIXELS })
+    }
+
+    let max_images = max_rows.saturating_mul(options.columns.max(1) as u64).min(MAX_SHEET_IMAGES as u64);
+    images_paths.truncate(max_images as usize);
+
+    //decoding images and making thumbnails
+    let mut thumbnails = Vec::new();
+    let mut skipped_files = Vec::new();
+
+    for (image_index, image_path) in images_paths.iter().enumerate() {
+        on_progress(
+            &BatchProgress {
+                processed_images: image_index,
+                images_count: images_paths.len(),
+                current_file: image_path
+            }
+        );
+
+        match get_image_thumbnail(image_path, options.thumbnail_size) {
+            Ok((thumbnail, (width, height), file_size)) => {
+                let tile = ContactSheetTile {
+                    path: image_path.clone(),
+                    file_name: image_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(),
+                    width,
+                    height,
+                    file_size,
+                    column: 0,
+                    row: 0
+                };
+
+                thumbnails.push((tile, thumbnail));
+            },
+            Err(_) => skipped_files.push(image_path.clone())
+        }
+    }
+
+    if thumbnails.is_empty() {
+        return Err(ImageManagerError::NoImagesFound { path: root })
+    }
+
+    //sheet fits in pixel budget (checked above), so its dimensions fit in u32
+    let (tile_width, tile_height) = (tile_width as u32, tile_height as u32);
+
+    let columns = options.columns.max(1).min(thumbnails.len() as u32);
+    let rows = (thumbnails.len() as u32).div_ceil(columns);
+
+    let sheet_width = columns * tile_width + (columns + 1) * TILE_PADDING;
+    let sheet_height = rows * tile_height + (rows + 1) * TILE_PADDING;
+
+    let mut sheet = RgbaImage::from_pixel(sheet_width, sheet_height, SHEET_BACKGROUND);
+    let mut tiles = Vec::new();
+
+    for (tile_index, (mut tile, thumbnail)) in thumbnails.into_iter().enumerate() {
+        let column = tile_index as u32 % columns;
+        let row = tile_index as u32 / columns;
+
+        let tile_x = TILE_PADDING + column * (tile_width + TILE_PADDING);
+        let tile_y = TILE_PADDING + row * (tile_height + TILE_PADDING);
+
+        draw_sheet_tile(&mut sheet, tile_x, tile_y, (tile_width, tile_height), options.thumbnail_size, &tile, &thumbnail);
+
+        tile.column = column + 1;
+        tile.row = row + 1;
+        tiles.push(tile);
+    }
+
+    return Ok(
+        (
+            ContactSheetReport {
+                directory: root,
+                columns,
+                rows,
+                thumbnail_size: options.thumbnail_size,
+                width: sheet_width,
+                height: sheet_height,
+                images_count,
+                tiles,
+                skipped_files
+            },
+            sheet
+        )
+    )
+}
@@ -0,0 +1,177 @@
+/*
+==================================================================================
+| This module reads metadata blocks from image containers: JPEG segments (before |
+| image data), PNG chunks, WebP chunks and TIFF IFD0 tags values. All readers    |
+| stop on broken lengths instead of failing, so damaged files give partial data. |
+| {Functions:}                                                                   |
+| 1) get_jpeg_segments() - gets JPEG metadata segments markers and payloads      |
+| 2) get_png_chunks() - gets PNG chunks types and payloads                       |
+| 3) get_webp_chunks() - gets WebP chunks fourccs and payloads                   |
+| 3.1) get_webp_chunk() - gets WebP chunk payload                                |
+| 4) get_tiff_ifd0_value() - gets TIFF IFD0 tag value bytes                      |
+| 5) inflate_zlib() - decompresses zlib stream (PNG compressed chunks)           |
+==================================================================================
+*/
+
+use flate2::read::ZlibDecoder;
+
+use std::io::Read;
+use std::ops::Range;
+
+use crate::image_analysis::exif_writer::get_value_type_size;
+
+//PNG file signature
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+//max decompressed chunk size (protects from zlib bombs)
+const MAX_INFLATED_SIZE: u64 = 64 * 1024 * 1024;
+
+//gets JPEG metadata segments (before image data): marker and payload range (after segment length)
+pub(crate) fn get_jpeg_segments(data: &[u8]) -> Vec<(u8, Range<usize>)> {
+    let mut segments = Vec::new();
+    let mut position = 2; //after SOI marker
+
+    while position + 4 <= data.len() && data[position] == 0xFF {
+        let marker = data[position + 1];
+
+        //markers without length
+        if marker == 0xD8 || marker == 0x01 || (0xD0..=0xD7).contains(&marker) {
+            position += 2;
+            continue
+        }
+
+        //image data starts after SOS marker, metadata segments are before it
+        if marker == 0xDA || marker == 0xD9 {
+            break
+        }
+
+        let length = u16::from_be_bytes([data[position + 2], data[position + 3]]) as usize;
+
+        //broken segment length
+        if length < 2 {
+            break
+        }
+
+        let segment_end = (position + 2 + length).min(data.len());
+
+        segments.push((marker, position + 4..segment_end));
+        position = segment_end;
+    }
+
+    return segments
+}
+
+//gets PNG chunks (before image end): chunk type and payload range
+pub(crate) fn get_png_chunks(data: &[u8]) -> Vec<([u8; 4], Range<usize>)> {
+    let mut chunks = Vec::new();
+
+    if !data.starts_with(PNG_SIGNATURE) {
+        return chunks
+    }
+
+    let mut position = PNG_SIGNATURE.len();
+
+    //chunk: length (4 bytes), type (4 bytes), payload, CRC (4 bytes)
+    while position + 8 <= data.len() {
+        let length = u32::from_be_bytes([data[position], data[position + 1], data[position + 2], data[position + 3]]) as usize;
+        let chunk_type = [data[position + 4], data[position + 5], data[position + 6], data[position + 7]];
+        let payload = position + 8..(position + 8 + length).min(data.len());
+
+        if &chunk_type == b"IEND" {
+            break
+        }
+
+        position = payload.end + 4;
+        chunks.push((chunk_type, payload));
+    }
+
+    return chunks
+}
+
+//gets WebP top-level chunks: fourcc (chunk name) and payload range (animation frames chunks aren't unpacked)
+pub(crate) fn get_webp_chunks(data: &[u8]) -> Vec<([u8; 4], Range<usize>)> {
+    let mut chunks = Vec::new();
+    let mut position = 12; //after RIFF header
+
+    while position + 8 <= data.len() {
+        let fourcc = [data[position], data[position + 1], data[position + 2], data[position + 3]];
+        let chunk_size = u32::from_le_bytes([data[position + 4], data[position + 5], data[position + 6], data[position + 7]]) as usize;
+        let payload = position + 8..(position + 8 + chunk_size).min(data.len());
+
+        position = payload.end + chunk_size % 2; //chunks are padded to even size
+        chunks.push((fourcc, payload));
+    }
+
+    return chunks
+}
+
+//gets WebP chunk (fourcc -> chunk name) payload range (None -> image has no such chunk)
+pub(crate) fn get_webp_chunk(
+    data: &[u8],
+    fourcc: &[u8; 4]
+) -> Option<Range<usize>> {
+    return get_webp_chunks(data)
+        .into_iter()
+        .find(|(chunk_fourcc, _)| chunk_fourcc == fourcc)
+        .map(|(_, payload)| payload)
+}
+
+//gets TIFF IFD0 tag value bytes (None -> image has no such tag or IFD0 is broken)
+pub(crate) fn get_tiff_ifd0_value(
+    data: &[u8],
+    tag: u16
+) -> Option<&[u8]> {
+    let little_endian = match data.get(0..4)? {
+        b"II*\0" => true,
+        b"MM\0*" => false,
+        _ => return None //not TIFF or BigTIFF
+    };
+
+    let read_u16 = |position: usize| -> Option<u16> {
+        let bytes = [*data.get(position)?, *data.get(position + 1)?];
+
+        return Some(if little_endian { u16::from_le_bytes(bytes) } else { u16::from_be_bytes(bytes) })
+    };
+
+    let read_u32 = |position: usize| -> Option<u32> {
+        let bytes = [*data.get(position)?, *data.get(position + 1)?, *data.get(position + 2)?, *data.get(position + 3)?];
+
+        return Some(if little_endian { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) })
+    };
+
+    let ifd_offset = read_u32(4)? as usize;
+    let entries_count = read_u16(ifd_offset)? as usize;
+
+    for index in 0..entries_count {
+        let entry = ifd_offset + 2 + index * 12;
+
+        if read_u16(entry)? != tag {
+            continue
+        }
+
+        let value_size = get_value_type_size(read_u16(entry + 2)?)? * read_u32(entry + 4)? as usize;
+
+        //values up to 4 bytes are stored in entry, bigger values - by offset
+        let value_start = if value_size <= 4 { entry + 8 } else { read_u32(entry + 8)? as usize };
+
+        return data.get(value_start..value_start.checked_add(value_size)?)
+    }
+
+    return None
+}
+
+//decompresses zlib stream (None -> broken stream or decompressed data is too big)
+pub(crate) fn inflate_zlib(data: &[u8]) -> Option<Vec<u8>> {
+    let mut inflated = Vec::new();
+
+    ZlibDecoder::new(data)
+        .take(MAX_INFLATED_SIZE + 1)
+        .read_to_end(&mut inflated)
+        .ok()?;
+
+    if inflated.len() as u64 > MAX_INFLATED_SIZE {
+        return None
+    }
+
+    return Some(inflated)
+}
@@ -0,0 +1,128 @@
+/*
+==================================================================================
+| This module finds byte-identical images (exact duplicates) in scan roots.      |
+| Images are grouped by file size first (only same size files can be equal),     |
+| then same size files are grouped by SHA-256 content hash.                      |
+| used libs: [sha2-files content hash, walkdir-for directory crawling]           |
+| {Functions:}                                                                   |
+| 1) get_file_hash() - counts file content SHA-256 hash                          |
+| 2) find_duplicate_images() - finds exact duplicate images in scan roots        |
+==================================================================================
+*/
+
+use sha2::{ Digest, Sha256 };
+
+use std::collections::{ HashMap, HashSet };
+use std::fs::File;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use crate::image_analysis::image_manager::*;
+use crate::image_analysis::image_reports::*;
+
+//counts file content SHA-256 hash (hex string), file is read by chunks
+fn get_file_hash(path: &Path) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+
+    return Ok(
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    )
+}
+
+//finds exact duplicate images in scan roots (images are detected by content, see image_manager), on_progress is called before every root scan
+pub fn find_duplicate_images(
+    scan_options: &ScanOptions,
+    mut on_progress: impl FnMut(&ScanProgress)
+) -> Result<DuplicatesReport, ImageManagerError> {
+    let scan_roots = resolve_scan_roots(scan_options)?;
+
+    let mut images_count = 0;
+    let mut images_by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    let mut seen_paths: HashSet<PathBuf> = HashSet::new(); //real paths of already found images
+
+    //collecting all images with their sizes
+    for (dir_index, (_, current_dir)) in scan_roots.iter().enumerate() {
+        on_progress(
+            &ScanProgress {
+                scanned_dirs: dir_index + 1,
+                dirs_count: scan_roots.len(),
+                images_found: images_count,
+                current_dir
+            }
+        );
+
+        for entry in walk_scan_root(current_dir, scan_options) {
+            let is_image = detect_scanned_file_format(entry.path()).is_some_and(|file_format| file_format.format.is_some());
+
+            if !is_image {
+                continue
+            }
+
+            let path: PathBuf = entry.path().canonicalize().unwrap_or(entry.path().to_path_buf());
+
+            //same file found by several roots or symlinks is counted once
+            if seen_paths.contains(&path) {
+                continue
+            }
+
+            if let Ok(metadata) = entry.metadata() {
+                images_count += 1;
+                images_by_size.entry(metadata.len()).or_default().push(path.clone());
+                seen_paths.insert(path);
+            }
+        }
+    }
+
+    let mut duplicate_groups = Vec::new();
+
+    //hashing only images with same size
+    for (file_size, mut paths) in images_by_size {
+        if paths.len() < 2 {
+            continue
+        }
+
+        paths.sort();
+
+        let mut images_by_hash: HashMap<String, Vec<PathBuf>> = HashMap::new();
+
+        for path in paths {
+            if let Ok(hash) = get_file_hash(&path) {
+                images_by_hash.entry(hash).or_default().push(path);
+            }
+        }
+
+        for (hash, files) in images_by_hash {
+            if files.len() < 2 {
+                continue
+            }
+
+            duplicate_groups.push(
+                DuplicateGroup {
+                    hash,
+                    file_size,
+                    wasted_size: file_size * (files.len() as u64 - 1), //all copies except one
+                    files
+                }
+            );
+        }
+    }
+
+    //groups wasting the most space first
+    duplicate_groups.sort_by(|first, second| second.wasted_size.cmp(&first.wasted_size).then(first.files.cmp(&second.files)));
+
+    return Ok(
+        DuplicatesReport {
+            directories: scan_roots.into_iter().map(|(_, path)| path).collect(),
+            images_count,
+            duplicate_files: duplicate_groups.iter().map(|group| group.files.len() as u64 - 1).sum(),
+            wasted_size: duplicate_groups.iter().map(|group| group.wasted_size).sum(),
+            duplicate_groups
+        }
+    )
+}
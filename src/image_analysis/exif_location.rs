@@ -0,0 +1,161 @@
+/*
+==================================================================================
+| This module decodes image location from Exif GPS entries: latitude, longitude |
+| and altitude are converted from degrees/minutes/seconds rationals to signed    |
+| decimal values (south, west and below sea level are negative).                 |
+| {Functions:}                                                                   |
+| 1) find_entry_value() - finds Exif entry value by tag                          |
+| 2) get_rationals() - converts rational entry value to floats                   |
+| 3) get_ascii_value() - gets text entry value                                   |
+| 4) get_decimal_degrees() - converts degrees/minutes/seconds to signed degrees  |
+| 5) get_gps_timestamp() - joins GPS date and time into UTC timestamp            |
+| 6) get_gps_location() - decodes image location from Exif entries               |
+==================================================================================
+*/
+
+use rexif::{ ExifEntry, ExifTag, TagValue };
+
+use crate::image_analysis::image_reports::GpsLocation;
+
+//finds Exif entry value by tag
+fn find_entry_value(
+    entries: &[ExifEntry],
+    tag: ExifTag
+) -> Option<&TagValue> {
+    return entries.iter().find(|entry| entry.tag == tag).map(|entry| &entry.value)
+}
+
+//converts rational entry value to floats (None if value isn't rational or has zero denominator)
+fn get_rationals(value: Option<&TagValue>) -> Option<Vec<f64>> {
+    let Some(TagValue::URational(rationals)) = value else {
+        return None
+    };
+
+    if rationals.is_empty() || rationals.iter().any(|rational| rational.denominator == 0) {
+        return None
+    }
+
+    return Some(rationals.iter().map(|rational| rational.value()).collect())
+}
+
+//gets text entry value (without spaces and null characters)
+fn get_ascii_value(value: Option<&TagValue>) -> Option<String> {
+    let Some(TagValue::Ascii(text)) = value else {
+        return None
+    };
+
+    let text = text.trim_matches(|character: char| character == '\0' || character.is_whitespace());
+
+    if text.is_empty() {
+        return None
+    }
+
+    return Some(text.to_string())
+}
+
+//converts degrees/minutes/seconds to signed decimal degrees (negative_ref -> "S" or "W")
+fn get_decimal_degrees(
+    value: Option<&TagValue>,
+    reference: Option<&TagValue>,
+    negative_ref: &str,
+    max_degrees: f64
+) -> Option<f64> {
+    let parts = get_rationals(value)?;
+
+    //degrees, minutes and seconds (minutes and seconds can be omitted)
+    let mut degrees = parts[0];
+
+    if let Some(minutes) = parts.get(1) {
+        degrees += minutes / 60.0;
+    }
+
+    if let Some(seconds) = parts.get(2) {
+        degrees += seconds / 3600.0;
+    }
+
+    if degrees > max_degrees {
+        return None
+    }
+
+    if get_ascii_value(reference).is_some_and(|reference| reference.eq_ignore_ascii_case(negative_ref)) {
+        degrees = -degrees;
+    }
+
+    return Some(degrees)
+}
+
+//joins GPS date (YYYY:MM:DD) and time (hours, minutes, seconds rationals) into UTC timestamp (YYYY-MM-DDTHH:MM:SSZ)
+fn get_gps_timestamp(
+    date: Option<&TagValue>,
+    time: Option<&TagValue>
+) -> Option<String> {
+    let time = get_rationals(time)
+        .filter(|parts| parts.len() == 3)
+        .map(|parts| format!("{:02}:{:02}:{:02}", parts[0] as u32, parts[1] as u32, parts[2] as u32));
+
+    let date = get_ascii_value(date).map(|date| date.replace(':', "-"));
+
+    return match (date, time) {
+        (Some(date), Some(time)) => Some(format!("{}T{}Z", date, time)),
+        (Some(date), None) => Some(date),
+        (None, Some(time)) => Some(format!("{}Z", time)),
+        (None, None) => None
+    }
+}
+
+//decodes image location from Exif entries (None if latitude or longitude isn't embedded)
+pub fn get_gps_location(entries: &[ExifEntry]) -> Option<GpsLocation> {
+    let latitude = get_decimal_degrees(
+        find_entry_value(entries, ExifTag::GPSLatitude),
+        find_entry_value(entries, ExifTag::GPSLatitudeRef),
+        "S",
+        90.0
+    )?;
+
+    let longitude = get_decimal_degrees(
+        find_entry_value(entries, ExifTag::GPSLongitude),
+        find_entry_value(entries, ExifTag::GPSLongitudeRef),
+        "W",
+        180.0
+    )?;
+
+    //altitude ref 1 -> below sea level
+    let altitude = get_rationals(find_entry_value(entries, ExifTag::GPSAltitude)).map(|parts| {
+        match find_entry_value(entries, ExifTag::GPSAltitudeRef) {
+            Some(TagValue::U8(reference)) if reference.first() == Some(&1) => -parts[0],
+            _ => parts[0]
+        }
+    });
+
+    let timestamp = get_gps_timestamp(
+        find_entry_value(entries, ExifTag::GPSDateStamp),
+        find_entry_value(entries, ExifTag::GPSTimeStamp)
+    );
+
+    let direction = get_rationals(find_entry_value(entries, ExifTag::GPSImgDirection)).map(|parts| parts[0]);
+
+    //direction ref: T -> true north, M -> magnetic north
+    let direction_ref = match get_ascii_value(find_entry_value(entries, ExifTag::GPSImgDirectionRef)).as_deref() {
+        Some("T") => Some(String::from("true north")),
+        Some("M") => Some(String::from("magnetic north")),
+        _ => None
+    };
+
+    //geo URI (RFC 5870): geo:latitude,longitude[,altitude]
+    let geo_uri = match altitude {
+        Some(altitude) => format!("geo:{:.6},{:.6},{:.1}", latitude, longitude, altitude),
+        None => format!("geo:{:.6},{:.6}", latitude, longitude)
+    };
+
+    return Some(
+        GpsLocation {
+            latitude,
+            longitude,
+            altitude,
+            timestamp,
+            direction,
+            direction_ref,
+            geo_uri
+        }
+    )
+}
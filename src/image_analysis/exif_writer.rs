@@ -0,0 +1,1256 @@
+/*
+==================================================================================
+| This module edits Exif metadata blocks in JPEG (APP1 segment), WebP (EXIF      |
+| chunk) and TIFF (image file itself) images. Exif block is TIFF structure: IFD0 |
+| with Exif and GPS sub-IFDs pointers. Removed entries are cut from their IFD    |
+| and their values are overwritten with zeros, so other offsets stay valid.      |
+| Edited IFDs and new values are appended to block end, old IFDs are wiped.      |
+| PNG (eXIf chunk) blocks are only read and copied by image conversion.          |
+| {Structs:}                                                                     |
+| 1) ExifBlock - Exif TIFF block bytes reader and writer                         |
+| 2) EncodedValue - IFD entry value encoded for writing                          |
+| {Enums:}                                                                       |
+| 1) ExifStripTarget - Exif metadata groups, which can be stripped selectively   |
+| 2) EditableExifTag - Exif tags, which can be set by exif-set command           |
+| {Functions:}                                                                   |
+| 1) read_entry_u16() - reads SHORT entry value                                  |
+| 2) read_entry_u32() - reads LONG entry value                                   |
+| 3) get_value_type_size() - gets IFD entry value type size                      |
+| 4) find_jpeg_exif_segments() - finds Exif APP1 segments in JPEG image          |
+| 5) find_webp_chunk() - finds chunk in WebP image                               |
+| 6) find_exif_block() - finds Exif TIFF block in image                          |
+| 7) clear_entry_value() - overwrites IFD entry value with zeros                 |
+| 8) wipe_ifd() - overwrites whole IFD and its values with zeros                 |
+| 9) strip_ifd() - removes entries from IFD and its sub-IFDs                     |
+| 10) strip_exif_block() - removes entries from Exif TIFF block                  |
+| 11) remove_exif_block() - removes Exif block from JPEG or WebP image           |
+| 12) get_block_entries() - parses Exif TIFF block entries                       |
+| 13) strip_exif_metadata() - removes all or selected Exif metadata from image   |
+| 14) read_exif_writable_image() - reads JPEG, WebP or TIFF image file bytes     |
+| 15) encode_tag_value() - validates and encodes editable tag value              |
+| 16) read_ifd_entries() - reads IFD entries and next IFD offset                 |
+| 17) read_entry_text() - reads text or number entry value                       |
+| 18) append_bytes() - appends bytes to block end (even offset)                  |
+| 19) set_ifd_entries() - sets encoded values into IFD entries                   |
+| 20) rewrite_ifd() - appends edited IFD to block end and wipes old IFD          |
+| 21) set_exif_block_tags() - sets tags values in Exif TIFF block                |
+| 22) create_exif_block() - creates empty Exif TIFF block                        |
+| 23) replace_exif_block() - replaces or inserts Exif block in image             |
+| 24) set_exif_tags() - sets Exif tags values in image                           |
+==================================================================================
+*/
+
+use image::ImageError;
+use rexif::ExifError;
+use serde::Serialize;
+
+use flate2::Crc;
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::image_analysis::image_manager::{ check_image_file, get_exif_entries_info };
+use crate::image_analysis::format_detector::ImageContainer;
+use crate::image_analysis::container_reader::get_png_chunks;
+use crate::image_analysis::image_reports::*;
+
+//sub-IFDs pointers tags
+const EXIF_IFD_TAG: u16 = 0x8769;
+const GPS_IFD_TAG: u16 = 0x8825;
+const INTEROPERABILITY_IFD_TAG: u16 = 0xA005;
+
+//camera and owner identifying tags: CameraOwnerName, BodySerialNumber, LensSerialNumber, ImageUniqueID,
+//MakerNote (manufacturer data, usually contains serial numbers) and CameraSerialNumber (DNG)
+const SERIAL_TAGS: &[u16] = &[0xA430, 0xA431, 0xA435, 0xA420, 0x927C, 0xC62F];
+
+//TIFF image metadata tags (image structure tags are kept): ImageDescription, Make, Model, Software,
+//DateTime, Artist, HostComputer, Copyright, Exif and GPS sub-IFDs
+const TIFF_METADATA_TAGS: &[u16] = &[
+    0x010E, 0x010F, 0x0110, 0x0131, 0x0132, 0x013B, 0x013C, 0x8298,
+    EXIF_IFD_TAG, GPS_IFD_TAG, 0xC62F
+];
+
+//Exif identifier before TIFF block in JPEG APP1 segment (and in some WebP EXIF chunks)
+const EXIF_IDENTIFIER: &[u8] = b"Exif\0\0";
+
+//WebP VP8X chunk flag, which marks image with EXIF chunk
+const WEBP_EXIF_FLAG: u8 = 0x08;
+
+//max sub-IFDs nesting (protects from IFDs pointing to each other)
+const MAX_IFD_DEPTH: usize = 4;
+
+//max Exif block size in JPEG APP1 segment (segment length field is 16-bit)
+const MAX_JPEG_EXIF_BLOCK_SIZE: usize = 0xFFFF - 2 - 6;
+
+//IFD entry value types
+const ASCII_TYPE: u16 = 2;
+const SHORT_TYPE: u16 = 3;
+const LONG_TYPE: u16 = 4;
+
+//Exif metadata groups, which can be stripped selectively
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExifStripTarget {
+    Gps, //GPS sub-IFD (location, GPS timestamp, direction)
+    Serial //camera, lens serial numbers, owner name, image unique ID and maker notes
+}
+
+impl ExifStripTarget {
+    //returns target name (as in strip command parameters)
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExifStripTarget::Gps => "gps",
+            ExifStripTarget::Serial => "serial"
+        }
+    }
+
+    //parses target name
+    pub fn from_name(name: &str) -> Option<ExifStripTarget> {
+        match name.to_lowercase().as_str() {
+            "gps" => Some(ExifStripTarget::Gps),
+            "serial" => Some(ExifStripTarget::Serial),
+            _ => None
+        }
+    }
+
+    //checks if IFD entry tag belongs to target
+    fn contains(&self, tag: u16) -> bool {
+        match self {
+            ExifStripTarget::Gps => tag == GPS_IFD_TAG,
+            ExifStripTarget::Serial => SERIAL_TAGS.contains(&tag)
+        }
+    }
+}
+
+//Exif tags, which can be set by exif-set command
+#[derive(Clone, Copy, PartialEq)]
+pub enum EditableExifTag {
+    Artist,
+    Copyright,
+    DateTimeOriginal,
+    ImageDescription,
+    Orientation
+}
+
+impl EditableExifTag {
+    //all editable tags
+    pub const ALL: [EditableExifTag; 5] = [
+        EditableExifTag::Artist,
+        EditableExifTag::Copyright,
+        EditableExifTag::DateTimeOriginal,
+        EditableExifTag::ImageDescription,
+        EditableExifTag::Orientation
+    ];
+
+    //returns tag name (as in exif-set command parameters)
+    pub fn name(&self) -> &'static str {
+        match self {
+            EditableExifTag::Artist => "Artist",
+            EditableExifTag::Copyright => "Copyright",
+            EditableExifTag::DateTimeOriginal => "DateTimeOriginal",
+            EditableExifTag::ImageDescription => "ImageDescription",
+            EditableExifTag::Orientation => "Orientation"
+        }
+    }
+
+    //parses tag name (case insensitive)
+    pub fn from_name(name: &str) -> Option<EditableExifTag> {
+        return EditableExifTag::ALL.into_iter().find(|tag| tag.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    //returns expected value description
+    pub fn expected_value(&self) -> &'static str {
+        match self {
+            EditableExifTag::DateTimeOriginal => "date and time YYYY:MM:DD HH:MM:SS",
+            EditableExifTag::Orientation => "number 1-8",
+            _ => "not empty ASCII text"
+        }
+    }
+
+    //returns IFD entry tag
+    fn tag_id(&self) -> u16 {
+        match self {
+            EditableExifTag::Artist => 0x013B,
+            EditableExifTag::Copyright => 0x8298,
+            EditableExifTag::DateTimeOriginal => 0x9003,
+            EditableExifTag::ImageDescription => 0x010E,
+            EditableExifTag::Orientation => 0x0112
+        }
+    }
+
+    //checks if tag is stored in Exif sub-IFD (other tags are stored in IFD0)
+    fn is_in_exif_ifd(&self) -> bool {
+        return *self == EditableExifTag::DateTimeOriginal
+    }
+}
+
+//IFD entry value encoded for writing (bytes are in block byte order)
+struct EncodedValue {
+    value_type: u16,
+    count: u32,
+    bytes: Vec<u8>,
+    text: String //normalized value text (for report)
+}
+
+//Exif TIFF block bytes reader and writer (offsets are counted from block start)
+struct ExifBlock<'a> {
+    data: &'a mut [u8],
+    little_endian: bool
+}
+
+impl ExifBlock<'_> {
+    fn read_u16(&self, position: usize) -> Option<u16> {
+        let bytes: [u8; 2] = self.data.get(position..position + 2)?.try_into().ok()?;
+
+        return Some(if self.little_endian { u16::from_le_bytes(bytes) } else { u16::from_be_bytes(bytes) })
+    }
+
+    fn read_u32(&self, position: usize) -> Option<u32> {
+        let bytes: [u8; 4] = self.data.get(position..position + 4)?.try_into().ok()?;
+
+        return Some(if self.little_endian { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) })
+    }
+
+    fn write_u16(&mut self, position: usize, value: u16) {
+        let bytes = if self.little_endian { value.to_le_bytes() } else { value.to_be_bytes() };
+
+        self.data[position..position + 2].copy_from_slice(&bytes);
+    }
+
+    fn write_u32(&mut self, position: usize, value: u32) {
+        let bytes = if self.little_endian { value.to_le_bytes() } else { value.to_be_bytes() };
+
+        self.data[position..position + 4].copy_from_slice(&bytes);
+    }
+
+    //overwrites bytes range with zeros (range outside of block is skipped)
+    fn clear(&mut self, range: Range<usize>) {
+        if let Some(bytes) = self.data.get_mut(range) {
+            bytes.fill(0);
+        }
+    }
+}
+
+//reads 16-bit number from IFD entry (position -> entry bytes offset)
+fn read_entry_u16(
+    entry: &[u8; 12],
+    position: usize,
+    little_endian: bool
+) -> u16 {
+    let bytes = [entry[position], entry[position + 1]];
+
+    return if little_endian { u16::from_le_bytes(bytes) } else { u16::from_be_bytes(bytes) }
+}
+
+//reads 32-bit number from IFD entry (position -> entry bytes offset)
+fn read_entry_u32(
+    entry: &[u8; 12],
+    position: usize,
+    little_endian: bool
+) -> u32 {
+    let bytes = [entry[position], entry[position + 1], entry[position + 2], entry[position + 3]];
+
+    return if little_endian { u32::from_le_bytes(bytes) } else { u32::from_be_bytes(bytes) }
+}
+
+//gets IFD entry value type size in bytes (None -> unknown type)
+pub(crate) fn get_value_type_size(value_type: u16) -> Option<usize> {
+    match value_type {
+        1 | 2 | 6 | 7 => Some(1), //BYTE, ASCII, SBYTE, UNDEFINED
+        3 | 8 => Some(2), //SHORT, SSHORT
+        4 | 9 | 11 | 13 => Some(4), //LONG, SLONG, FLOAT, IFD
+        5 | 10 | 12 => Some(8), //RATIONAL, SRATIONAL, DOUBLE
+        _ => None
+    }
+}
+
+//finds Exif APP1 segments in JPEG image (segments ranges with markers)
+fn find_jpeg_exif_segments(data: &[u8]) -> Vec<Range<usize>> {
+    let mut segments = Vec::new();
+    let mut position = 2; //after SOI marker
+
+    while position + 4 <= data.len() && data[position] == 0xFF {
+        let marker = data[position + 1];
+
+        //markers without length
+        if marker == 0xD8 || marker == 0x01 || (0xD0..=0xD7).contains(&marker) {
+            position += 2;
+            continue
+        }
+
+        //image data starts after SOS marker, metadata segments are before it
+        if marker == 0xDA || marker == 0xD9 {
+            break
+        }
+
+        let length = u16::from_be_bytes([data[position + 2], data[position + 3]]) as usize;
+
+        //broken segment length
+        if length < 2 {
+            break
+        }
+        let segment_end = (position + 2 + length).min(data.len());
+
+        if marker == 0xE1 && data[position + 4..segment_end].starts_with(EXIF_IDENTIFIER) {
+            segments.push(position..segment_end);
+        }
+
+        position = segment_end;
+    }
+
+    return segments
+}
+
+//finds chunk (fourcc -> chunk name) in WebP image, returns chunk range (with header and padding) and payload range
+fn find_webp_chunk(
+    data: &[u8],
+    fourcc: &[u8; 4]
+) -> Option<(Range<usize>, Range<usize>)> {
+    let mut position = 12; //after RIFF header
+
+    while position + 8 <= data.len() {
+        let chunk_size = u32::from_le_bytes(data[position + 4..position + 8].try_into().ok()?) as usize;
+        let payload = position + 8..(position + 8 + chunk_size).min(data.len());
+        let chunk_end = (payload.end + chunk_size % 2).min(data.len()); //chunks are padded to even size
+
+        if &data[position..position + 4] == fourcc {
+            return Some((position..chunk_end, payload))
+        }
+
+        position = chunk_end;
+    }
+
+    return None
+}
+
+//finds Exif TIFF block range in image (None -> image has no Exif block)
+pub(crate) fn find_exif_block(
+    data: &[u8],
+    container: ImageContainer
+) -> Option<Range<usize>> {
+    match container {
+        ImageContainer::Jpeg => {
+            let segment = find_jpeg_exif_segments(data).into_iter().next()?;
+
+            return Some(segment.start + 4 + EXIF_IDENTIFIER.len()..segment.end)
+        },
+        ImageContainer::WebP => {
+            let (_, payload) = find_webp_chunk(data, b"EXIF")?;
+
+            if data[payload.clone()].starts_with(EXIF_IDENTIFIER) {
+                return Some(payload.start + EXIF_IDENTIFIER.len()..payload.end)
+            }
+
+            return Some(payload)
+        },
+        ImageContainer::Png => get_png_chunks(data)
+            .into_iter()
+            .find(|(chunk_type, _)| chunk_type == b"eXIf")
+            .map(|(_, payload)| payload),
+        ImageContainer::Tiff => Some(0..data.len()),
+        _ => None
+    }
+}
+
+//overwrites IFD entry value with zeros (values, which don't fit into entry, are stored outside of IFD)
+fn clear_entry_value(
+    block: &mut ExifBlock,
+    entry_position: usize,
+    visited_ifds: &mut HashSet<usize>,
+    depth: usize
+) {
+    let tag = block.read_u16(entry_position).unwrap_or(0);
+    let value_type = block.read_u16(entry_position + 2).unwrap_or(0);
+    let count = block.read_u32(entry_position + 4).unwrap_or(0) as usize;
+    let value_offset = block.read_u32(entry_position + 8).unwrap_or(0) as usize;
+
+    //sub-IFD pointer -> whole sub-IFD is removed
+    if matches!(tag, EXIF_IFD_TAG | GPS_IFD_TAG | INTEROPERABILITY_IFD_TAG) {
+        wipe_ifd(block, value_offset, visited_ifds, depth + 1);
+    }
+
+    if let Some(value_size) = get_value_type_size(value_type).and_then(|size| size.checked_mul(count)) {
+        if value_size > 4 {
+            block.clear(value_offset..value_offset.saturating_add(value_size));
+        }
+    }
+
+    block.clear(entry_position..entry_position + 12);
+}
+
+//overwrites whole IFD and its entries values with zeros
+fn wipe_ifd(
+    block: &mut ExifBlock,
+    offset: usize,
+    visited_ifds: &mut HashSet<usize>,
+    depth: usize
+) {
+    if depth > MAX_IFD_DEPTH || !visited_ifds.insert(offset) {
+        return
+    }
+
+    let Some(entries_count) = block.read_u16(offset) else {
+        return
+    };
+
+    for index in 0..entries_count as usize {
+        clear_entry_value(block, offset + 2 + index * 12, visited_ifds, depth);
+    }
+
+    block.clear(offset..offset + 2 + entries_count as usize * 12 + 4);
+}
+
+//removes entries (is_removed -> checks entry tag) from IFD and its sub-IFDs, returns removed entries count
+//kept entries are moved to IFD start, next IFD offset is moved after them
+fn strip_ifd(
+    block: &mut ExifBlock,
+    offset: usize,
+    is_removed: &dyn Fn(u16) -> bool,
+    visited_ifds: &mut HashSet<usize>,
+    depth: usize
+) -> Result<usize, ExifError> {
+    if depth > MAX_IFD_DEPTH || !visited_ifds.insert(offset) {
+        return Ok(0)
+    }
+
+    let entries_count = block.read_u16(offset).ok_or(ExifError::IfdTruncated)? as usize;
+    let next_ifd_position = offset + 2 + entries_count * 12;
+    let next_ifd_offset = block.read_u32(next_ifd_position).ok_or(ExifError::IfdTruncated)?;
+
+    let mut kept_entries = Vec::new();
+    let mut removed_count = 0;
+
+    for index in 0..entries_count {
+        let entry_position = offset + 2 + index * 12;
+        let tag = block.read_u16(entry_position).ok_or(ExifError::IfdTruncated)?;
+
+        if is_removed(tag) {
+            clear_entry_value(block, entry_position, visited_ifds, depth);
+            removed_count += 1;
+            continue
+        }
+
+        //entries are removed from kept sub-IFDs too (serial numbers are in Exif sub-IFD)
+        if matches!(tag, EXIF_IFD_TAG | GPS_IFD_TAG | INTEROPERABILITY_IFD_TAG) {
+            let sub_ifd_offset = block.read_u32(entry_position + 8).ok_or(ExifError::IfdTruncated)? as usize;
+
+            removed_count += strip_ifd(block, sub_ifd_offset, is_removed, visited_ifds, depth + 1)?;
+        }
+
+        kept_entries.push(block.data[entry_position..entry_position + 12].to_vec());
+    }
+
+    if removed_count == 0 {
+        return Ok(0)
+    }
+
+    //rewriting IFD with kept entries
+    block.write_u16(offset, kept_entries.len() as u16);
+
+    for (index, entry) in kept_entries.iter().enumerate() {
+        let entry_position = offset + 2 + index * 12;
+
+        block.data[entry_position..entry_position + 12].copy_from_slice(entry);
+    }
+
+    let new_next_ifd_position = offset + 2 + kept_entries.len() * 12;
+    let next_ifd_bytes = if block.little_endian { next_ifd_offset.to_le_bytes() } else { next_ifd_offset.to_be_bytes() };
+
+    block.clear(new_next_ifd_position..next_ifd_position + 4);
+    block.data[new_next_ifd_position..new_next_ifd_position + 4].copy_from_slice(&next_ifd_bytes);
+
+    return Ok(removed_count)
+}
+
+//removes entries (is_removed -> checks entry tag) from Exif TIFF block IFD0 and its sub-IFDs
+fn strip_exif_block(
+    data: &mut [u8],
+    is_removed: &dyn Fn(u16) -> bool
+) -> Result<usize, ExifError> {
+    let little_endian = match data.get(0..4) {
+        Some(b"II*\0") => true,
+        Some(b"MM\0*") => false,
+        _ => return Err(ExifError::TiffBadPreamble(String::from("Exif block doesn't start with TIFF header")))
+    };
+
+    let mut block = ExifBlock { data, little_endian };
+    let ifd0_offset = block.read_u32(4).ok_or(ExifError::TiffTruncated)? as usize;
+
+    return strip_ifd(&mut block, ifd0_offset, is_removed, &mut HashSet::new(), 0)
+}
+
+//removes Exif block from JPEG (all Exif APP1 segments) or WebP (EXIF chunk) image
+fn remove_exif_block(
+    data: &mut Vec<u8>,
+    container: ImageContainer
+) {
+    match container {
+        ImageContainer::Jpeg => {
+            for segment in find_jpeg_exif_segments(data).into_iter().rev() {
+                data.drain(segment);
+            }
+        },
+        ImageContainer::WebP => {
+            if let Some((chunk, _)) = find_webp_chunk(data, b"EXIF") {
+                data.drain(chunk);
+
+                //updating RIFF size and removing EXIF flag from VP8X chunk
+                let riff_size = (data.len() - 8) as u32;
+                data[4..8].copy_from_slice(&riff_size.to_le_bytes());
+
+                if data.get(12..16) == Some(b"VP8X") && data.len() > 20 {
+                    data[20] &= !WEBP_EXIF_FLAG;
+                }
+            }
+        },
+        _ => {}
+    }
+}
+
+//parses Exif TIFF block entries (empty -> no Exif block or entries couldn't be parsed)
+fn get_block_entries(
+    data: &[u8],
+    container: ImageContainer
+) -> Vec<ExifEntryInfo> {
+    let Some(block) = find_exif_block(data, container) else {
+        return Vec::new()
+    };
+
+    return match rexif::parse_buffer(&data[block]) {
+        Ok(exif) => get_exif_entries_info(&exif.entries),
+        Err(_) => Vec::new()
+    }
+}
+
+//removes all (targets is empty) or selected Exif metadata from JPEG, WebP or TIFF image
+//returns strip report and stripped image copy bytes (original file isn't changed)
+pub fn strip_exif_metadata(
+    path: String,
+    targets: &[ExifStripTarget]
+) -> Result<(ExifStripReport, Vec<u8>), ImageManagerError> {
+    let (file_name, file_extension, container, mut data) = read_exif_writable_image(&path)?;
+    let original_entries = get_block_entries(&data, container);
+
+    if let Some(block) = find_exif_block(&data, container) {
+        if !targets.is_empty() {
+            strip_exif_block(&mut data[block], &|tag| targets.iter().any(|target| target.contains(tag)))
+                .map_err(ImageManagerError::ExifParse)?;
+        } else if container == ImageContainer::Tiff {
+            //TIFF image is Exif block itself, only metadata tags are removed
+            strip_exif_block(&mut data[block], &|tag| TIFF_METADATA_TAGS.contains(&tag))
+                .map_err(ImageManagerError::ExifParse)?;
+        } else {
+            remove_exif_block(&mut data, container);
+        }
+    }
+
+    //removed entries -> original entries, which aren't found in stripped copy
+    let mut remaining_entries = get_block_entries(&data, container);
+    let remaining_entries_count = remaining_entries.len();
+    let mut removed_entries = Vec::new();
+
+    for entry in original_entries {
+        let remaining_entry = remaining_entries.iter().position(|remaining| {
+            remaining.ifd == entry.ifd && remaining.tag == entry.tag && remaining.raw_value == entry.raw_value
+        });
+
+        match remaining_entry {
+            Some(index) => {
+                remaining_entries.swap_remove(index);
+            },
+            None => removed_entries.push(entry)
+        }
+    }
+
+    return Ok(
+        (
+            ExifStripReport {
+                file_name,
+                file_extension,
+                targets: targets.to_vec(),
+                removed_entries,
+                remaining_entries_count
+            },
+            data
+        )
+    )
+}
+
+//reads JPEG, WebP or TIFF image file bytes, returns image filename, extension, container and bytes
+fn read_exif_writable_image(path: &str) -> Result<(String, String, ImageContainer, Vec<u8>), ImageManagerError> {
+    let (file_name, file_extension, container) = check_image_file(path)?;
+
+    let container = match container {
+        Some(container @ (ImageContainer::Jpeg | ImageContainer::WebP | ImageContainer::Tiff)) => container,
+        _ => return Err(ImageManagerError::ExifWriteNotSupported { extension: file_extension })
+    };
+
+    let data = std::fs::read(path.trim()).map_err(|error| {
+        ImageManagerError::ImageOpen { path: path.to_string(), error: ImageError::IoError(error) }
+    })?;
+
+    return Ok((file_name, file_extension, container, data))
+}
+
+//validates and encodes editable tag value (text values are ASCII with null terminator)
+fn encode_tag_value(
+    tag: EditableExifTag,
+    value: &str,
+    little_endian: bool
+) -> Option<EncodedValue> {
+    let value = value.trim();
+
+    let text = match tag {
+        EditableExifTag::Orientation => {
+            let orientation = value.parse::<u16>().ok().filter(|orientation| (1..=8).contains(orientation))?;
+            let bytes = if little_endian { orientation.to_le_bytes() } else { orientation.to_be_bytes() };
+
+            return Some(EncodedValue { value_type: SHORT_TYPE, count: 1, bytes: bytes.to_vec(), text: orientation.to_string() })
+        },
+        EditableExifTag::DateTimeOriginal => {
+            //YYYY:MM:DD HH:MM:SS (YYYY-MM-DD and T separator are also accepted)
+            let parts: Vec<u32> = value
+                .split([':', '-', ' ', 'T'])
+                .map(|part| part.parse::<u32>().ok().filter(|_| part.len() == 2 || part.len() == 4))
+                .collect::<Option<Vec<u32>>>()?;
+
+            let [year, month, day, hours, minutes, seconds] = parts[..] else {
+                return None
+            };
+
+            if year < 1000 || !(1..=12).contains(&month) || !(1..=31).contains(&day) || hours > 23 || minutes > 59 || seconds > 59 {
+                return None
+            }
+
+            format!("{:04}:{:02}:{:02} {:02}:{:02}:{:02}", year, month, day, hours, minutes, seconds)
+        },
+        _ => {
+            if value.is_empty() || !value.chars().all(|character| character.is_ascii() && !character.is_ascii_control()) {
+                return None
+            }
+
+            value.to_string()
+        }
+    };
+
+    let mut bytes = text.clone().into_bytes();
+    bytes.push(0);
+
+    return Some(EncodedValue { value_type: ASCII_TYPE, count: bytes.len() as u32, bytes, text })
+}
+
+//reads IFD entries and next IFD offset
+fn read_ifd_entries(
+    block: &ExifBlock,
+    offset: usize
+) -> Result<(Vec<[u8; 12]>, u32), ExifError> {
+    let entries_count = block.read_u16(offset).ok_or(ExifError::IfdTruncated)? as usize;
+    let mut entries = Vec::new();
+
+    for index in 0..entries_count {
+        let entry_position = offset + 2 + index * 12;
+        let entry = block.data.get(entry_position..entry_position + 12).ok_or(ExifError::IfdTruncated)?;
+
+        entries.push(entry.try_into().map_err(|_| ExifError::IfdTruncated)?);
+    }
+
+    let next_ifd_offset = block.read_u32(offset + 2 + entries_count * 12).ok_or(ExifError::IfdTruncated)?;
+
+    return Ok((entries, next_ifd_offset))
+}
+
+//reads text (ASCII) or number (SHORT) entry value, None -> entry not found or has other type
+fn read_entry_text(
+    block: &ExifBlock,
+    entries: &[[u8; 12]],
+    tag_id: u16
+) -> Option<String> {
+    let entry = entries.iter().find(|entry| read_entry_u16(entry, 0, block.little_endian) == tag_id)?;
+    let value_type = read_entry_u16(entry, 2, block.little_endian);
+    let count = read_entry_u32(entry, 4, block.little_endian) as usize;
+
+    match value_type {
+        ASCII_TYPE => {
+            let bytes = if count <= 4 {
+                entry.get(8..8 + count)?
+            } else {
+                let value_offset = read_entry_u32(entry, 8, block.little_endian) as usize;
+                block.data.get(value_offset..value_offset.checked_add(count)?)?
+            };
+
+            return Some(String::from_utf8_lossy(bytes).trim_end_matches('\0').to_string())
+        },
+        SHORT_TYPE => Some(read_entry_u16(entry, 8, block.little_endian).to_string()),
+        _ => None
+    }
+}
+
+//appends bytes to block end (values and IFDs start at even offset), returns bytes offset
+fn append_bytes(
+    block_data: &mut Vec<u8>,
+    bytes: &[u8]
+) -> u32 {
+    if block_data.len() % 2 == 1 {
+        block_data.push(0);
+    }
+
+    let offset = block_data.len() as u32;
+    block_data.extend_from_slice(bytes);
+
+    return offset
+}
+
+//sets encoded values into IFD entries (existing entries are replaced, new entries are inserted by tag order)
+//old values, which don't fit into entry, are overwritten with zeros, new values are appended to block end
+fn set_ifd_entries(
+    block_data: &mut Vec<u8>,
+    little_endian: bool,
+    entries: &mut Vec<[u8; 12]>,
+    values: Vec<(u16, EncodedValue)>
+) {
+    for (tag_id, value) in values {
+        if let Some(index) = entries.iter().position(|entry| read_entry_u16(entry, 0, little_endian) == tag_id) {
+            let mut block = ExifBlock { data: block_data, little_endian };
+            let old_entry = entries.remove(index);
+            let old_size = get_value_type_size(read_entry_u16(&old_entry, 2, block.little_endian)).unwrap_or(0) * read_entry_u32(&old_entry, 4, block.little_endian) as usize;
+
+            if old_size > 4 {
+                let old_offset = read_entry_u32(&old_entry, 8, block.little_endian) as usize;
+                block.clear(old_offset..old_offset.saturating_add(old_size));
+            }
+        }
+
+        let mut entry = [0u8; 12];
+        let mut entry_block = ExifBlock { data: &mut entry, little_endian };
+
+        entry_block.write_u16(0, tag_id);
+        entry_block.write_u16(2, value.value_type);
+        entry_block.write_u32(4, value.count);
+
+        if value.bytes.len() <= 4 {
+            entry[8..8 + value.bytes.len()].copy_from_slice(&value.bytes);
+        } else {
+            let value_offset = append_bytes(block_data, &value.bytes);
+            ExifBlock { data: &mut entry, little_endian }.write_u32(8, value_offset);
+        }
+
+        let index = entries
+            .iter()
+            .position(|other| read_entry_u16(other, 0, little_endian) > tag_id)
+            .unwrap_or(entries.len());
+
+        entries.insert(index, entry);
+    }
+}
+
+//appends edited IFD to block end and wipes old IFD (None -> IFD is new), returns new IFD offset
+fn rewrite_ifd(
+    block_data: &mut Vec<u8>,
+    little_endian: bool,
+    old_offset: Option<usize>,
+    entries: &[[u8; 12]],
+    next_ifd_offset: u32
+) -> u32 {
+    let mut ifd = vec![0u8; 2 + entries.len() * 12 + 4];
+    let mut ifd_block = ExifBlock { data: &mut ifd, little_endian };
+
+    ifd_block.write_u16(0, entries.len() as u16);
+    ifd_block.write_u32(2 + entries.len() * 12, next_ifd_offset);
+
+    for (index, entry) in entries.iter().enumerate() {
+        ifd[2 + index * 12..2 + (index + 1) * 12].copy_from_slice(entry);
+    }
+
+    if let Some(old_offset) = old_offset {
+        let mut block = ExifBlock { data: block_data, little_endian };
+
+        if let Some(old_entries_count) = block.read_u16(old_offset) {
+            block.clear(old_offset..old_offset + 2 + old_entries_count as usize * 12 + 4);
+        }
+    }
+
+    return append_bytes(block_data, &ifd)
+}
+
+//sets tags values in Exif TIFF block (IFD0 and Exif sub-IFD), returns tags changes (old and new values)
+pub(crate) fn set_exif_block_tags(
+    block_data: &mut Vec<u8>,
+    edits: &[(EditableExifTag, String)]
+) -> Result<Vec<ExifTagChange>, ImageManagerError> {
+    let little_endian = match block_data.get(0..4) {
+        Some(b"II*\0") => true,
+        Some(b"MM\0*") => false,
+        _ => return Err(ImageManagerError::ExifParse(ExifError::TiffBadPreamble(String::from("Exif block doesn't start with TIFF header"))))
+    };
+
+    let block = ExifBlock { data: block_data, little_endian };
+    let ifd0_offset = block.read_u32(4).ok_or(ImageManagerError::ExifParse(ExifError::TiffTruncated))? as usize;
+    let (mut ifd0_entries, ifd1_offset) = read_ifd_entries(&block, ifd0_offset).map_err(ImageManagerError::ExifParse)?;
+
+    //Exif sub-IFD (None -> image has no Exif sub-IFD)
+    let exif_ifd_offset = ifd0_entries
+        .iter()
+        .find(|entry| read_entry_u16(entry, 0, block.little_endian) == EXIF_IFD_TAG)
+        .map(|entry| read_entry_u32(entry, 8, block.little_endian) as usize);
+
+    let (mut exif_entries, exif_next_offset) = match exif_ifd_offset {
+        Some(offset) => read_ifd_entries(&block, offset).map_err(ImageManagerError::ExifParse)?,
+        None => (Vec::new(), 0)
+    };
+
+    let mut changed_tags = Vec::new();
+    let mut ifd0_values = Vec::new();
+    let mut exif_values = Vec::new();
+
+    for (tag, value) in edits {
+        let encoded_value = encode_tag_value(*tag, value, little_endian).ok_or_else(|| {
+            ImageManagerError::InvalidExifValue {
+                tag: tag.name().to_string(),
+                value: value.clone(),
+                expected: tag.expected_value().to_string()
+            }
+        })?;
+
+        let (entries, values) = if tag.is_in_exif_ifd() {
+            (&exif_entries, &mut exif_values)
+        } else {
+            (&ifd0_entries, &mut ifd0_values)
+        };
+
+        changed_tags.push(
+            ExifTagChange {
+                tag: tag.name().to_string(),
+                old_value: read_entry_text(&block, entries, tag.tag_id()),
+                new_value: encoded_value.text.clone()
+            }
+        );
+
+        values.push((tag.tag_id(), encoded_value));
+    }
+
+    //Exif sub-IFD is rewritten first, IFD0 gets its new offset
+    if !exif_values.is_empty() {
+        set_ifd_entries(block_data, little_endian, &mut exif_entries, exif_values);
+
+        let new_exif_ifd_offset = rewrite_ifd(block_data, little_endian, exif_ifd_offset, &exif_entries, exif_next_offset);
+        let pointer_bytes = if little_endian { new_exif_ifd_offset.to_le_bytes() } else { new_exif_ifd_offset.to_be_bytes() };
+
+        ifd0_values.push((EXIF_IFD_TAG, EncodedValue { value_type: LONG_TYPE, count: 1, bytes: pointer_bytes.to_vec(), text: new_exif_ifd_offset.to_string() }));
+    }
+
+    set_ifd_entries(block_data, little_endian, &mut ifd0_entries, ifd0_values);
+
+    let new_ifd0_offset = rewrite_ifd(block_data, little_endian, Some(ifd0_offset), &ifd0_entries, ifd1_offset);
+    ExifBlock { data: block_data, little_endian }.write_u32(4, new_ifd0_offset);
+
+    return Ok(changed_tags)
+}
+
+//creates empty Exif TIFF block (big-endian header and IFD0 without entries)
+fn create_exif_block() -> Vec<u8> {
+    let mut block_data = b"MM\0*".to_vec();
+
+    block_data.extend_from_slice(&8u32.to_be_bytes()); //IFD0 offset
+    block_data.extend_from_slice(&[0; 6]); //entries count and next IFD offset
+
+    return block_data
+}
+
+//replaces Exif block in image or inserts new Exif block (JPEG APP1 segment, WebP EXIF chunk, PNG eXIf chunk)
+pub(crate) fn replace_exif_block(
+    data: &mut Vec<u8>,
+    container: ImageContainer,
+    block_data: Vec<u8>
+) -> Result<(), ImageManagerError> {
+    match container {
+        ImageContainer::Tiff => *data = block_data,
+        ImageContainer::Jpeg => {
+            if block_data.len() > MAX_JPEG_EXIF_BLOCK_SIZE {
+                return Err(ImageManagerError::ExifWrite { reason: String::from("Exif block is too big for JPEG APP1 segment (64 KB)") })
+            }
+
+            let mut segment = vec![0xFF, 0xE1];
+            segment.extend_from_slice(&((block_data.len() + EXIF_IDENTIFIER.len() + 2) as u16).to_be_bytes());
+            segment.extend_from_slice(EXIF_IDENTIFIER);
+            segment.extend_from_slice(&block_data);
+
+            //new segment goes after SOI and JFIF APP0 segment
+            let position = match (find_jpeg_exif_segments(data).first(), data.get(2..4), data.get(4..6)) {
+                (Some(old_segment), _, _) => {
+                    let position = old_segment.start;
+                    data.drain(old_segment.clone());
+                    position
+                },
+                (None, Some([0xFF, 0xE0]), Some(&[high, low])) => 4 + u16::from_be_bytes([high, low]) as usize,
+                (None, _, _) => 2
+            };
+
+            //broken APP0 segment length points past image end
+            if position > data.len() {
+                return Err(ImageManagerError::ExifWrite { reason: String::from("JPEG image is truncated") })
+            }
+
+            data.splice(position..position, segment);
+        },
+        ImageContainer::WebP => {
+            let mut chunk = b"EXIF".to_vec();
+            chunk.extend_from_slice(&(block_data.len() as u32).to_le_bytes());
+            chunk.extend_from_slice(&block_data);
+
+            if block_data.len() % 2 == 1 {
+                chunk.push(0);
+            }
+
+            //extended format (VP8X chunk) is required for EXIF chunk
+            if data.get(12..16) != Some(b"VP8X") {
+                let (width, height) = image::load_from_memory(data)
+                    .map(|image| (image.width(), image.height()))
+                    .map_err(|error| ImageManagerError::ExifWrite { reason: format!("WebP image couldn't be decoded: {}", error) })?;
+
+                let has_alpha = data.get(12..16) == Some(b"VP8L") && data.get(24).is_some_and(|byte| byte & 0x10 != 0);
+
+                let mut vp8x_chunk = b"VP8X".to_vec();
+                vp8x_chunk.extend_from_slice(&10u32.to_le_bytes());
+                vp8x_chunk.push(if has_alpha { 0x10 } else { 0 });
+                vp8x_chunk.extend_from_slice(&[0; 3]);
+                vp8x_chunk.extend_from_slice(&(width - 1).to_le_bytes()[..3]);
+                vp8x_chunk.extend_from_slice(&(height - 1).to_le_bytes()[..3]);
+
+                data.splice(12..12, vp8x_chunk);
+            }
+
+            data[20] |= WEBP_EXIF_FLAG;
+
+            //EXIF chunk goes before XMP chunk or to the end
+            let position = match find_webp_chunk(data, b"EXIF") {
+                Some((old_chunk, _)) => {
+                    let position = old_chunk.start;
+                    data.drain(old_chunk);
+                    position
+                },
+                None => find_webp_chunk(data, b"XMP ").map(|(xmp_chunk, _)| xmp_chunk.start).unwrap_or(data.len())
+            };
+
+            data.splice(position..position, chunk);
+
+            let riff_size = (data.len() - 8) as u32;
+            data[4..8].copy_from_slice(&riff_size.to_le_bytes());
+        },
+        ImageContainer::Png => {
+            //chunk: length, type, payload, CRC of type and payload
+            let mut chunk = (block_data.len() as u32).to_be_bytes().to_vec();
+            chunk.extend_from_slice(b"eXIf");
+            chunk.extend_from_slice(&block_data);
+
+            let mut crc = Crc::new();
+            crc.update(&chunk[4..]);
+            chunk.extend_from_slice(&crc.sum().to_be_bytes());
+
+            //old chunk is removed with its length and CRC, new chunk goes after IHDR chunk (before image data)
+            if let Some((_, old_payload)) = get_png_chunks(data).into_iter().find(|(chunk_type, _)| chunk_type == b"eXIf") {
+                data.drain(old_payload.start - 8..(old_payload.end + 4).min(data.len()));
+            }
+
+            let Some((_, header)) = get_png_chunks(data).into_iter().next() else {
+                return Err(ImageManagerError::ExifWrite { reason: String::from("PNG image has no header chunk") })
+            };
+
+            let position = header.end + 4;
+
+            if position > data.len() {
+                return Err(ImageManagerError::ExifWrite { reason: String::from("PNG image is truncated") })
+            }
+
+            data.splice(position..position, chunk);
+        },
+        _ => {}
+    }
+
+    return Ok(())
+}
+
+//sets Exif tags values in JPEG, WebP or TIFF image (image without Exif gets new Exif block)
+//returns set tags report and edited image bytes (image file isn't changed)
+pub fn set_exif_tags(
+    path: String,
+    edits: &[(String, String)]
+) -> Result<(ExifSetReport, Vec<u8>), ImageManagerError> {
+    let (file_name, file_extension, container, mut data) = read_exif_writable_image(&path)?;
+
+    let mut tags_edits: Vec<(EditableExifTag, String)> = Vec::new();
+
+    for (tag_name, value) in edits {
+        let tag = EditableExifTag::from_name(tag_name).ok_or_else(|| ImageManagerError::ExifTagNotEditable { tag: tag_name.clone() })?;
+
+        //last value of repeated tag is set
+        tags_edits.retain(|(other_tag, _)| *other_tag != tag);
+        tags_edits.push((tag, value.clone()));
+    }
+
+    let mut block_data = match find_exif_block(&data, container) {
+        Some(block) => data[block].to_vec(),
+        None => create_exif_block()
+    };
+
+    let changed_tags = set_exif_block_tags(&mut block_data, &tags_edits)?;
+
+    //written block is checked before saving
+    rexif::parse_buffer(&block_data).map_err(|error| ImageManagerError::ExifWrite { reason: format!("written Exif block is broken: {}", error) })?;
+
+    replace_exif_block(&mut data, container, block_data)?;
+
+    return Ok(
+        (
+            ExifSetReport {
+                file_name,
+                file_extension,
+                changed_tags
+            },
+            data
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use image::DynamicImage;
+
+    use crate::image_analysis::image_converter::{ encode_image, ConversionFormat };
+
+    //test block tags: IFD0 (Make, Orientation), Exif sub-IFD (DateTimeOriginal, BodySerialNumber), GPS sub-IFD (version, latitude ref)
+    const MAKE_TAG: u16 = 0x010F;
+    const ORIENTATION_TAG: u16 = 0x0112;
+    const DATE_TIME_ORIGINAL_TAG: u16 = 0x9003;
+    const BODY_SERIAL_NUMBER_TAG: u16 = 0xA431;
+    const GPS_VERSION_TAG: u16 = 0x0000;
+    const GPS_LATITUDE_REF_TAG: u16 = 0x0001;
+
+    const SERIAL_NUMBER: &[u8] = b"SN12345\0";
+
+    //builds Exif TIFF block with IFD0, Exif and GPS sub-IFDs (little endian -> "II" header, big endian -> "MM" header)
+    fn build_exif_block(little_endian: bool) -> Vec<u8> {
+        let u16_bytes = |value: u16| if little_endian { value.to_le_bytes() } else { value.to_be_bytes() };
+        let u32_bytes = |value: u32| if little_endian { value.to_le_bytes() } else { value.to_be_bytes() };
+
+        //entry: tag, type, count, value (values up to 4 bytes) or value offset
+        let entry = |tag: u16, value_type: u16, count: u32, value: [u8; 4]| -> Vec<u8> {
+            let mut entry = u16_bytes(tag).to_vec();
+            entry.extend_from_slice(&u16_bytes(value_type));
+            entry.extend_from_slice(&u32_bytes(count));
+            entry.extend_from_slice(&value);
+            entry
+        };
+
+        let short_value = |value: u16| { let bytes = u16_bytes(value); [bytes[0], bytes[1], 0, 0] };
+
+        //layout: header (8), IFD0 (54), Exif IFD (30), GPS IFD (30), DateTimeOriginal (20), BodySerialNumber (8)
+        let (ifd0_offset, exif_ifd_offset, gps_ifd_offset) = (8u32, 62u32, 92u32);
+        let (date_offset, serial_offset) = (122u32, 142u32);
+
+        let mut block = if little_endian { b"II*\0".to_vec() } else { b"MM\0*".to_vec() };
+        block.extend_from_slice(&u32_bytes(ifd0_offset));
+
+        block.extend_from_slice(&u16_bytes(4));
+        block.extend(entry(MAKE_TAG, ASCII_TYPE, 4, *b"Cam\0"));
+        block.extend(entry(ORIENTATION_TAG, SHORT_TYPE, 1, short_value(6)));
+        block.extend(entry(EXIF_IFD_TAG, LONG_TYPE, 1, u32_bytes(exif_ifd_offset)));
+        block.extend(entry(GPS_IFD_TAG, LONG_TYPE, 1, u32_bytes(gps_ifd_offset)));
+        block.extend_from_slice(&u32_bytes(0));
+
+        block.extend_from_slice(&u16_bytes(2));
+        block.extend(entry(DATE_TIME_ORIGINAL_TAG, ASCII_TYPE, 20, u32_bytes(date_offset)));
+        block.extend(entry(BODY_SERIAL_NUMBER_TAG, ASCII_TYPE, SERIAL_NUMBER.len() as u32, u32_bytes(serial_offset)));
+        block.extend_from_slice(&u32_bytes(0));
+
+        block.extend_from_slice(&u16_bytes(2));
+        block.extend(entry(GPS_VERSION_TAG, 1, 4, [2, 3, 0, 0]));
+        block.extend(entry(GPS_LATITUDE_REF_TAG, ASCII_TYPE, 2, *b"N\0\0\0"));
+        block.extend_from_slice(&u32_bytes(0));
+
+        block.extend_from_slice(b"2024:01:02 03:04:05\0");
+        block.extend_from_slice(SERIAL_NUMBER);
+
+        assert_eq!(block.len(), serial_offset as usize + SERIAL_NUMBER.len());
+
+        return block
+    }
+
+    //parses Exif block with rexif, returns entries tags numbers (without sub-IFDs pointers) and raw values
+    fn parse_block_entries(block: &[u8]) -> Vec<(u16, String)> {
+        let exif = rexif::parse_buffer(block).expect("Exif block should parse");
+
+        return get_exif_entries_info(&exif.entries)
+            .into_iter()
+            .filter(|entry| !matches!(entry.tag_id, EXIF_IFD_TAG | GPS_IFD_TAG | INTEROPERABILITY_IFD_TAG))
+            .map(|entry| (entry.tag_id, entry.raw_value))
+            .collect()
+    }
+
+    fn entry_value(
+        entries: &[(u16, String)],
+        tag: u16
+    ) -> Option<String> {
+        return entries.iter().find(|(entry_tag, _)| *entry_tag == tag).map(|(_, value)| value.clone())
+    }
+
+    fn tags_of(entries: &[(u16, String)]) -> Vec<u16> {
+        let mut tags: Vec<u16> = entries.iter().map(|(tag, _)| *tag).collect();
+        tags.sort();
+
+        return tags
+    }
+
+    //encodes small image to JPEG, PNG or WebP (image crate encoders write no Exif block)
+    fn encode_test_image(container: ImageContainer) -> Vec<u8> {
+        let format = match container {
+            ImageContainer::Jpeg => ConversionFormat::Jpeg,
+            ImageContainer::Png => ConversionFormat::Png,
+            _ => ConversionFormat::WebP
+        };
+
+        return encode_image(DynamicImage::new_rgb8(8, 8), format, 90).expect("test image should encode")
+    }
+
+    #[test]
+    fn built_block_parses_in_both_byte_orders() {
+        for little_endian in [true, false] {
+            let entries = parse_block_entries(&build_exif_block(little_endian));
+
+            assert_eq!(
+                tags_of(&entries),
+                vec![GPS_VERSION_TAG, GPS_LATITUDE_REF_TAG, MAKE_TAG, ORIENTATION_TAG, DATE_TIME_ORIGINAL_TAG, BODY_SERIAL_NUMBER_TAG]
+            );
+            assert_eq!(entry_value(&entries, ORIENTATION_TAG).as_deref(), Some("6"));
+        }
+    }
+
+    #[test]
+    fn strip_gps_removes_only_gps_entries() {
+        for little_endian in [true, false] {
+            let mut block = build_exif_block(little_endian);
+
+            strip_exif_block(&mut block, &|tag| ExifStripTarget::Gps.contains(tag)).unwrap();
+
+            assert_eq!(
+                tags_of(&parse_block_entries(&block)),
+                vec![MAKE_TAG, ORIENTATION_TAG, DATE_TIME_ORIGINAL_TAG, BODY_SERIAL_NUMBER_TAG]
+            );
+        }
+    }
+
+    #[test]
+    fn strip_serial_removes_only_serial_entries_and_wipes_values() {
+        for little_endian in [true, false] {
+            let mut block = build_exif_block(little_endian);
+
+            strip_exif_block(&mut block, &|tag| ExifStripTarget::Serial.contains(tag)).unwrap();
+
+            assert_eq!(
+                tags_of(&parse_block_entries(&block)),
+                vec![GPS_VERSION_TAG, GPS_LATITUDE_REF_TAG, MAKE_TAG, ORIENTATION_TAG, DATE_TIME_ORIGINAL_TAG]
+            );
+            assert!(!block.windows(SERIAL_NUMBER.len()).any(|window| window == SERIAL_NUMBER));
+        }
+    }
+
+    #[test]
+    fn set_tags_output_reparses_with_new_values() {
+        for little_endian in [true, false] {
+            let mut block = build_exif_block(little_endian);
+
+            let edits = [
+                (EditableExifTag::Artist, String::from("Jane Doe")),
+                (EditableExifTag::Orientation, String::from("1")),
+                (EditableExifTag::DateTimeOriginal, String::from("2025-06-01 12:30:00")),
+                (EditableExifTag::Copyright, String::from("(c) 2026 Jane Doe"))
+            ];
+
+            let changes = set_exif_block_tags(&mut block, &edits).unwrap();
+
+            let orientation_change = changes.iter().find(|change| change.tag == "Orientation").unwrap();
+            assert_eq!(orientation_change.old_value.as_deref(), Some("6"));
+            assert!(changes.iter().find(|change| change.tag == "Artist").unwrap().old_value.is_none());
+
+            let entries = parse_block_entries(&block);
+
+            assert_eq!(entry_value(&entries, 0x013B).as_deref(), Some("Jane Doe"));
+            assert_eq!(entry_value(&entries, 0x8298).as_deref(), Some("(c) 2026 Jane Doe"));
+            assert_eq!(entry_value(&entries, ORIENTATION_TAG).as_deref(), Some("1"));
+            assert_eq!(entry_value(&entries, DATE_TIME_ORIGINAL_TAG).as_deref(), Some("2025:06:01 12:30:00"));
+
+            //not edited entries are kept
+            assert_eq!(entry_value(&entries, MAKE_TAG).as_deref(), Some("Cam"));
+            assert!(entry_value(&entries, BODY_SERIAL_NUMBER_TAG).is_some());
+            assert!(entry_value(&entries, GPS_LATITUDE_REF_TAG).is_some());
+        }
+    }
+
+    #[test]
+    fn invalid_tag_value_is_rejected() {
+        let mut block = build_exif_block(true);
+
+        let result = set_exif_block_tags(&mut block, &[(EditableExifTag::Orientation, String::from("9"))]);
+
+        assert!(matches!(result, Err(ImageManagerError::InvalidExifValue { .. })));
+    }
+
+    #[test]
+    fn exif_block_round_trips_through_containers() {
+        for container in [ImageContainer::Jpeg, ImageContainer::Png, ImageContainer::WebP] {
+            for little_endian in [true, false] {
+                let mut data = encode_test_image(container);
+                assert!(find_exif_block(&data, container).is_none());
+
+                //inserting new block
+                let block = build_exif_block(little_endian);
+                replace_exif_block(&mut data, container, block.clone()).unwrap();
+
+                assert_eq!(&data[find_exif_block(&data, container).unwrap()], block.as_slice());
+                image::load_from_memory(&data).expect("image with inserted Exif block should decode");
+
+                //replacing block with edited block (old block is removed, not duplicated)
+                let mut edited_block = data[find_exif_block(&data, container).unwrap()].to_vec();
+                set_exif_block_tags(&mut edited_block, &[(EditableExifTag::Artist, String::from("Jane Doe"))]).unwrap();
+                replace_exif_block(&mut data, container, edited_block.clone()).unwrap();
+
+                assert_eq!(&data[find_exif_block(&data, container).unwrap()], edited_block.as_slice());
+                assert_eq!(entry_value(&parse_block_entries(&edited_block), 0x013B).as_deref(), Some("Jane Doe"));
+                image::load_from_memory(&data).expect("image with replaced Exif block should decode");
+
+                match container {
+                    ImageContainer::Jpeg => assert_eq!(find_jpeg_exif_segments(&data).len(), 1),
+                    ImageContainer::Png => assert_eq!(get_png_chunks(&data).iter().filter(|(chunk_type, _)| chunk_type == b"eXIf").count(), 1),
+                    _ => {
+                        assert_eq!(u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize, data.len() - 8);
+                        assert_eq!(&data[12..16], b"VP8X");
+                        assert_ne!(data[20] & WEBP_EXIF_FLAG, 0);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn exif_block_is_removed_from_jpeg_and_webp() {
+        for container in [ImageContainer::Jpeg, ImageContainer::WebP] {
+            let mut data = encode_test_image(container);
+
+            replace_exif_block(&mut data, container, build_exif_block(true)).unwrap();
+            remove_exif_block(&mut data, container);
+
+            assert!(find_exif_block(&data, container).is_none());
+            image::load_from_memory(&data).expect("image without Exif block should decode");
+        }
+    }
+
+    #[test]
+    fn truncated_jpeg_app0_segment_is_rejected() {
+        let mut data = vec![0xFF, 0xD8, 0xFF, 0xE0, 0xFF, 0xFF];
+
+        let result = replace_exif_block(&mut data, ImageContainer::Jpeg, build_exif_block(true));
+
+        assert!(matches!(result, Err(ImageManagerError::ExifWrite { .. })));
+    }
+}
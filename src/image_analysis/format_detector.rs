@@ -0,0 +1,176 @@
+/*
+==================================================================================
+| This module detects image container format by file content (magic bytes      |
+| signatures), not by file extension: extensionless images are recognized and  |
+| files with wrong extension (.png, which is JPEG or HTML page) are detected.   |
+| {Enums:}                                                                       |
+| 1) ImageContainer - image container formats recognized by signatures           |
+| {Functions:}                                                                   |
+| 1) detect_image_container() - detects image container from file header bytes  |
+| 2) detect_file_image_container() - reads file header and detects container    |
+| 3) detect_ftyp_container() - detects AVIF/HEIF from ISO BMFF ftyp box brands   |
+| 4) is_detectable_extension() - checks if extension belongs to detectable format|
+| 5) check_extension_mismatch() - checks if extension doesn't match content      |
+==================================================================================
+*/
+
+use std::fs::File;
+use std::io::{ self, Read };
+use std::path::Path;
+
+use serde::Serialize;
+
+//file header bytes count read for detection (ftyp box with several compatible brands fits in it)
+const FILE_HEADER_SIZE: usize = 64;
+
+//all detectable image containers
+pub const IMAGE_CONTAINERS: [ImageContainer; 10] = [
+    ImageContainer::Png, ImageContainer::Jpeg, ImageContainer::Gif, ImageContainer::WebP, ImageContainer::Tiff,
+    ImageContainer::Bmp, ImageContainer::Ico, ImageContainer::Avif, ImageContainer::Heif, ImageContainer::Psd
+];
+
+//image container formats recognized by file signatures
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageContainer {
+    Png,
+    Jpeg,
+    Gif,
+    WebP,
+    Tiff,
+    Bmp,
+    Ico,
+    Avif,
+    Heif,
+    Psd
+}
+
+impl ImageContainer {
+    //returns container name (same as in JSON output)
+    pub fn name(&self) -> &'static str {
+        match self {
+            ImageContainer::Png => "png",
+            ImageContainer::Jpeg => "jpeg",
+            ImageContainer::Gif => "gif",
+            ImageContainer::WebP => "webp",
+            ImageContainer::Tiff => "tiff",
+            ImageContainer::Bmp => "bmp",
+            ImageContainer::Ico => "ico",
+            ImageContainer::Avif => "avif",
+            ImageContainer::Heif => "heif",
+            ImageContainer::Psd => "psd"
+        }
+    }
+
+    //returns file extensions used for container (first is main extension)
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            ImageContainer::Png => &["png", "apng"],
+            ImageContainer::Jpeg => &["jpg", "jpeg", "jpe", "jfif"],
+            ImageContainer::Gif => &["gif"],
+            ImageContainer::WebP => &["webp"],
+            ImageContainer::Tiff => &["tiff", "tif"],
+            ImageContainer::Bmp => &["bmp", "dib"],
+            ImageContainer::Ico => &["ico"],
+            ImageContainer::Avif => &["avif"],
+            ImageContainer::Heif => &["heic", "heif", "hif"],
+            ImageContainer::Psd => &["psd"]
+        }
+    }
+
+    //checks if file extension matches container (case is ignored)
+    pub fn matches_extension(&self, extension: &str) -> bool {
+        return self.extensions().iter().any(|container_extension| container_extension.eq_ignore_ascii_case(extension))
+    }
+}
+
+//detects image container from file header bytes (None -> not recognized image)
+pub fn detect_image_container(header: &[u8]) -> Option<ImageContainer> {
+    if header.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return Some(ImageContainer::Png)
+    }
+
+    if header.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some(ImageContainer::Jpeg)
+    }
+
+    if header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a") {
+        return Some(ImageContainer::Gif)
+    }
+
+    if header.len() >= 12 && &header[0..4] == b"RIFF" && &header[8..12] == b"WEBP" {
+        return Some(ImageContainer::WebP)
+    }
+
+    //little endian (II*\0) and big endian (MM\0*) TIFF, BigTIFF (version 43)
+    if header.starts_with(b"II*\0") || header.starts_with(b"MM\0*") || header.starts_with(b"II+\0") || header.starts_with(b"MM\0+") {
+        return Some(ImageContainer::Tiff)
+    }
+
+    if header.starts_with(b"BM") && header.len() >= 14 {
+        return Some(ImageContainer::Bmp)
+    }
+
+    //ICO: reserved 0, type 1, images count greater than 0
+    if header.len() >= 6 && header[0..4] == [0, 0, 1, 0] && (header[4] != 0 || header[5] != 0) {
+        return Some(ImageContainer::Ico)
+    }
+
+    if header.starts_with(b"8BPS") {
+        return Some(ImageContainer::Psd)
+    }
+
+    return detect_ftyp_container(header)
+}
+
+//reads file header and detects image container
+pub fn detect_file_image_container(path: &Path) -> io::Result<Option<ImageContainer>> {
+    let mut header = Vec::with_capacity(FILE_HEADER_SIZE);
+
+    File::open(path)?.take(FILE_HEADER_SIZE as u64).read_to_end(&mut header)?;
+
+    return Ok(detect_image_container(&header))
+}
+
+//detects AVIF/HEIF from ISO BMFF ftyp box major and compatible brands
+fn detect_ftyp_container(header: &[u8]) -> Option<ImageContainer> {
+    if header.len() < 12 || &header[4..8] != b"ftyp" {
+        return None
+    }
+
+    let box_size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let box_end = box_size.clamp(12, header.len());
+
+    //major brand (8..12), minor version (12..16), compatible brands (16..box end)
+    let brands = std::iter::once(&header[8..12]).chain(header.get(16..box_end).unwrap_or(&[]).chunks_exact(4));
+
+    let mut container = None;
+
+    for brand in brands {
+        match brand {
+            b"avif" | b"avis" => return Some(ImageContainer::Avif),
+            b"heic" | b"heix" | b"hevc" | b"hevx" | b"heim" | b"heis" | b"mif1" | b"msf1" => container = Some(ImageContainer::Heif),
+            _ => {}
+        }
+    }
+
+    return container
+}
+
+//checks if extension belongs to format detectable by content (svg, raw... aren't detectable)
+pub fn is_detectable_extension(extension: &str) -> bool {
+    return IMAGE_CONTAINERS.iter().any(|container| container.matches_extension(extension))
+}
+
+//checks if file extension doesn't match detected container
+//(also true when file has detectable image extension, but content isn't recognized image, for example HTML page)
+pub fn check_extension_mismatch(
+    container: Option<ImageContainer>,
+    extension: Option<&str>
+) -> bool {
+    match (container, extension) {
+        (Some(container), Some(extension)) => !container.matches_extension(extension),
+        (None, Some(extension)) => is_detectable_extension(extension),
+        _ => false //extensionless file isn't mismatch
+    }
+}
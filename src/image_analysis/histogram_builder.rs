@@ -0,0 +1,63 @@
+/*
+==================================================================================
+| This module builds image 256-bin histograms for red, green, blue channels and  |
+| luminance (Rec. 709 weights: 0.2126 R + 0.7152 G + 0.0722 B).                  |
+| {Functions:}                                                                   |
+| 1) get_luminance() - counts pixel luminance (0-255)                            |
+| 2) get_image_histogram() - builds image channels and luminance histograms      |
+==================================================================================
+*/
+
+use crate::image_analysis::image_manager::{ check_image_file, load_image };
+use crate::image_analysis::image_reports::*;
+
+//histogram bins count (one bin for every channel value)
+pub const HISTOGRAM_BINS_COUNT: usize = 256;
+
+//counts pixel luminance (0-255)
+pub(crate) fn get_luminance(red: u8, green: u8, blue: u8) -> u8 {
+    let luminance = 0.2126 * red as f64 + 0.7152 * green as f64 + 0.0722 * blue as f64;
+
+    return luminance.round().min(255.0) as u8
+}
+
+//builds image red, green, blue and luminance histograms (fully transparent pixels are skipped)
+pub fn get_image_histogram(path: String) -> Result<HistogramReport, ImageManagerError> {
+    let (file_name, _, _) = check_image_file(&path)?;
+
+    let img = match load_image(path.trim()) {
+        Ok(img) => img,
+        Err(error) => return Err(ImageManagerError::ImageOpen { path, error })
+    };
+
+    let mut red = vec![0u64; HISTOGRAM_BINS_COUNT];
+    let mut green = vec![0u64; HISTOGRAM_BINS_COUNT];
+    let mut blue = vec![0u64; HISTOGRAM_BINS_COUNT];
+    let mut luminance = vec![0u64; HISTOGRAM_BINS_COUNT];
+    let mut pixels_count = 0;
+
+    for pixel in img.to_rgba8().pixels() {
+        let [red_value, green_value, blue_value, alpha] = pixel.0;
+
+        if alpha == 0 {
+            continue
+        }
+
+        red[red_value as usize] += 1;
+        green[green_value as usize] += 1;
+        blue[blue_value as usize] += 1;
+        luminance[get_luminance(red_value, green_value, blue_value) as usize] += 1;
+        pixels_count += 1;
+    }
+
+    return Ok(
+        HistogramReport {
+            file_name,
+            pixels_count,
+            red,
+            green,
+            blue,
+            luminance
+        }
+    )
+}
@@ -0,0 +1,305 @@
+/*
+==================================================================================
+| This module extracts embedded ICC color profiles (JPEG APP2 segments, PNG iCCP |
+| chunk, WebP ICCP chunk, TIFF tag 34675) and parses profile header and tags:    |
+| description, version, device class, color space, PCS and rendering intent.    |
+| sRGB, Display P3 and Adobe RGB (1998) are recognized by primaries colorants   |
+| (rXYZ, gXYZ, bXYZ tags) or, if colorants are missing, by description.          |
+| {Functions:}                                                                   |
+| 1) find_icc_profile() - finds ICC profile bytes in image                       |
+| 2) read_u32() - reads big endian 32-bit number from profile                    |
+| 3) get_signature_text() - converts 4-byte signature to text                    |
+| 4) get_device_class_name() - gets profile device class name                    |
+| 5) get_rendering_intent_name() - gets rendering intent name                    |
+| 6) find_profile_tag() - finds profile tag data                                 |
+| 7) get_profile_description() - reads profile description (desc tag)           |
+| 8) read_xyz_tag() - reads XYZ tag values                                       |
+| 9) get_known_profile_name() - recognizes sRGB, Display P3, Adobe RGB profiles  |
+| 10) parse_icc_profile() - parses ICC profile header and description            |
+| 11) get_file_icc_profile() - reads image file ICC profile                      |
+| 11.1) get_icc_profile_from_data() - reads ICC profile from image bytes         |
+| 12) get_image_icc_profile() - gets image ICC profile info and bytes            |
+==================================================================================
+*/
+
+use std::path::Path;
+
+use crate::image_analysis::image_manager::check_image_file;
+use crate::image_analysis::format_detector::ImageContainer;
+use crate::image_analysis::container_reader::*;
+use crate::image_analysis::image_reports::*;
+
+//JPEG APP2 ICC profile segment identifier (followed by chunk number and chunks count)
+const ICC_IDENTIFIER: &[u8] = b"ICC_PROFILE\0";
+
+//TIFF ICC profile tag
+const TIFF_ICC_TAG: u16 = 0x8773;
+
+//ICC profile header size and header signature ("acsp" at offset 36)
+const ICC_HEADER_SIZE: usize = 128;
+const ICC_PROFILE_SIGNATURE: &[u8] = b"acsp";
+
+//multiLocalizedUnicodeType record size: language, country, string length, string offset
+const MLUC_RECORD_SIZE: usize = 12;
+
+//max colorant difference for known profiles recognition
+const COLORANT_TOLERANCE: f64 = 0.005;
+
+//known RGB profiles primaries colorants (D50 adapted XYZ of red, green, blue): (name, description keyword, colorants)
+const KNOWN_PROFILES: &[(&str, &str, [[f64; 3]; 3])] = &[
+    ("sRGB", "sRGB", [[0.4361, 0.2225, 0.0139], [0.3851, 0.7169, 0.0971], [0.1431, 0.0606, 0.7141]]),
+    ("Display P3", "Display P3", [[0.5151, 0.2412, -0.0011], [0.2920, 0.6922, 0.0419], [0.1571, 0.0666, 0.7841]]),
+    ("Adobe RGB (1998)", "Adobe RGB", [[0.6097, 0.3111, 0.0195], [0.2053, 0.6257, 0.0609], [0.1492, 0.0632, 0.7446]])
+];
+
+//finds ICC profile bytes in image (None -> no profile embedded)
+fn find_icc_profile(
+    data: &[u8],
+    container: ImageContainer
+) -> Option<Vec<u8>> {
+    match container {
+        ImageContainer::Jpeg => {
+            //big profile is split into several APP2 segments: identifier, chunk number (from 1), chunks count, chunk
+            let mut chunks: Vec<(u8, &[u8])> = get_jpeg_segments(data)
+                .into_iter()
+                .filter(|(marker, payload)| *marker == 0xE2 && payload.len() > ICC_IDENTIFIER.len() + 2 && data[payload.clone()].starts_with(ICC_IDENTIFIER))
+                .map(|(_, payload)| (data[payload.start + ICC_IDENTIFIER.len()], &data[payload.start + ICC_IDENTIFIER.len() + 2..payload.end]))
+                .collect();
+
+            if chunks.is_empty() {
+                return None
+            }
+
+            chunks.sort_by_key(|(number, _)| *number);
+
+            return Some(chunks.into_iter().flat_map(|(_, chunk)| chunk.iter().copied()).collect())
+        },
+        ImageContainer::Png => {
+            //iCCP: profile name, null, compression method, zlib compressed profile
+            let (_, payload) = get_png_chunks(data).into_iter().find(|(chunk_type, _)| chunk_type == b"iCCP")?;
+            let chunk = &data[payload];
+            let name_end = chunk.iter().position(|byte| *byte == 0)?;
+
+            return inflate_zlib(chunk.get(name_end + 2..)?)
+        },
+        ImageContainer::WebP => get_webp_chunk(data, b"ICCP").map(|payload| data[payload].to_vec()),
+        ImageContainer::Tiff => get_tiff_ifd0_value(data, TIFF_ICC_TAG).map(|value| value.to_vec()),
+        _ => None
+    }
+}
+
+//reads big endian 32-bit number from profile (ICC profiles are always big endian)
+fn read_u32(
+    profile: &[u8],
+    position: usize
+) -> Option<u32> {
+    let bytes = profile.get(position..position.checked_add(4)?)?;
+
+    return Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+//converts 4-byte signature to text without padding spaces ("RGB " -> "RGB")
+fn get_signature_text(signature: &[u8]) -> String {
+    return String::from_utf8_lossy(signature).trim_end_matches([' ', '\0']).to_string()
+}
+
+//gets profile device class name (mntr -> display device)
+fn get_device_class_name(signature: &[u8]) -> String {
+    let name = match signature {
+        b"scnr" => "input device",
+        b"mntr" => "display device",
+        b"prtr" => "output device",
+        b"link" => "device link",
+        b"spac" => "color space",
+        b"abst" => "abstract",
+        b"nmcl" => "named color",
+        _ => return get_signature_text(signature)
+    };
+
+    return name.to_string()
+}
+
+//gets rendering intent name
+fn get_rendering_intent_name(intent: u32) -> String {
+    let name = match intent {
+        0 => "perceptual",
+        1 => "media-relative colorimetric",
+        2 => "saturation",
+        3 => "ICC-absolute colorimetric",
+        _ => return format!("unknown ({})", intent)
+    };
+
+    return name.to_string()
+}
+
+//finds profile tag data in tags table (table starts after header: tags count, then signature, offset, size of every tag)
+fn find_profile_tag<'a>(
+    profile: &'a [u8],
+    signature: &[u8; 4]
+) -> Option<&'a [u8]> {
+    let tags_count = read_u32(profile, ICC_HEADER_SIZE)? as usize;
+
+    for index in 0..tags_count {
+        let entry = ICC_HEADER_SIZE + 4 + index * 12;
+
+        if profile.get(entry..entry + 4)? != signature {
+            continue
+        }
+
+        let offset = read_u32(profile, entry + 4)? as usize;
+        let size = read_u32(profile, entry + 8)? as usize;
+
+        return profile.get(offset..offset.checked_add(size)?)
+    }
+
+    return None
+}
+
+//reads profile description: textDescriptionType (ICC v2, ASCII), multiLocalizedUnicodeType (ICC v4, UTF-16, English preferred) or textType
+fn get_profile_description(profile: &[u8]) -> Option<String> {
+    let tag = find_profile_tag(profile, b"desc")?;
+
+    let description = match tag.get(0..4)? {
+        b"desc" => {
+            let length = read_u32(tag, 8)? as usize;
+
+            String::from_utf8_lossy(tag.get(12..length.checked_add(12)?)?).to_string()
+        },
+        b"mluc" => {
+            let records_count = read_u32(tag, 8)? as usize;
+            let record_size = read_u32(tag, 12)? as usize;
+
+            //record: language, country, string length, string offset (from tag start)
+            if record_size < MLUC_RECORD_SIZE {
+                return None
+            }
+
+            //records positions are counted lazily, records count is checked against tag size (broken count can be huge)
+            let mut records = (0..records_count)
+                .map_while(|index| index.checked_mul(record_size)?.checked_add(16))
+                .take_while(|record| record.checked_add(MLUC_RECORD_SIZE).is_some_and(|record_end| record_end <= tag.len()));
+
+            let first_record = records.next()?;
+
+            let record = std::iter::once(first_record)
+                .chain(records)
+                .find(|record| tag.get(*record..*record + 2) == Some(b"en"))
+                .unwrap_or(first_record);
+
+            let length = read_u32(tag, record + 4)? as usize;
+            let offset = read_u32(tag, record + 8)? as usize;
+
+            let units: Vec<u16> = tag
+                .get(offset..offset.checked_add(length)?)?
+                .chunks_exact(2)
+                .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+                .collect();
+
+            String::from_utf16_lossy(&units)
+        },
+        b"text" => String::from_utf8_lossy(tag.get(8..)?).to_string(),
+        _ => return None
+    };
+
+    let description = description.trim_matches(|character: char| character == '\0' || character.is_whitespace());
+
+    if description.is_empty() {
+        return None
+    }
+
+    return Some(description.to_string())
+}
+
+//reads XYZ tag values (s15Fixed16Number numbers)
+fn read_xyz_tag(
+    profile: &[u8],
+    signature: &[u8; 4]
+) -> Option<[f64; 3]> {
+    let tag = find_profile_tag(profile, signature)?;
+
+    if tag.get(0..4)? != b"XYZ " {
+        return None
+    }
+
+    let value = |position: usize| read_u32(tag, position).map(|number| number as i32 as f64 / 65536.0);
+
+    return Some([value(8)?, value(12)?, value(16)?])
+}
+
+//recognizes sRGB, Display P3 and Adobe RGB (1998) profiles by primaries colorants or by description
+fn get_known_profile_name(
+    profile: &[u8],
+    description: Option<&str>
+) -> Option<String> {
+    let colorants = [read_xyz_tag(profile, b"rXYZ"), read_xyz_tag(profile, b"gXYZ"), read_xyz_tag(profile, b"bXYZ")];
+
+    if let [Some(red), Some(green), Some(blue)] = colorants {
+        let known_profile = KNOWN_PROFILES.iter().find(|(_, _, known_colorants)| {
+            known_colorants
+                .iter()
+                .zip([red, green, blue])
+                .all(|(known, colorant)| known.iter().zip(colorant).all(|(known, value)| (known - value).abs() <= COLORANT_TOLERANCE))
+        });
+
+        return known_profile.map(|(name, _, _)| name.to_string())
+    }
+
+    let description = description?;
+
+    return KNOWN_PROFILES
+        .iter()
+        .find(|(_, keyword, _)| description.contains(keyword))
+        .map(|(name, _, _)| name.to_string())
+}
+
+//parses ICC profile header and description (None -> profile header is broken)
+fn parse_icc_profile(profile: &[u8]) -> Option<IccProfileInfo> {
+    if profile.len() < ICC_HEADER_SIZE || profile.get(36..40)? != ICC_PROFILE_SIGNATURE {
+        return None
+    }
+
+    //version: major, minor (high 4 bits) and bug fix (low 4 bits) numbers
+    let version = format!("{}.{}.{}", profile[8], profile[9] >> 4, profile[9] & 0x0F);
+    let description = get_profile_description(profile);
+    let known_profile = get_known_profile_name(profile, description.as_deref());
+
+    return Some(
+        IccProfileInfo {
+            description,
+            version,
+            device_class: get_device_class_name(&profile[12..16]),
+            color_space: get_signature_text(&profile[16..20]),
+            pcs: get_signature_text(&profile[20..24]),
+            rendering_intent: get_rendering_intent_name(read_u32(profile, 64)?),
+            known_profile,
+            size: profile.len() as u64
+        }
+    )
+}
+
+//reads image file ICC profile info and bytes (None -> file can't be read, no profile embedded or profile is broken)
+pub(crate) fn get_file_icc_profile(
+    path: &Path,
+    container: Option<ImageContainer>
+) -> Option<(IccProfileInfo, Vec<u8>)> {
+    let data = std::fs::read(path).ok()?;
+
+    return get_icc_profile_from_data(&data, container?)
+}
+
+//reads ICC profile info and bytes from already read image file bytes (None -> no profile embedded or profile is broken)
+pub(crate) fn get_icc_profile_from_data(
+    data: &[u8],
+    container: ImageContainer
+) -> Option<(IccProfileInfo, Vec<u8>)> {
+    let profile = find_icc_profile(data, container)?;
+
+    return Some((parse_icc_profile(&profile)?, profile))
+}
+
+//gets image ICC profile info and profile bytes (for saving profile to .icc file)
+pub fn get_image_icc_profile(path: String) -> Result<(IccProfileInfo, Vec<u8>), ImageManagerError> {
+    let (file_name, _, container) = check_image_file(&path)?; //checks that file is readable image
+
+    return get_file_icc_profile(Path::new(path.trim()), container).ok_or(ImageManagerError::IccProfileNotFound { file_name })
+}
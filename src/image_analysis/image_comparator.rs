@@ -0,0 +1,228 @@
+/*
+==================================================================================
+| This module compares two images: dimensions and color models differences, MSE |
+| (mean squared error of RGBA channels), PSNR (peak signal-to-noise ratio) and   |
+| SSIM (structural similarity of luminance, 8x8 windows with step 4). Visual     |
+| difference heatmap is built from every pixel max channel difference.           |
+| {Functions:}                                                                   |
+| 1) get_compared_image_info() - gets compared image simple data                 |
+| 2) get_luminance_plane() - converts image to luminance values                  |
+| 3) get_windows_positions() - counts SSIM windows positions on one axis         |
+| 4) get_ssim() - counts mean SSIM of luminance planes                           |
+| 5) get_heatmap_color() - converts pixel difference to heatmap color            |
+| 6) compare_images() - compares two images and builds difference heatmap       |
+==================================================================================
+*/
+
+use image::{ DynamicImage, RgbImage, Rgb };
+use image::imageops::FilterType;
+
+use crate::image_analysis::image_manager::{ check_image_file, load_image };
+use crate::image_analysis::histogram_builder::get_luminance;
+use crate::image_analysis::image_reports::*;
+
+//SSIM window size and step (windows overlap)
+const SSIM_WINDOW_SIZE: u32 = 8;
+const SSIM_WINDOW_STEP: usize = 4;
+
+//SSIM stabilizing constants: (0.01 * 255)^2 and (0.03 * 255)^2
+const SSIM_C1: f64 = 6.5025;
+const SSIM_C2: f64 = 58.5225;
+
+//gets compared image simple data
+fn get_compared_image_info(
+    file_name: String,
+    image: &DynamicImage
+) -> ComparedImageInfo {
+    return ComparedImageInfo {
+        file_name,
+        width: image.width(),
+        height: image.height(),
+        color_model: image.color()
+    }
+}
+
+//converts image to luminance values (Rec. 709), values are stored by rows
+fn get_luminance_plane(image: &DynamicImage) -> Vec<f64> {
+    return image
+        .to_rgb8()
+        .pixels()
+        .map(|pixel| get_luminance(pixel[0], pixel[1], pixel[2]) as f64)
+        .collect()
+}
+
+//counts SSIM windows start positions on one axis (last window is aligned to image edge)
+fn get_windows_positions(
+    length: u32,
+    window_size: u32
+) -> Vec<u32> {
+    let mut positions: Vec<u32> = (0..=length - window_size).step_by(SSIM_WINDOW_STEP).collect();
+
+    if positions.last() != Some(&(length - window_size)) {
+        positions.push(length - window_size);
+    }
+
+    return positions
+}
+
+//counts mean SSIM of two same size luminance planes (1.0 -> identical images)
+fn get_ssim(
+    first_plane: &[f64],
+    second_plane: &[f64],
+    width: u32,
+    height: u32
+) -> f64 {
+    //images smaller than window are compared by one window
+    let window_width = SSIM_WINDOW_SIZE.min(width);
+    let window_height = SSIM_WINDOW_SIZE.min(height);
+    let window_pixels_count = (window_width * window_height) as f64;
+
+    let mut ssim_sum = 0.0;
+    let mut windows_count = 0;
+
+    for window_y in get_windows_positions(height, window_height) {
+        for window_x in get_windows_positions(width, window_width) {
+            let mut first_sum = 0.0;
+            let mut second_sum = 0.0;
+            let mut first_squares_sum = 0.0;
+            let mut second_squares_sum = 0.0;
+            let mut products_sum = 0.0;
+
+            for y in window_y..window_y + window_height {
+                for x in window_x..window_x + window_width {
+                    let index = (y * width + x) as usize;
+                    let (first, second) = (first_plane[index], second_plane[index]);
+
+                    first_sum += first;
+                    second_sum += second;
+                    first_squares_sum += first * first;
+                    second_squares_sum += second * second;
+                    products_sum += first * second;
+                }
+            }
+
+            let first_mean = first_sum / window_pixels_count;
+            let second_mean = second_sum / window_pixels_count;
+            let first_variance = first_squares_sum / window_pixels_count - first_mean * first_mean;
+            let second_variance = second_squares_sum / window_pixels_count - second_mean * second_mean;
+            let covariance = products_sum / window_pixels_count - first_mean * second_mean;
+
+            ssim_sum += ((2.0 * first_mean * second_mean + SSIM_C1) * (2.0 * covariance + SSIM_C2))
+                / ((first_mean * first_mean + second_mean * second_mean + SSIM_C1) * (first_variance + second_variance + SSIM_C2));
+            windows_count += 1;
+        }
+    }
+
+    return ssim_sum / windows_count as f64
+}
+
+//converts pixel difference (0-255) to heatmap color: black (same) -> red -> yellow -> white (max difference)
+//small differences are amplified with square root to stay visible
+fn get_heatmap_color(difference: u8) -> Rgb<u8> {
+    let heat = (difference as f64 / 255.0).sqrt() * 3.0;
+
+    let channel = |offset: f64| ((heat - offset).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    return Rgb([channel(0.0), channel(1.0), channel(2.0)])
+}
+
+//compares two images, second image is resized to first image dimensions if they differ
+//returns comparison report and difference heatmap (first image dimensions)
+pub fn compare_images(
+    first_path: String,
+    second_path: String
+) -> Result<(ComparisonReport, RgbImage), ImageManagerError> {
+    let (first_file_name, _, _) = check_image_file(&first_path)?;
+    let (second_file_name, _, _) = check_image_file(&second_path)?;
+
+    let first_img = match load_image(first_path.trim()) {
+        Ok(img) => img,
+        Err(error) => return Err(ImageManagerError::ImageOpen { path: first_path, error })
+    };
+
+    let second_img = match load_image(second_path.trim()) {
+        Ok(img) => img,
+        Err(error) => return Err(ImageManagerError::ImageOpen { path: second_path, error })
+    };
+
+    let first_image = get_compared_image_info(first_file_name, &first_img);
+    let second_image = get_compared_image_info(second_file_name, &second_img);
+
+    let (width, height) = (first_image.width, first_image.height);
+    let dimensions_match = width == second_image.width && height == second_image.height;
+    let color_models_match = first_image.color_model == second_image.color_model;
+
+    //images with different dimensions are compared in first image dimensions
+    let second_img = if dimensions_match {
+        second_img
+    } else {
+        second_img.resize_exact(width, height, FilterType::Lanczos3)
+    };
+
+    let first_pixels = first_img.to_rgba8();
+    let second_pixels = second_img.to_rgba8();
+
+    let mut heatmap = RgbImage::new(width, height);
+    let mut squared_errors_sum = 0.0;
+    let mut different_pixels = 0;
+
+    for ((first_pixel, second_pixel), heatmap_pixel) in first_pixels.pixels().zip(second_pixels.pixels()).zip(heatmap.pixels_mut()) {
+        let mut max_difference = 0;
+
+        for channel in 0..4 {
+            let difference = first_pixel[channel].abs_diff(second_pixel[channel]);
+
+            squared_errors_sum += (difference as f64).powi(2);
+            max_difference = max_difference.max(difference);
+        }
+
+        if max_difference > 0 {
+            different_pixels += 1;
+        }
+
+        *heatmap_pixel = get_heatmap_color(max_difference);
+    }
+
+    let pixels_count = width as u64 * height as u64;
+
+    let mse = if pixels_count > 0 {
+        squared_errors_sum / (pixels_count * 4) as f64
+    } else {
+        0.0
+    };
+
+    //identical images have infinite PSNR
+    let psnr = if mse > 0.0 {
+        Some(10.0 * (255.0 * 255.0 / mse).log10())
+    } else {
+        None
+    };
+
+    let ssim = if pixels_count > 0 {
+        get_ssim(
+            &get_luminance_plane(&first_img),
+            &get_luminance_plane(&second_img),
+            width,
+            height
+        )
+    } else {
+        1.0
+    };
+
+    return Ok(
+        (
+            ComparisonReport {
+                first_image,
+                second_image,
+                dimensions_match,
+                color_models_match,
+                pixels_count,
+                different_pixels,
+                mse,
+                psnr,
+                ssim
+            },
+            heatmap
+        )
+    )
+}
@@ -0,0 +1,229 @@
+/*
+==================================================================================
+| This module converts images to other formats: image is decoded and encoded    |
+| again to PNG, JPEG, WebP (lossless), BMP, TIFF, GIF, ICO or AVIF. Image pixels |
+| are converted to color types, which target format supports (JPEG has no alpha, |
+| float images are saved as 16-bit, ICO is RGBA). Exif block can be copied from  |
+| JPEG, PNG and WebP images to JPEG, PNG and WebP images.                        |
+| {Structs:}                                                                     |
+| 1) ConversionOptions - image conversion quality and Exif options               |
+| {Enums:}                                                                       |
+| 1) ConversionFormat - image conversion target formats                          |
+| {Functions:}                                                                   |
+| 1) prepare_image_colors() - converts image to target format color type         |
+| 2) encode_image() - encodes image to target format                             |
+| 3) convert_image() - converts image file to target format                      |
+==================================================================================
+*/
+
+use image::{ DynamicImage, ImageEncoder, ImageError, ImageFormat };
+use image::codecs::avif::AvifEncoder;
+use image::codecs::jpeg::JpegEncoder;
+use serde::Serialize;
+
+use std::io::Cursor;
+
+use crate::image_analysis::image_manager::{ check_image_file, load_image };
+use crate::image_analysis::format_detector::ImageContainer;
+use crate::image_analysis::exif_writer::{ find_exif_block, replace_exif_block };
+use crate::image_analysis::image_reports::*;
+
+//default JPEG and AVIF quality (1-100)
+pub const DEFAULT_JPEG_QUALITY: u8 = 85;
+pub const DEFAULT_AVIF_QUALITY: u8 = 80;
+
+//AVIF encoder speed (1 - slowest and smallest file, 10 - fastest)
+const AVIF_ENCODER_SPEED: u8 = 8;
+
+//image conversion target formats
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ConversionFormat {
+    Png,
+    Jpeg,
+    WebP,
+    Bmp,
+    Tiff,
+    Gif,
+    Ico,
+    Avif
+}
+
+impl ConversionFormat {
+    //returns format name (same as in JSON output)
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConversionFormat::Png => "png",
+            ConversionFormat::Jpeg => "jpeg",
+            ConversionFormat::WebP => "webp",
+            ConversionFormat::Bmp => "bmp",
+            ConversionFormat::Tiff => "tiff",
+            ConversionFormat::Gif => "gif",
+            ConversionFormat::Ico => "ico",
+            ConversionFormat::Avif => "avif"
+        }
+    }
+
+    //returns converted file extension
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ConversionFormat::Jpeg => "jpg",
+            ConversionFormat::Tiff => "tif",
+            _ => self.name()
+        }
+    }
+
+    //parses format name or extension (jpg, jpeg, tif, tiff...)
+    pub fn from_name(name: &str) -> Option<ConversionFormat> {
+        match name.trim().to_lowercase().as_str() {
+            "png" => Some(ConversionFormat::Png),
+            "jpg" | "jpeg" => Some(ConversionFormat::Jpeg),
+            "webp" => Some(ConversionFormat::WebP),
+            "bmp" => Some(ConversionFormat::Bmp),
+            "tif" | "tiff" => Some(ConversionFormat::Tiff),
+            "gif" => Some(ConversionFormat::Gif),
+            "ico" => Some(ConversionFormat::Ico),
+            "avif" => Some(ConversionFormat::Avif),
+            _ => None
+        }
+    }
+
+    //returns default quality of lossy formats (None -> format has no quality setting)
+    pub fn default_quality(&self) -> Option<u8> {
+        match self {
+            ConversionFormat::Jpeg => Some(DEFAULT_JPEG_QUALITY),
+            ConversionFormat::Avif => Some(DEFAULT_AVIF_QUALITY),
+            _ => None
+        }
+    }
+
+    //returns container, which Exif block can be written to (None -> format doesn't keep Exif metadata)
+    fn exif_container(&self) -> Option<ImageContainer> {
+        match self {
+            ConversionFormat::Png => Some(ImageContainer::Png),
+            ConversionFormat::Jpeg => Some(ImageContainer::Jpeg),
+            ConversionFormat::WebP => Some(ImageContainer::WebP),
+            _ => None
+        }
+    }
+}
+
+//image conversion quality and Exif options
+pub struct ConversionOptions {
+    pub quality: Option<u8>, //JPEG and AVIF quality 1-100 (None -> default quality)
+    pub keep_exif: bool //copy Exif block from source image (false -> converted image has no Exif metadata)
+}
+
+//converts image to color type, which target format supports
+fn prepare_image_colors(
+    image: DynamicImage,
+    format: ConversionFormat
+) -> DynamicImage {
+    let color = image.color();
+
+    match format {
+        //PNG and TIFF keep 8-bit and 16-bit images, float images are saved as 16-bit
+        ConversionFormat::Png | ConversionFormat::Tiff => match image {
+            DynamicImage::ImageRgb32F(_) => DynamicImage::ImageRgb16(image.to_rgb16()),
+            DynamicImage::ImageRgba32F(_) => DynamicImage::ImageRgba16(image.to_rgba16()),
+            _ => image
+        },
+        //JPEG has no alpha channel
+        ConversionFormat::Jpeg if !color.has_color() => DynamicImage::ImageLuma8(image.to_luma8()),
+        ConversionFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8()),
+        //ICO readers expect RGBA images inside icon
+        ConversionFormat::Ico => DynamicImage::ImageRgba8(image.to_rgba8()),
+        _ if color.has_alpha() => DynamicImage::ImageRgba8(image.to_rgba8()),
+        _ => DynamicImage::ImageRgb8(image.to_rgb8())
+    }
+}
+
+//encodes image to target format (quality -> JPEG and AVIF quality 1-100)
+pub(crate) fn encode_image(
+    image: DynamicImage,
+    format: ConversionFormat,
+    quality: u8
+) -> Result<Vec<u8>, ImageManagerError> {
+    let image = prepare_image_colors(image, format);
+    let mut encoded_data = Cursor::new(Vec::new());
+
+    let encode_result = match format {
+        ConversionFormat::Jpeg => JpegEncoder::new_with_quality(&mut encoded_data, quality)
+            .write_image(image.as_bytes(), image.width(), image.height(), image.color().into()),
+        ConversionFormat::Avif => AvifEncoder::new_with_speed_quality(&mut encoded_data, AVIF_ENCODER_SPEED, quality)
+            .write_image(image.as_bytes(), image.width(), image.height(), image.color().into()),
+        ConversionFormat::Png => image.write_to(&mut encoded_data, ImageFormat::Png),
+        ConversionFormat::WebP => image.write_to(&mut encoded_data, ImageFormat::WebP),
+        ConversionFormat::Bmp => image.write_to(&mut encoded_data, ImageFormat::Bmp),
+        ConversionFormat::Tiff => image.write_to(&mut encoded_data, ImageFormat::Tiff),
+        ConversionFormat::Gif => image.write_to(&mut encoded_data, ImageFormat::Gif),
+        ConversionFormat::Ico => image.write_to(&mut encoded_data, ImageFormat::Ico)
+    };
+
+    if let Err(error) = encode_result {
+        return Err(ImageManagerError::ImageEncode { format: format.name().to_string(), error })
+    }
+
+    return Ok(encoded_data.into_inner())
+}
+
+//converts image file to target format, returns conversion report and converted image bytes (source file isn't changed)
+pub fn convert_image(
+    path: String,
+    format: ConversionFormat,
+    options: &ConversionOptions
+) -> Result<(ConversionReport, Vec<u8>), ImageManagerError> {
+    let (file_name, file_extension, container) = check_image_file(&path)?;
+    let trimmed_path = path.trim();
+
+    //Exif block is checked before decoding, so unsupported target fails fast
+    let exif_container = match (options.keep_exif, format.exif_container()) {
+        (true, None) => return Err(ImageManagerError::ExifKeepNotSupported { format: format.name().to_string() }),
+        (true, exif_container) => exif_container,
+        (false, _) => None
+    };
+
+    let source_data = std::fs::read(trimmed_path)
+        .map_err(|error| ImageManagerError::ImageOpen { path: path.clone(), error: ImageError::IoError(error) })?;
+
+    //TIFF image is Exif block itself (with image data), so Exif isn't copied from TIFF images
+    let exif_block = container
+        .filter(|container| *container != ImageContainer::Tiff)
+        .and_then(|container| find_exif_block(&source_data, container))
+        .map(|block| source_data[block].to_vec());
+
+    let image = load_image(trimmed_path).map_err(|error| ImageManagerError::ImageOpen { path: path.clone(), error })?;
+    let (width, height) = (image.width(), image.height());
+
+    //quality is ignored by lossless formats
+    let quality = format.default_quality().map(|default_quality| options.quality.unwrap_or(default_quality));
+
+    let mut converted_data = encode_image(image, format, quality.unwrap_or(100))?;
+
+    let exif_preserved = match (exif_container, &exif_block) {
+        (Some(exif_container), Some(block)) => {
+            replace_exif_block(&mut converted_data, exif_container, block.clone())?;
+            true
+        },
+        _ => false
+    };
+
+    return Ok(
+        (
+            ConversionReport {
+                file_name,
+                file_extension,
+                source_container: container,
+                target_format: format,
+                width,
+                height,
+                quality,
+                source_size: source_data.len() as u64,
+                converted_size: converted_data.len() as u64,
+                source_has_exif: exif_block.is_some(),
+                exif_preserved
+            },
+            converted_data
+        )
+    )
+}
@@ -0,0 +1,752 @@
+/*
+========================================================================================
+| This module parse functions for processing image, fetching metadata                  |
+| and get info about images sizes, count and all size with specific extension from dirs|
+| Functions return typed results (see image_reports), app screens_manager renders them.|
+| used libs: [image-image processing, rexif-fetching exif metadata,                    |
+| [dirs-crossplatform methods to get dirs addresses, walkdir-for directory crawling]   |
+| {Functions:}                                                                         |
+| 1) check_image_format() - checks image format for processing                         |
+| 1.1) get_image_format_name() - gets image format name (joins extension aliases)      |
+| 2) adapt_file_size() - converts file size in bytes to MB, KB, GB                     |
+| 3) convert_to_percent() - converts value to percent                                  |
+| 4) get_channel_statistics() - counts channel mean, median, std deviation, min, max   |
+| 5) get_all_image_pixels_info() - scans pixels. counts pixels, channels statistics,   |
+| transparent pixels and dominant channels                                             |
+| 6) load_image() - loads image                                                        |
+| 6.1) load_image_from_data() - loads image from file bytes (extension is format hint) |
+| 7) check_image_file() - checks if file is image, gets its filename and extension     |
+| 8) get_image_data() - gets image simple data                                         |
+| 8.1) get_image_data_with_thumbnail() - gets image simple data and image thumbnail    |
+| 8.2) read_image_data() - reads image simple data, returns it with loaded image       |
+| 8.3) make_thumbnail() - makes image thumbnail turned by Exif orientation             |
+| 8.4) get_image_thumbnail() - gets image thumbnail, displayed dimensions, file size   |
+| 9) get_image_exif_metadata() - extracts Exif metadata from image                     |
+| 9.1) get_exif_entries_info() - converts rexif entries to Exif entries info           |
+| 9.2) get_exif_value_items() - splits typed Exif value into separate value items      |
+| 10) get_images_size_with_extensions() - scans computer dirs, counts image files and  |
+| size with specific extensions (every format in every dir). counts all files size     |
+| 10.0) get_scan_formats() - gets image formats names for scan from extensions list    |
+| 10.1) default_scan_roots() - returns default scan directories                        |
+| 10.2) resolve_scan_roots() - resolves scan roots real paths                          |
+| 10.3) is_hidden_entry() - checks if directory entry is hidden                        |
+| 10.4) walk_scan_root() - walks scan root with scan options, returns files entries    |
+| 10.5) detect_scanned_file_format() - detects scanned file image format               |
+========================================================================================
+*/
+
+use dirs;
+use rexif;
+use image::*;
+use walkdir::{ DirEntry, WalkDir };
+
+use std::borrow::Cow;
+use std::path::{ Path, PathBuf };
+
+use crate::image_analysis::image_reports::*;
+use crate::image_analysis::format_detector::*;
+use crate::image_analysis::exif_location::get_gps_location;
+use crate::image_analysis::icc_profile_reader::get_icc_profile_from_data;
+use crate::image_analysis::animation_reader::get_animation_info_from_data;
+use crate::image_analysis::orientation_manager::{ read_exif_orientation, get_display_dimensions, apply_exif_orientation };
+
+const SUPPORTED_EXIF_METADATA_FORMATS: &[&str; 7] = &[
+    "jpeg", "jpg", "tif", "tiff", "webp",
+    "heic", "heif"
+];
+
+pub const IMAGE_FILES_FORMATS: &[&str; 15] = &[
+    "png", "jpg", "jpeg", "gif", "webp",
+    "raw", "tiff", "tif", "svg", "heic",
+    "heif", "ico", "bmp", "psd", "avif"
+];
+
+//extensions of same image format (scan counts them as one format)
+const IMAGE_FORMATS_ALIASES: &[&[&str]] = &[
+    &["jpg", "jpeg"],
+    &["tif", "tiff"],
+    &["heic", "heif"]
+];
+
+//checks image format support (extension case is ignored: JPG == jpg)
+fn check_image_format_support(
+    extension: &str,
+    support_formats: &[&str]
+) -> bool {
+    let mut is_format_support = false;
+
+    for format in support_formats {
+        if extension.eq_ignore_ascii_case(format) {
+            is_format_support = true;
+        }
+    }
+
+    return is_format_support
+}
+
+//gets image format name for extension, aliases are joined (jpeg -> jpg/jpeg, PNG -> png)
+pub fn get_image_format_name(extension: &str) -> String {
+    let extension = extension.to_ascii_lowercase();
+
+    for aliases in IMAGE_FORMATS_ALIASES {
+        if aliases.contains(&extension.as_str()) {
+            return aliases.join("/")
+        }
+    }
+
+    return extension
+}
+
+//adapts file size to readable size
+pub fn adapt_file_size(file_size_in_bytes: f64) -> String {
+    let mut result = String::new();
+
+    const BYTES_IN_GIGABYTE: f64 = 1073741824.0;
+    const BYTES_IN_MEGABYTE: f64 = 1048576.0;
+    const BYTES_IN_KILOBYTE: f64 = 1024.0;
+
+    if file_size_in_bytes >= BYTES_IN_GIGABYTE {
+        //returns file size in gigabytes
+        result.push_str(
+            &format!(
+                "{:.2} gb",
+                file_size_in_bytes / BYTES_IN_GIGABYTE
+            )
+        );
+    } else if file_size_in_bytes >= BYTES_IN_MEGABYTE {
+        //returns file size in megabytes
+        result.push_str(
+            &format!(
+                "{:.2} mb",
+                file_size_in_bytes / BYTES_IN_MEGABYTE
+            )
+        );
+    } else if file_size_in_bytes >= BYTES_IN_KILOBYTE {
+        //returns file size in kilobytes
+        result.push_str(
+            &format!(
+                "{:.2} kb",
+                file_size_in_bytes / BYTES_IN_KILOBYTE
+            )
+        );
+    } else {
+        //returns file size in bytes
+        result.push_str(
+            &format!(
+                "{} b",
+                file_size_in_bytes
+            )
+        );
+    }
+
+    return result
+}
+
+//converts value to percent (of max), with 2 decimal places
+pub fn convert_to_percent(
+    max: u64,
+    value: u64
+) -> String {
+    if max == 0 {
+        return "0.00".to_string()
+    }
+
+    return format!("{:.2}", value as f64 / max as f64 * 100.0)
+}
+
+//counts channel statistics from channel values histogram (256 bins)
+fn get_channel_statistics(
+    histogram: &[u64; 256],
+    values_count: u64
+) -> ChannelStatistics {
+    let mut channel_statistics = ChannelStatistics {
+        mean: 0.0,
+        median: 0,
+        standard_deviation: 0.0,
+        min: 0,
+        max: 0
+    };
+
+    if values_count == 0 {
+        return channel_statistics
+    }
+
+    let mut sum = 0.0;
+    let mut squares_sum = 0.0;
+    let mut counted_values = 0;
+    let median_position = values_count.div_ceil(2); //lower median position
+
+    for (value, count) in histogram.iter().enumerate() {
+        if *count == 0 {
+            continue
+        }
+
+        if counted_values == 0 {
+            channel_statistics.min = value as u8;
+        }
+
+        channel_statistics.max = value as u8;
+
+        //median is the value, where counted values reaches median position
+        if counted_values < median_position && counted_values + count >= median_position {
+            channel_statistics.median = value as u8;
+        }
+
+        counted_values += count;
+        sum += value as f64 * *count as f64;
+        squares_sum += (value * value) as f64 * *count as f64;
+    }
+
+    channel_statistics.mean = sum / values_count as f64;
+    channel_statistics.standard_deviation = (squares_sum / values_count as f64 - channel_statistics.mean * channel_statistics.mean).max(0.0).sqrt();
+
+    return channel_statistics
+}
+
+//processing image pixels data
+fn get_all_image_pixels_info(image: &DynamicImage) -> PixelsStatistics {
+    let mut pixels_count = 0;
+    let mut transparent_pixels = 0;
+    let mut dominant_pixels = [0; 4]; //red, green, blue, no dominant
+    let mut histograms = [[0u64; 256]; 4]; //red, green, blue, alpha channels histograms
+
+    let rgba_image = image.to_rgba8(); //converting immage to RGBA, for pixels counting
+
+    //scanning image for counting and get info about image pixels
+    for pixel in rgba_image.pixels() {
+        let [red, green, blue, alpha] = pixel.0;
+
+        pixels_count += 1;
+
+        for (channel_index, value) in pixel.0.iter().enumerate() {
+            histograms[channel_index][*value as usize] += 1;
+        }
+
+        if alpha == 0 {
+            transparent_pixels += 1;
+        }
+
+        //counting pixels where one channel dominates
+        if red > green && red > blue {
+            dominant_pixels[0] += 1;
+        } else if green > red && green > blue {
+            dominant_pixels[1] += 1;
+        } else if blue > red && blue > green {
+            dominant_pixels[2] += 1;
+        } else {
+            dominant_pixels[3] += 1;
+        }
+    }
+
+    return PixelsStatistics {
+        pixels_count,
+        red: get_channel_statistics(&histograms[0], pixels_count),
+        green: get_channel_statistics(&histograms[1], pixels_count),
+        blue: get_channel_statistics(&histograms[2], pixels_count),
+        alpha: get_channel_statistics(&histograms[3], pixels_count),
+        transparent_pixels,
+        red_dominant_pixels: dominant_pixels[0],
+        green_dominant_pixels: dominant_pixels[1],
+        blue_dominant_pixels: dominant_pixels[2],
+        no_dominant_pixels: dominant_pixels[3]
+    }
+}
+
+//loads image from path (format is guessed by file content, not by extension)
+pub fn load_image(path: &str) -> Result<DynamicImage, ImageError> {
+    let image = ImageReader::open(Path::new(path))?.with_guessed_format()?.decode()?;
+    Ok(image)
+}
+
+//loads image from file bytes (format is guessed by content, file extension is used, if content isn't recognized)
+//file read once can be decoded and parsed for metadata
+pub(crate) fn load_image_from_data(
+    data: &[u8],
+    path: &Path
+) -> Result<DynamicImage, ImageError> {
+    let mut reader = ImageReader::new(std::io::Cursor::new(data));
+
+    if let Ok(format) = ImageFormat::from_path(path) {
+        reader.set_format(format);
+    }
+
+    let image = reader.with_guessed_format()?.decode()?;
+    Ok(image)
+}
+
+//checks if file from path is image by content (magic bytes), not detectable formats (svg, raw) are checked by extension
+//returns image filename, file extension and detected container
+pub fn check_image_file(path: &str) -> Result<(String, String, Option<ImageContainer>), ImageManagerError> {
+    let image_path = Path::new(path.trim()); //creating image path
+
+    let file_name = if let Some(name) = image_path.file_name() {
+        name.to_string_lossy()
+    } else {
+        Cow::Borrowed("Unknown filename")
+    }; //getting image filename
+
+    let file_extension = if let Some(extension) = image_path.extension() {
+        extension.to_string_lossy()
+    } else {
+        Cow::Borrowed("Unknown file extension")
+    }; //getting image file extension
+
+    //detecting image container by file content
+    let container = match detect_file_image_container(image_path) {
+        Ok(container) => container,
+        Err(error) => return Err(ImageManagerError::ImageOpen { path: path.to_string(), error: ImageError::IoError(error) })
+    };
+
+    //checking if it is image before processing (content isn't image, extension isn't image or belongs to detectable format)
+    let is_this_image_file = container.is_some() || (
+        check_image_format_support(&file_extension, IMAGE_FILES_FORMATS) && !is_detectable_extension(&file_extension)
+    );
+
+    //if this file not image, return error
+    if !is_this_image_file {
+        return Err(ImageManagerError::NotImage { extension: file_extension.to_string() });
+    }
+
+    return Ok((file_name.to_string(), file_extension.to_string(), container))
+}
+
+//gets image data
+pub fn get_image_data(path: String) -> Result<ImageInfo, ImageManagerError> {
+    let (image_info, _) = read_image_data(path)?;
+
+    return Ok(image_info)
+}
+
+//gets image data and image thumbnail (fits in thumbnail_size x thumbnail_size square, aspect ratio is kept)
+//small images, which already fit in square, aren't enlarged. thumbnail is turned by Exif orientation (as image is displayed)
+pub fn get_image_data_with_thumbnail(
+    path: String,
+    thumbnail_size: u32
+) -> Result<(ImageInfo, RgbaImage), ImageManagerError> {
+    let (image_info, img) = read_image_data(path)?;
+    let thumbnail = make_thumbnail(&img, thumbnail_size, image_info.orientation);
+
+    return Ok((image_info, thumbnail))
+}
+
+//makes image thumbnail (fits in thumbnail_size x thumbnail_size square) turned by Exif orientation
+//thumbnail is turned after resizing, turned thumbnail still fits in square
+fn make_thumbnail(
+    img: &DynamicImage,
+    thumbnail_size: u32,
+    orientation: Option<u16>
+) -> RgbaImage {
+    let thumbnail = if img.width() <= thumbnail_size && img.height() <= thumbnail_size {
+        img.to_rgba8()
+    } else {
+        img.thumbnail(thumbnail_size, thumbnail_size).to_rgba8()
+    };
+
+    return apply_exif_orientation(DynamicImage::ImageRgba8(thumbnail), orientation).into_rgba8()
+}
+
+//gets image thumbnail turned by Exif orientation, image displayed dimensions and file size
+//lightweight get_image_data_with_thumbnail: file is read once and decoded, pixels statistics and metadata aren't collected
+pub(crate) fn get_image_thumbnail(
+    path: &Path,
+    thumbnail_size: u32
+) -> Result<(RgbaImage, (u32, u32), u64), ImageManagerError> {
+    let data = std::fs::read(path).map_err(|error| {
+        ImageManagerError::ImageOpen { path: path.to_string_lossy().to_string(), error: ImageError::IoError(error) }
+    })?;
+
+    let img = load_image_from_data(&data, path).map_err(|error| {
+        ImageManagerError::ImageOpen { path: path.to_string_lossy().to_string(), error }
+    })?;
+
+    let orientation = detect_image_container(&data).and_then(|container| read_exif_orientation(&data, container));
+    let display_dimensions = get_display_dimensions(img.width(), img.height(), orientation);
+
+    return Ok((make_thumbnail(&img, thumbnail_size, orientation), display_dimensions, data.len() as u64))
+}
+
+//reads image data, returns it with loaded image
+fn read_image_data(path: String) -> Result<(ImageInfo, DynamicImage), ImageManagerError> {
+    let trimmed_path = path.trim(); //trim path
+    let image_path = Path::new(trimmed_path); //creating image path
+
+    let (file_name, file_extension, container) = check_image_file(trimmed_path)?;
+    let extension_mismatch = check_extension_mismatch(
+        container,
+        image_path.extension().map(|extension| extension.to_string_lossy()).as_deref()
+    );
+
+    //file is read once: bytes are decoded and parsed for metadata (ICC profile, animation, Exif orientation)
+    let data = match std::fs::read(image_path) {
+        Ok(data) => data,
+        Err(error) => return Err(ImageManagerError::ImageOpen { path, error: ImageError::IoError(error) })
+    };
+
+    //load image from bytes and proccesing image (if result is Ok -> processing else return error)
+    let img = match load_image_from_data(&data, image_path) {
+        Ok(img) => img,
+        Err(error) => return Err(ImageManagerError::ImageOpen { path, error })
+    };
+
+    let (image_width, image_height) = img.dimensions(); //gets width and height
+    let image_format = img.color(); //gets color format
+    let file_size = data.len() as u64; //getting image file size in bytes
+
+    let pixels_statistics = get_all_image_pixels_info(&img); //gettin image pixels data
+    let (icc_profile, icc_profile_data) = container.and_then(|container| get_icc_profile_from_data(&data, container)).unzip(); //getting embedded color profile
+    let animation = container.and_then(|container| get_animation_info_from_data(&data, container)); //getting animation frames (first frame is decoded above)
+    let orientation = container.and_then(|container| read_exif_orientation(&data, container)); //getting Exif orientation (how image is displayed)
+    let (display_width, display_height) = get_display_dimensions(image_width, image_height, orientation);
+
+    return Ok(
+        (
+            ImageInfo {
+                file_name,
+                file_extension,
+                file_size,
+                width: image_width,
+                height: image_height,
+                orientation,
+                display_width,
+                display_height,
+                color_model: image_format,
+                container,
+                extension_mismatch,
+                icc_profile,
+                icc_profile_data,
+                animation,
+                pixels_statistics
+            },
+            img
+        )
+    )
+}
+
+//gets image exif metadata
+pub fn get_image_exif_metadata(path: String) -> Result<ExifReport, ImageManagerError> {
+    let trimmed_path = path.trim(); //trim path
+    let image_path = Path::new(trimmed_path); //creating image path
+
+    let file_name = if let Some(name) = image_path.file_name() {
+        name.to_string_lossy()
+    } else {
+        Cow::Borrowed("Unknown name")
+    }; //getting filename
+
+    let file_extension = if let Some(extension) = image_path.extension() {
+        extension.to_string_lossy()
+    } else {
+        Cow::Borrowed("Unknown file extension")
+    }; //getting file extension
+
+    //checking if it is file supporting exif metadata
+    let is_format_exif_supported = check_image_format_support(&file_extension, SUPPORTED_EXIF_METADATA_FORMATS);
+
+    if !is_format_exif_supported {
+        return Err(ImageManagerError::ExifNotSupported { extension: file_extension.to_string() });
+    }
+
+    //fetching exif metadata
+    let exif = rexif::parse_file(&*path).map_err(ImageManagerError::ExifParse)?;
+
+    let location = get_gps_location(&exif.entries);
+
+    return Ok(
+        ExifReport {
+            file_name: file_name.to_string(),
+            file_extension: file_extension.to_string(),
+            entries: get_exif_entries_info(&exif.entries),
+            location
+        }
+    )
+}
+
+//converts rexif entries to Exif entries info (tag name and number, raw and readable values, IFD)
+pub(crate) fn get_exif_entries_info(exif_entries: &[rexif::ExifEntry]) -> Vec<ExifEntryInfo> {
+    let mut entries = Vec::new();
+
+    //fetch entries
+    for entry in exif_entries {
+        entries.push(
+            ExifEntryInfo {
+                tag: entry.tag.to_string(),
+                tag_id: entry.ifd.tag,
+                raw_value: entry.value.to_string(),
+                raw_values: get_exif_value_items(&entry.value),
+                readable_value: entry.value_more_readable.to_string(),
+                ifd: format!("{:?}", entry.kind)
+            }
+        );
+    }
+
+    return entries
+}
+
+//splits typed Exif value into separate value items (text and opaque bytes are one item)
+fn get_exif_value_items(value: &rexif::TagValue) -> Vec<String> {
+    fn to_strings<T: ToString>(values: &[T]) -> Vec<String> {
+        return values.iter().map(ToString::to_string).collect()
+    }
+
+    match value {
+        rexif::TagValue::U8(values) => to_strings(values),
+        rexif::TagValue::U16(values) => to_strings(values),
+        rexif::TagValue::U32(values) => to_strings(values),
+        rexif::TagValue::URational(values) => to_strings(values),
+        rexif::TagValue::I8(values) => to_strings(values),
+        rexif::TagValue::I16(values) => to_strings(values),
+        rexif::TagValue::I32(values) => to_strings(values),
+        rexif::TagValue::IRational(values) => to_strings(values),
+        rexif::TagValue::F32(values) => to_strings(values),
+        rexif::TagValue::F64(values) => to_strings(values),
+        _ => vec![value.to_string()]
+    }
+}
+
+//images scan options (default: default directories, unlimited depth, no symlinks, no hidden files)
+#[derive(Default)]
+pub struct ScanOptions {
+    pub roots: Vec<PathBuf>, //directories to scan, empty -> default directories (see default_scan_roots)
+    pub max_depth: Option<usize>, //max directory depth (root files depth is 1), None -> unlimited
+    pub follow_symlinks: bool, //follow symbolic links to directories and files
+    pub include_hidden: bool //scan hidden files and directories (name starts with ".")
+}
+
+//returns default scan directories (where images are usually stored), not existing directories are skipped
+pub fn default_scan_roots() -> Vec<(String, PathBuf)> {
+    let all_dirs: [(&str, Option<PathBuf>); 7] = [
+        ("Downloads dir", dirs::download_dir()),
+        ("Documents dir", dirs::document_dir()),
+        ("Videos dir", dirs::video_dir()),
+        ("Pictures dir", dirs::picture_dir()),
+        ("Local data dir", dirs::data_local_dir()),
+        ("Data dir", dirs::data_dir()),
+        ("Desktop dir", dirs::desktop_dir())
+    ];
+
+    return all_dirs
+        .into_iter()
+        .filter_map(|(dir_name, dir_path)| Some((dir_name.to_string(), dir_path?)))
+        .filter(|(_, dir_path)| dir_path.is_dir())
+        .collect()
+}
+
+//resolves scan roots real paths (scan options roots or default directories), same directories are scanned once
+pub(crate) fn resolve_scan_roots(scan_options: &ScanOptions) -> Result<Vec<(String, PathBuf)>, ImageManagerError> {
+    let roots = if scan_options.roots.is_empty() {
+        default_scan_roots()
+    } else {
+        scan_options.roots.iter().map(|root| (root.to_string_lossy().to_string(), root.clone())).collect()
+    };
+
+    let mut resolved_roots: Vec<(String, PathBuf)> = Vec::new();
+
+    for (root_name, root_path) in roots {
+        let real_path = match root_path.canonicalize() {
+            Ok(path) if path.is_dir() => path,
+            Ok(path) => return Err(
+                ImageManagerError::ScanRoot {
+                    path,
+                    error: std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a directory")
+                }
+            ),
+            Err(error) => return Err(ImageManagerError::ScanRoot { path: root_path, error })
+        };
+
+        //Local data dir and Data dir are same directory on Linux
+        if !resolved_roots.iter().any(|(_, path)| path == &real_path) {
+            resolved_roots.push((root_name, real_path));
+        }
+    }
+
+    return Ok(resolved_roots)
+}
+
+//checks if directory entry is hidden (name starts with "."), scan root itself is never hidden
+fn is_hidden_entry(entry: &DirEntry) -> bool {
+    return entry.depth() > 0 && entry.file_name().to_string_lossy().starts_with('.')
+}
+
+//walks scan root with scan options, returns regular files entries (hidden entries are skipped with their content)
+pub(crate) fn walk_scan_root<'a>(
+    root: &Path,
+    scan_options: &'a ScanOptions
+) -> impl Iterator<Item = DirEntry> + 'a {
+    let mut walker = WalkDir::new(root).follow_links(scan_options.follow_symlinks);
+
+    if let Some(max_depth) = scan_options.max_depth {
+        walker = walker.max_depth(max_depth);
+    }
+
+    return walker
+        .into_iter()
+        .filter_entry(|entry| scan_options.include_hidden || !is_hidden_entry(entry))
+        .filter_map(|e| e.ok())
+        .filter(|entry| entry.file_type().is_file())
+}
+
+//scanned file extension and image format (by content or by extension)
+pub(crate) struct ScannedFileFormat {
+    pub extension: Option<String>,
+    pub extension_format: Option<String>, //image format name by extension
+    pub container: Option<ImageContainer>, //container detected by content
+    pub format: Option<String> //image format name by content, not detectable formats (svg, raw) by extension
+}
+
+//detects scanned file image format, only files with image extension or without extension are read (None -> file isn't read)
+pub(crate) fn detect_scanned_file_format(path: &Path) -> Option<ScannedFileFormat> {
+    let extension = path.extension().map(|extension| extension.to_string_lossy().to_string());
+    let extension_format = extension.as_deref().map(get_image_format_name);
+
+    let is_image_extension = extension.as_deref().is_some_and(|extension| check_image_format_support(extension, IMAGE_FILES_FORMATS));
+
+    if extension.is_some() && !is_image_extension {
+        return None
+    }
+
+    let container = detect_file_image_container(path).ok().flatten();
+
+    let format = match container {
+        Some(container) => Some(get_image_format_name(container.extensions()[0])),
+        None if is_image_extension && !extension.as_deref().is_some_and(is_detectable_extension) => extension_format.clone(),
+        None => None
+    };
+
+    return Some(
+        ScannedFileFormat {
+            extension,
+            extension_format,
+            container,
+            format
+        }
+    )
+}
+
+//gets image formats names for scan from extensions list ("*" -> all image formats), same formats are joined
+pub(crate) fn get_scan_formats(extensions: &[String]) -> Result<Vec<String>, ImageManagerError> {
+    let mut formats: Vec<String> = Vec::new();
+
+    for extension in extensions {
+        let format_extensions = if extension.trim() == "*" {
+            IMAGE_FILES_FORMATS.iter().map(|format| format.to_string()).collect()
+        } else if check_image_format_support(extension.trim(), IMAGE_FILES_FORMATS) {
+            vec![extension.trim().to_string()]
+        } else {
+            return Err(ImageManagerError::NotImage { extension: extension.clone() })
+        };
+
+        for format_extension in format_extensions {
+            let format = get_image_format_name(&format_extension);
+
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+    }
+
+    if formats.is_empty() {
+        return Err(ImageManagerError::NotImage { extension: String::new() })
+    }
+
+    return Ok(formats)
+}
+
+//gets images size with specific extensions in scan roots ("*" -> all image formats), every format is counted in every root
+//files format is detected by content (files with image extension and without extension are read), mismatched files are reported
+//on_progress is called before every root scan
+pub fn get_images_size_with_extensions(
+    extensions: &[String],
+    scan_options: &ScanOptions,
+    mut on_progress: impl FnMut(&ScanProgress)
+) -> Result<ExtensionScanReport, ImageManagerError> {
+    let mut images_count = 0;
+    let mut directories = Vec::new();
+
+    //checking if it is image formats
+    let scan_formats = get_scan_formats(extensions)?;
+    let empty_formats_info: Vec<FormatScanInfo> = scan_formats
+        .iter()
+        .map(|format| FormatScanInfo { format: format.clone(), images_count: 0, images_size: 0 })
+        .collect();
+
+    let scan_roots = resolve_scan_roots(scan_options)?;
+
+    //scanning dirs on images with specific extensions
+    for (dir_index, (dir_name, current_dir)) in scan_roots.iter().enumerate() {
+        //reporting scan progress
+        on_progress(
+            &ScanProgress {
+                scanned_dirs: dir_index + 1,
+                dirs_count: scan_roots.len(),
+                images_found: images_count,
+                current_dir
+            }
+        );
+
+        let mut directory_scan_info = DirectoryScanInfo {
+            name: dir_name.clone(),
+            path: current_dir.clone(),
+            images_count: 0,
+            images_size: 0,
+            formats: empty_formats_info.clone(),
+            mismatched_files: Vec::new()
+        };
+
+        //scanning files in current dir
+        for entry in walk_scan_root(current_dir, scan_options) {
+            let Some(ScannedFileFormat { extension, extension_format, container, format: file_format }) = detect_scanned_file_format(entry.path()) else {
+                continue
+            };
+
+            //mismatched files are reported only for scan formats (by content or by extension)
+            let is_scan_format = |format: &Option<String>| format.as_ref().is_some_and(|format| scan_formats.contains(format));
+
+            if check_extension_mismatch(container, extension.as_deref()) && (is_scan_format(&file_format) || is_scan_format(&extension_format)) {
+                directory_scan_info.mismatched_files.push(
+                    MismatchedFileInfo {
+                        path: entry.path().to_path_buf(),
+                        extension: extension.clone().unwrap_or_default(),
+                        container
+                    }
+                );
+            }
+
+            //if file format is one of scan formats, count.
+            if let Some(format_index) = scan_formats.iter().position(|format| Some(format) == file_format.as_ref()) {
+                let file_size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+
+                images_count += 1;
+
+                //counting files in dir count and files size in dir
+                directory_scan_info.images_count += 1;
+                directory_scan_info.images_size += file_size;
+                directory_scan_info.formats[format_index].images_count += 1;
+                directory_scan_info.formats[format_index].images_size += file_size;
+            }
+        }
+
+        directories.push(directory_scan_info);
+    }
+
+    //every format files count and size in all dirs
+    let mut formats = empty_formats_info;
+
+    for directory in &directories {
+        for (format_info, directory_format_info) in formats.iter_mut().zip(&directory.formats) {
+            format_info.images_count += directory_format_info.images_count;
+            format_info.images_size += directory_format_info.images_size;
+        }
+    }
+
+    //all files size
+    let total_size = directories.iter().map(|directory| directory.images_size).sum();
+
+    return Ok(
+        ExtensionScanReport {
+            formats,
+            images_count,
+            total_size,
+            directories
+        }
+    )
+}
@@ -0,0 +1,589 @@
+/*
+===============================================================================
+| This module contains typed results and errors of image_manager functions.   |
+| Results are rendered into decorated text by img_info app screens_manager.   |
+| {Structs:}                                                                  |
+| 1) ImageInfo - image simple data (gii command result)                       |
+| 2) PixelsStatistics - image pixels colors statistics                        |
+| 3) ChannelStatistics - one color channel statistics                         |
+| 4) ExifReport - image Exif metadata (fem command result)                    |
+| 5) ExifEntryInfo - one Exif metadata entry                                  |
+| 6) ExtensionScanReport - images with specific extensions scan (is command)  |
+| 7) DirectoryScanInfo - images count and size in one scanned directory       |
+| 7.1) FormatScanInfo - one image format images count and size                |
+| 7.2) MismatchedFileInfo - file with extension, which doesn't match content  |
+| 8) ScanProgress - directories scan progress (for progress callback)         |
+| 9) PaletteReport - image dominant colors palette (palette command result)   |
+| 10) PaletteColor - one palette color                                        |
+| 11) HistogramReport - image channels histograms (hist command result)       |
+| 12) DuplicatesReport - exact duplicate images (dups command result)         |
+| 13) DuplicateGroup - group of byte-identical images                         |
+| 14) SimilarImagesReport - similar images clusters (similar command result)  |
+| 15) SimilarImagesCluster - cluster of visually similar images               |
+| 16) SimilarImageInfo - one image in similar images cluster                  |
+| 17) PerceptualHashes - image aHash, dHash and pHash                         |
+| 18) ComparisonReport - two images differences (cmp command result)          |
+| 19) ComparedImageInfo - one compared image simple data                      |
+| 20) GpsLocation - image location decoded from Exif GPS entries              |
+| 21) ExifStripReport - removed Exif metadata entries (strip command result)  |
+| 22) ExifSetReport - set Exif tags values (exif-set command result)          |
+| 23) ExifTagChange - one set Exif tag old and new values                     |
+| 24) XmpReport - image XMP and IPTC metadata (xmp command result)            |
+| 25) XmpProperty - one XMP property                                          |
+| 26) IptcEntry - one IPTC-IIM dataset                                        |
+| 27) IccProfileInfo - embedded ICC color profile header and description      |
+| 28) AnimationInfo - animated image (GIF, APNG, WebP) frames and timing      |
+| 29) AnimationFrameInfo - one animation frame                                |
+| 30) ConversionReport - converted image sizes (conv command result)          |
+| 31) BatchReport - directory images conversion (batch command result)        |
+| 32) BatchFileInfo - one batch converted, skipped or failed image            |
+| 33) BatchProgress - images processing progress (for progress callback)      |
+| 34) ContactSheetReport - directory images contact sheet (sheet command)     |
+| 35) ContactSheetTile - one contact sheet tile (image thumbnail and label)   |
+| 36) AutoRotateReport - image Exif orientation applied (autorotate command)  |
+| {Enums:}                                                                    |
+| 1) ImageManagerError - image_manager functions errors                       |
+| 2) BatchFileStatus - batch image processing result                          |
+| {Functions:}                                                                |
+| 1) serialize_with_debug() - serializes value as its Debug text (for JSON)   |
+| 2) serialize_as_hex() - serializes 64-bit hash as 16 hex digits (for JSON)  |
+===============================================================================
+*/
+
+use std::fmt;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use image::{ ColorType, ImageError };
+use serde::{ Serialize, Serializer };
+
+use crate::image_analysis::format_detector::ImageContainer;
+use crate::image_analysis::exif_writer::ExifStripTarget;
+use crate::image_analysis::image_converter::ConversionFormat;
+use crate::image_analysis::batch_converter::ResizeOptions;
+
+//image simple data
+#[derive(Serialize)]
+pub struct ImageInfo {
+    pub file_name: String,
+    pub file_extension: String,
+    pub file_size: u64, //in bytes
+    pub width: u32, //stored pixels width
+    pub height: u32,
+    pub orientation: Option<u16>, //Exif orientation 1-8 (None -> no Exif Orientation tag)
+    pub display_width: u32, //width after Exif orientation (orientations 5-8 swap width and height)
+    pub display_height: u32,
+    #[serde(serialize_with = "serialize_with_debug")]
+    pub color_model: ColorType,
+    pub container: Option<ImageContainer>, //container detected by file content (None -> not detectable format: svg, raw)
+    pub extension_mismatch: bool, //file extension doesn't match detected container
+    pub icc_profile: Option<IccProfileInfo>, //None -> no ICC profile embedded
+    #[serde(skip)]
+    pub icc_profile_data: Option<Vec<u8>>, //embedded ICC profile bytes (saved to .icc file without reading image again)
+    pub animation: Option<AnimationInfo>, //None -> still image (width and height are first frame size)
+    pub pixels_statistics: PixelsStatistics
+}
+
+//image pixels colors statistics (image converted to RGBA8, channels values 0-255)
+#[derive(Serialize)]
+pub struct PixelsStatistics {
+    pub pixels_count: u64,
+    pub red: ChannelStatistics,
+    pub green: ChannelStatistics,
+    pub blue: ChannelStatistics,
+    pub alpha: ChannelStatistics,
+    pub transparent_pixels: u64, //pixels with alpha 0
+    pub red_dominant_pixels: u64, //pixels where red is greater than green and blue
+    pub green_dominant_pixels: u64,
+    pub blue_dominant_pixels: u64,
+    pub no_dominant_pixels: u64 //pixels where two or three channels are equal maximum (gray, yellow...)
+}
+
+//one color channel statistics
+#[derive(Serialize)]
+pub struct ChannelStatistics {
+    pub mean: f64,
+    pub median: u8,
+    pub standard_deviation: f64,
+    pub min: u8,
+    pub max: u8
+}
+
+//image Exif metadata
+#[derive(Serialize)]
+pub struct ExifReport {
+    pub file_name: String,
+    pub file_extension: String,
+    pub entries: Vec<ExifEntryInfo>,
+    pub location: Option<GpsLocation> //None -> no location embedded
+}
+
+//one Exif metadata entry
+#[derive(Serialize)]
+pub struct ExifEntryInfo {
+    pub tag: String,
+    pub tag_id: u16, //Exif tag number (0x0112 -> Orientation)
+    pub raw_value: String,
+    #[serde(skip)]
+    pub raw_values: Vec<String>, //one item per value of multi-value entries (ISO speed sequence -> [100, 200])
+    pub readable_value: String,
+    pub ifd: String
+}
+
+//image location decoded from Exif GPS entries
+#[derive(Serialize)]
+pub struct GpsLocation {
+    pub latitude: f64, //decimal degrees, south is negative
+    pub longitude: f64, //decimal degrees, west is negative
+    pub altitude: Option<f64>, //in meters, below sea level is negative
+    pub timestamp: Option<String>, //UTC, YYYY-MM-DDTHH:MM:SSZ
+    pub direction: Option<f64>, //image direction in degrees (0-360)
+    pub direction_ref: Option<String>, //"true north" or "magnetic north"
+    pub geo_uri: String //geo:latitude,longitude[,altitude]
+}
+
+//removed Exif metadata entries (stripped copy is saved by app)
+#[derive(Serialize)]
+pub struct ExifStripReport {
+    pub file_name: String,
+    pub file_extension: String,
+    pub targets: Vec<ExifStripTarget>, //stripped metadata groups, empty -> all metadata
+    pub removed_entries: Vec<ExifEntryInfo>,
+    pub remaining_entries_count: usize
+}
+
+//set Exif tags values (edited image is saved by app)
+#[derive(Serialize)]
+pub struct ExifSetReport {
+    pub file_name: String,
+    pub file_extension: String,
+    pub changed_tags: Vec<ExifTagChange>
+}
+
+//one set Exif tag old and new values
+#[derive(Serialize)]
+pub struct ExifTagChange {
+    pub tag: String,
+    pub old_value: Option<String>, //None -> tag wasn't set
+    pub new_value: String
+}
+
+//image XMP and IPTC-IIM metadata
+#[derive(Serialize)]
+pub struct XmpReport {
+    pub file_name: String,
+    pub file_extension: String,
+    pub container: ImageContainer,
+    pub xmp_properties: Vec<XmpProperty>, //empty -> no XMP packet embedded
+    pub iptc_entries: Vec<IptcEntry> //empty -> no IPTC block embedded
+}
+
+//one XMP property (struct fields are joined with "/", array items - with ", ")
+#[derive(Serialize)]
+pub struct XmpProperty {
+    pub namespace: String, //namespace prefix (dc, xmp, photoshop...)
+    pub namespace_uri: String,
+    pub property: String, //property name (subject, Rating, Flash/Fired...)
+    pub value: String
+}
+
+//one IPTC-IIM dataset (repeated datasets values, like keywords, are joined with ", ")
+#[derive(Serialize)]
+pub struct IptcEntry {
+    pub record: u8,
+    pub dataset: u8,
+    pub name: String,
+    pub value: String
+}
+
+//embedded ICC color profile header and description
+#[derive(Serialize)]
+pub struct IccProfileInfo {
+    pub description: Option<String>,
+    pub version: String, //major.minor.bugfix (4.3.0)
+    pub device_class: String, //display device, input device, output device...
+    pub color_space: String, //data color space (RGB, GRAY, CMYK...)
+    pub pcs: String, //profile connection space (XYZ or Lab)
+    pub rendering_intent: String,
+    pub known_profile: Option<String>, //sRGB, Display P3 or Adobe RGB (1998), None -> other profile
+    pub size: u64 //profile size in bytes
+}
+
+//animated image (GIF, APNG, WebP) frames and timing
+#[derive(Serialize)]
+pub struct AnimationInfo {
+    pub frames_count: usize,
+    pub loop_count: u32, //times animation is played, 0 -> infinite
+    pub duration_ms: u64, //one play duration (sum of frames delays)
+    pub frames: Vec<AnimationFrameInfo>
+}
+
+//one animation frame (area drawn on canvas)
+#[derive(Serialize)]
+pub struct AnimationFrameInfo {
+    pub number: usize, //from 1
+    pub width: u32,
+    pub height: u32,
+    pub x_offset: u32,
+    pub y_offset: u32,
+    pub delay_ms: u32,
+    pub disposal: String, //none, background, previous or unspecified (GIF)
+    pub blend: String //over (alpha blending with canvas) or source (frame replaces canvas area)
+}
+
+//converted image sizes (conv command result)
+#[derive(Serialize)]
+pub struct ConversionReport {
+    pub file_name: String,
+    pub file_extension: String,
+    pub source_container: Option<ImageContainer>,
+    pub target_format: ConversionFormat,
+    pub width: u32,
+    pub height: u32,
+    pub quality: Option<u8>, //JPEG and AVIF quality, None -> lossless format
+    pub source_size: u64, //in bytes
+    pub converted_size: u64, //in bytes
+    pub source_has_exif: bool,
+    pub exif_preserved: bool //Exif block is copied to converted image
+}
+
+//directory images conversion and resizing to mirrored output directory tree (batch command result)
+#[derive(Serialize)]
+pub struct BatchReport {
+    pub input_dir: PathBuf, //real path
+    pub output_dir: PathBuf, //real path
+    pub target_format: Option<ConversionFormat>, //None -> images keep their formats
+    pub resize: Option<ResizeOptions>, //None -> images aren't resized
+    pub images_count: u64, //all matching images
+    pub converted_count: u64,
+    pub skipped_count: u64, //output images are up to date
+    pub failed_count: u64,
+    pub source_size: u64, //in bytes, converted images sources
+    pub output_size: u64, //in bytes, converted images
+    pub saved_size: i64, //in bytes, source size - output size (negative -> output is bigger)
+    pub files: Vec<BatchFileInfo> //sorted by source path
+}
+
+//one batch converted, skipped or failed image
+#[derive(Serialize)]
+pub struct BatchFileInfo {
+    pub source_path: PathBuf,
+    pub output_path: PathBuf,
+    pub status: BatchFileStatus,
+    pub source_size: u64, //in bytes
+    pub output_size: Option<u64>, //in bytes, None -> image isn't converted
+    pub width: Option<u32>, //converted image width, None -> image isn't converted
+    pub height: Option<u32>,
+    pub error: Option<String> //failed image error message
+}
+
+//batch image processing result
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchFileStatus {
+    Converted,
+    Skipped, //output image is newer than source image (up to date)
+    Failed
+}
+
+//images processing progress of batch and sheet commands (passed to progress callback before every image processing)
+pub struct BatchProgress<'a> {
+    pub processed_images: usize,
+    pub images_count: usize,
+    pub current_file: &'a Path
+}
+
+//images with specific extensions scan result
+#[derive(Serialize)]
+pub struct ExtensionScanReport {
+    pub formats: Vec<FormatScanInfo>, //every scanned format images in all directories
+    pub images_count: u64,
+    pub total_size: u64, //in bytes
+    pub directories: Vec<DirectoryScanInfo>
+}
+
+//images count and size in one scanned directory (scan root)
+#[derive(Serialize)]
+pub struct DirectoryScanInfo {
+    pub name: String, //default directory name (Downloads dir...) or root path as entered
+    pub path: PathBuf, //real (canonical) directory path
+    pub images_count: u64,
+    pub images_size: u64, //in bytes
+    pub formats: Vec<FormatScanInfo>, //every scanned format images in directory (same order as report formats)
+    pub mismatched_files: Vec<MismatchedFileInfo> //files with extension, which doesn't match content
+}
+
+//file with extension, which doesn't match content (photo.png, which is JPEG)
+#[derive(Serialize)]
+pub struct MismatchedFileInfo {
+    pub path: PathBuf,
+    pub extension: String, //empty if file has no extension
+    pub container: Option<ImageContainer> //None -> content isn't recognized image
+}
+
+//one image format images count and size (format -> extension or joined aliases: jpg/jpeg)
+#[derive(Serialize, Clone)]
+pub struct FormatScanInfo {
+    pub format: String,
+    pub images_count: u64,
+    pub images_size: u64 //in bytes
+}
+
+//directories scan progress (passed to progress callback before every directory scan)
+pub struct ScanProgress<'a> {
+    pub scanned_dirs: usize,
+    pub dirs_count: usize,
+    pub images_found: u64,
+    pub current_dir: &'a Path
+}
+
+//image dominant colors palette
+#[derive(Serialize)]
+pub struct PaletteReport {
+    pub file_name: String,
+    pub pixels_count: u64, //counted (not fully transparent) pixels
+    pub colors: Vec<PaletteColor> //sorted by coverage, the most covering first
+}
+
+//one palette color
+#[derive(Serialize)]
+pub struct PaletteColor {
+    pub hex: String, //#RRGGBB
+    pub rgb: [u8; 3],
+    pub hsl: [f64; 3], //hue (degrees), saturation (%), lightness (%)
+    pub pixels_count: u64,
+    pub coverage: f64 //percent of counted pixels
+}
+
+//image red, green, blue and luminance histograms (256 bins, bin index -> channel value)
+#[derive(Serialize)]
+pub struct HistogramReport {
+    pub file_name: String,
+    pub pixels_count: u64, //counted (not fully transparent) pixels
+    pub red: Vec<u64>,
+    pub green: Vec<u64>,
+    pub blue: Vec<u64>,
+    pub luminance: Vec<u64>
+}
+
+//exact duplicate images in scanned directories
+#[derive(Serialize)]
+pub struct DuplicatesReport {
+    pub directories: Vec<PathBuf>, //scanned directories real paths
+    pub images_count: u64, //all scanned images
+    pub duplicate_files: u64, //copies count (every group first file isn't counted)
+    pub wasted_size: u64, //in bytes, all copies size
+    pub duplicate_groups: Vec<DuplicateGroup> //sorted by wasted size, the biggest first
+}
+
+//group of byte-identical images
+#[derive(Serialize)]
+pub struct DuplicateGroup {
+    pub hash: String, //SHA-256 content hash
+    pub file_size: u64, //one file size in bytes
+    pub wasted_size: u64, //in bytes, all files except one
+    pub files: Vec<PathBuf> //real paths, sorted
+}
+
+//visually similar images clusters in scanned directories
+#[derive(Serialize)]
+pub struct SimilarImagesReport {
+    pub directories: Vec<PathBuf>, //scanned directories real paths
+    pub threshold: u32, //images with hashes distance below threshold are similar
+    pub images_count: u64, //all compared images
+    pub skipped_files: u64, //images, which couldn't be decoded
+    pub clusters: Vec<SimilarImagesCluster> //the biggest clusters first
+}
+
+//cluster of visually similar images
+#[derive(Serialize)]
+pub struct SimilarImagesCluster {
+    pub images: Vec<SimilarImageInfo> //the biggest image (by pixels count) first
+}
+
+//one image in similar images cluster
+#[derive(Serialize)]
+pub struct SimilarImageInfo {
+    pub path: PathBuf, //real path
+    pub width: u32,
+    pub height: u32,
+    pub file_size: u64, //in bytes
+    pub distance: u32, //hashes distance to cluster first image (0-64)
+    pub hashes: PerceptualHashes
+}
+
+//image 64-bit perceptual hashes (hex strings in JSON)
+#[derive(Serialize, Clone)]
+pub struct PerceptualHashes {
+    #[serde(serialize_with = "serialize_as_hex")]
+    pub average_hash: u64, //aHash
+    #[serde(serialize_with = "serialize_as_hex")]
+    pub difference_hash: u64, //dHash
+    #[serde(serialize_with = "serialize_as_hex")]
+    pub dct_hash: u64 //pHash
+}
+
+//two images differences, images with different dimensions are compared in first image dimensions
+#[derive(Serialize)]
+pub struct ComparisonReport {
+    pub first_image: ComparedImageInfo,
+    pub second_image: ComparedImageInfo,
+    pub dimensions_match: bool, //false -> second image was resized for comparison
+    pub color_models_match: bool,
+    pub pixels_count: u64, //compared pixels
+    pub different_pixels: u64, //pixels with at least one different channel
+    pub mse: f64, //mean squared error of RGBA channels (0 -> identical)
+    pub psnr: Option<f64>, //in dB, None -> identical images (infinite PSNR)
+    pub ssim: f64 //luminance structural similarity (1 -> identical)
+}
+
+//one compared image simple data
+#[derive(Serialize)]
+pub struct ComparedImageInfo {
+    pub file_name: String,
+    pub width: u32,
+    pub height: u32,
+    #[serde(serialize_with = "serialize_with_debug")]
+    pub color_model: ColorType
+}
+
+//directory images contact sheet (sheet command result)
+#[derive(Serialize)]
+pub struct ContactSheetReport {
+    pub directory: PathBuf, //real path
+    pub columns: u32,
+    pub rows: u32,
+    pub thumbnail_size: u32, //thumbnails fit in thumbnail_size x thumbnail_size square
+    pub width: u32, //contact sheet width in pixels
+    pub height: u32,
+    pub images_count: u64, //all found images (only first images, which fit in MAX_SHEET_IMAGES and MAX_SHEET_PIXELS, are placed)
+    pub tiles: Vec<ContactSheetTile>, //sorted by path (rows from left to right)
+    pub skipped_files: Vec<PathBuf> //images, which couldn't be decoded
+}
+
+//one contact sheet tile (image thumbnail and label: filename, dimensions, file size)
+#[derive(Serialize)]
+pub struct ContactSheetTile {
+    pub path: PathBuf,
+    pub file_name: String,
+    pub width: u32,
+    pub height: u32,
+    pub file_size: u64, //in bytes
+    pub column: u32, //from 1
+    pub row: u32 //from 1
+}
+
+//image Exif orientation applied to pixels (autorotate command result)
+#[derive(Serialize)]
+pub struct AutoRotateReport {
+    pub file_name: String,
+    pub file_extension: String,
+    pub orientation: Option<u16>, //source Exif orientation 1-8 (None -> no Exif Orientation tag)
+    pub transformation: String, //orientation name, for example "rotated 90° clockwise"
+    pub stored_width: u32, //source pixels width
+    pub stored_height: u32,
+    pub width: u32, //auto-rotated image width
+    pub height: u32,
+    pub rotated: bool //false -> image orientation is already normal, image isn't saved
+}
+
+//image_manager functions errors
+#[derive(Debug)]
+pub enum ImageManagerError {
+    NotImage { extension: String }, //file extension isn't image extension
+    ImageOpen { path: String, error: ImageError }, //image couldn't open or decode
+    ExifNotSupported { extension: String }, //image format doesn't support Exif metadata
+    ExifParse(rexif::ExifError), //Exif metadata couldn't parse
+    ExifWriteNotSupported { extension: String }, //Exif metadata can't be written to image format
+    ExifTagNotEditable { tag: String }, //Exif tag can't be set by exif-set command
+    InvalidExifValue { tag: String, value: String, expected: String }, //Exif tag value doesn't match tag type
+    ExifWrite { reason: String }, //Exif metadata couldn't be written
+    MetadataNotSupported { extension: String }, //image format doesn't support XMP and IPTC metadata
+    XmpParse { reason: String }, //XMP packet couldn't parse
+    IccProfileNotFound { file_name: String }, //image has no valid embedded ICC profile
+    NotAnimated { file_name: String }, //frames extraction requested for still image
+    FrameOutOfRange { frame: usize, frames_count: usize }, //selected frame number is greater than frames count
+    ImageEncode { format: String, error: ImageError }, //image couldn't be encoded to target format
+    ExifKeepNotSupported { format: String }, //conversion target format can't keep Exif metadata
+    OrientationNotSupported { extension: String }, //Exif orientation can't be applied to image format
+    BatchOutputDir { path: PathBuf }, //batch output directory is input directory
+    NoImagesFound { path: PathBuf }, //directory has no images, which can be decoded
+    ContactSheetTooBig { max_pixels: u64 }, //one contact sheet row doesn't fit in pixel budget
+    OutputFile(io::Error), //output file couldn't create or write
+    ScanRoot { path: PathBuf, error: io::Error } //scan root directory doesn't exist or couldn't be read
+}
+
+impl ImageManagerError {
+    //returns stable error kind name (used in JSON output)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ImageManagerError::NotImage { .. } => "not_image",
+            ImageManagerError::ImageOpen { .. } => "image_open",
+            ImageManagerError::ExifNotSupported { .. } => "exif_not_supported",
+            ImageManagerError::ExifParse(_) => "exif_parse",
+            ImageManagerError::ExifWriteNotSupported { .. } => "exif_write_not_supported",
+            ImageManagerError::ExifTagNotEditable { .. } => "exif_tag_not_editable",
+            ImageManagerError::InvalidExifValue { .. } => "invalid_exif_value",
+            ImageManagerError::ExifWrite { .. } => "exif_write",
+            ImageManagerError::MetadataNotSupported { .. } => "metadata_not_supported",
+            ImageManagerError::XmpParse { .. } => "xmp_parse",
+            ImageManagerError::IccProfileNotFound { .. } => "icc_profile_not_found",
+            ImageManagerError::NotAnimated { .. } => "not_animated",
+            ImageManagerError::FrameOutOfRange { .. } => "frame_out_of_range",
+            ImageManagerError::ImageEncode { .. } => "image_encode",
+            ImageManagerError::ExifKeepNotSupported { .. } => "exif_keep_not_supported",
+            ImageManagerError::OrientationNotSupported { .. } => "orientation_not_supported",
+            ImageManagerError::BatchOutputDir { .. } => "batch_output_dir",
+            ImageManagerError::NoImagesFound { .. } => "no_images_found",
+            ImageManagerError::ContactSheetTooBig { .. } => "contact_sheet_too_big",
+            ImageManagerError::OutputFile(_) => "output_file",
+            ImageManagerError::ScanRoot { .. } => "scan_root"
+        }
+    }
+}
+
+impl fmt::Display for ImageManagerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImageManagerError::NotImage { extension } => write!(f, "({}) this is not image", extension),
+            ImageManagerError::ImageOpen { path, error } => write!(f, "image from path [{}] couldn't open: {}", path, error),
+            ImageManagerError::ExifNotSupported { extension } => write!(f, "image format ({}) doesn't support Exif metadata", extension),
+            ImageManagerError::ExifParse(error) => write!(f, "Exif metadata not fetched: {}", error),
+            ImageManagerError::ExifWriteNotSupported { extension } => write!(f, "Exif metadata can't be written to image format ({})", extension),
+            ImageManagerError::ExifTagNotEditable { tag } => write!(f, "Exif tag ({}) can't be set", tag),
+            ImageManagerError::InvalidExifValue { tag, value, expected } => write!(f, "value [{}] of Exif tag ({}) is invalid, expected {}", value, tag, expected),
+            ImageManagerError::ExifWrite { reason } => write!(f, "Exif metadata couldn't be written: {}", reason),
+            ImageManagerError::MetadataNotSupported { extension } => write!(f, "image format ({}) doesn't support XMP and IPTC metadata", extension),
+            ImageManagerError::XmpParse { reason } => write!(f, "XMP packet couldn't parse: {}", reason),
+            ImageManagerError::IccProfileNotFound { file_name } => write!(f, "image [{}] has no valid embedded ICC profile", file_name),
+            ImageManagerError::NotAnimated { file_name } => write!(f, "image [{}] isn't animated", file_name),
+            ImageManagerError::FrameOutOfRange { frame, frames_count } => write!(f, "frame {} is out of range, animation has {} frames", frame, frames_count),
+            ImageManagerError::ImageEncode { format, error } => write!(f, "image couldn't be encoded to {}: {}", format, error),
+            ImageManagerError::ExifKeepNotSupported { format } => write!(f, "converted image format ({}) can't keep Exif metadata", format),
+            ImageManagerError::OrientationNotSupported { extension } => write!(f, "Exif orientation can't be applied to image format ({})", extension),
+            ImageManagerError::BatchOutputDir { path } => write!(f, "output directory [{}] can't be input directory", path.display()),
+            ImageManagerError::NoImagesFound { path } => write!(f, "directory [{}] has no images, which can be decoded", path.display()),
+            ImageManagerError::ContactSheetTooBig { max_pixels } => write!(f, "contact sheet row is bigger than {} pixels, reduce columns count or thumbnails size", max_pixels),
+            ImageManagerError::OutputFile(error) => write!(f, "output file couldn't be written: {}", error),
+            ImageManagerError::ScanRoot { path, error } => write!(f, "scan directory [{}] couldn't be read: {}", path.display(), error)
+        }
+    }
+}
+
+impl std::error::Error for ImageManagerError {}
+
+//serializes value as its Debug text (for JSON), for example ColorType::Rgb8 -> "Rgb8"
+fn serialize_with_debug<T: fmt::Debug, S: Serializer>(
+    value: &T,
+    serializer: S
+) -> Result<S::Ok, S::Error> {
+    return serializer.serialize_str(&format!("{:?}", value))
+}
+
+//serializes 64-bit hash as 16 hex digits (for JSON)
+fn serialize_as_hex<S: Serializer>(
+    hash: &u64,
+    serializer: S
+) -> Result<S::Ok, S::Error> {
+    return serializer.serialize_str(&format!("{:016x}", hash))
+}
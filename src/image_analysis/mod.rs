@@ -0,0 +1,19 @@
+//image_analysis library directory public modules
+pub mod image_manager;
+pub mod image_reports;
+pub mod format_detector;
+pub mod palette_extractor;
+pub mod histogram_builder;
+pub mod duplicates_finder;
+pub mod similarity_finder;
+pub mod image_comparator;
+pub mod exif_location;
+pub mod exif_writer;
+pub mod container_reader;
+pub mod xmp_reader;
+pub mod icc_profile_reader;
+pub mod animation_reader;
+pub mod image_converter;
+pub mod batch_converter;
+pub mod contact_sheet_builder;
+pub mod orientation_manager;
@@ -0,0 +1,144 @@
+/*
+==================================================================================
+| This module reads Exif Orientation tag (0x0112) and applies it to image        |
+| pixels. Orientation 1 is normal, 2-4 flip or rotate image by 180 degrees, 5-8  |
+| also swap width and height (image is displayed rotated by 90 degrees), so      |
+| displayed dimensions differ from stored dimensions. Auto-rotated JPEG, PNG and |
+| WebP images keep Exif metadata with Orientation reset to 1 (normal).           |
+| {Functions:}                                                                   |
+| 1) read_exif_orientation() - reads Orientation tag from Exif block in image    |
+| 2) get_orientation_name() - gets orientation transformation name               |
+| 3) get_display_dimensions() - gets image dimensions after orientation          |
+| 4) apply_exif_orientation() - rotates and flips image by orientation           |
+| 5) auto_rotate_image() - applies Exif orientation to image pixels              |
+==================================================================================
+*/
+
+use image::{ DynamicImage, ImageError };
+use image::metadata::Orientation;
+
+use crate::image_analysis::image_manager::{ check_image_file, load_image };
+use crate::image_analysis::format_detector::ImageContainer;
+use crate::image_analysis::container_reader::get_tiff_ifd0_value;
+use crate::image_analysis::exif_writer::{ find_exif_block, replace_exif_block, set_exif_block_tags, EditableExifTag };
+use crate::image_analysis::image_converter::{ encode_image, ConversionFormat };
+use crate::image_analysis::image_reports::*;
+
+//Exif Orientation tag
+const ORIENTATION_TAG: u16 = 0x0112;
+
+//auto-rotated JPEG images quality (image is encoded again, high quality keeps details)
+const AUTOROTATE_JPEG_QUALITY: u8 = 95;
+
+//reads Orientation tag from Exif block in image (None -> no Exif block, no tag or not valid value 1-8)
+pub(crate) fn read_exif_orientation(
+    data: &[u8],
+    container: ImageContainer
+) -> Option<u16> {
+    let block = &data[find_exif_block(data, container)?];
+    let value = get_tiff_ifd0_value(block, ORIENTATION_TAG)?;
+    let bytes = [*value.first()?, *value.get(1)?];
+
+    //Exif block byte order (II - little endian, MM - big endian)
+    let orientation = if block.starts_with(b"II") {
+        u16::from_le_bytes(bytes)
+    } else {
+        u16::from_be_bytes(bytes)
+    };
+
+    return Some(orientation).filter(|orientation| (1..=8).contains(orientation))
+}
+
+//gets orientation transformation name (how stored image is turned for displaying)
+pub fn get_orientation_name(orientation: u16) -> &'static str {
+    match orientation {
+        1 => "normal",
+        2 => "mirrored horizontally",
+        3 => "rotated 180°",
+        4 => "mirrored vertically",
+        5 => "mirrored horizontally and rotated 270° clockwise",
+        6 => "rotated 90° clockwise",
+        7 => "mirrored horizontally and rotated 90° clockwise",
+        8 => "rotated 270° clockwise",
+        _ => "unknown"
+    }
+}
+
+//gets image dimensions after orientation (orientations 5-8 swap width and height)
+pub(crate) fn get_display_dimensions(
+    width: u32,
+    height: u32,
+    orientation: Option<u16>
+) -> (u32, u32) {
+    match orientation {
+        Some(5..=8) => (height, width),
+        _ => (width, height)
+    }
+}
+
+//rotates and flips image by Exif orientation (None or 1 -> image isn't changed)
+pub(crate) fn apply_exif_orientation(
+    mut image: DynamicImage,
+    orientation: Option<u16>
+) -> DynamicImage {
+    if let Some(orientation) = orientation.and_then(|orientation| Orientation::from_exif(orientation as u8)) {
+        image.apply_orientation(orientation);
+    }
+
+    return image
+}
+
+//applies Exif orientation to JPEG, PNG, WebP image pixels and resets Orientation tag to 1 (other Exif metadata is kept)
+//returns auto-rotation report and auto-rotated image bytes (None -> image is already normal, nothing is changed)
+pub fn auto_rotate_image(path: String) -> Result<(AutoRotateReport, Option<Vec<u8>>), ImageManagerError> {
+    let (file_name, file_extension, container) = check_image_file(&path)?;
+    let trimmed_path = path.trim();
+
+    let (container, format) = match container {
+        Some(container @ ImageContainer::Jpeg) => (container, ConversionFormat::Jpeg),
+        Some(container @ ImageContainer::Png) => (container, ConversionFormat::Png),
+        Some(container @ ImageContainer::WebP) => (container, ConversionFormat::WebP),
+        _ => return Err(ImageManagerError::OrientationNotSupported { extension: file_extension })
+    };
+
+    let data = std::fs::read(trimmed_path)
+        .map_err(|error| ImageManagerError::ImageOpen { path: path.clone(), error: ImageError::IoError(error) })?;
+
+    let image = load_image(trimmed_path).map_err(|error| ImageManagerError::ImageOpen { path: path.clone(), error })?;
+
+    let orientation = read_exif_orientation(&data, container);
+    let (stored_width, stored_height) = (image.width(), image.height());
+    let (width, height) = get_display_dimensions(stored_width, stored_height, orientation);
+
+    let mut report = AutoRotateReport {
+        file_name,
+        file_extension,
+        orientation,
+        transformation: get_orientation_name(orientation.unwrap_or(1)).to_string(),
+        stored_width,
+        stored_height,
+        width,
+        height,
+        rotated: false
+    };
+
+    //image without orientation or with normal orientation is already displayed as stored
+    if orientation.unwrap_or(1) == 1 {
+        return Ok((report, None))
+    }
+
+    let rotated_image = apply_exif_orientation(image, orientation);
+    let mut rotated_data = encode_image(rotated_image, format, AUTOROTATE_JPEG_QUALITY)?;
+
+    //Exif block is copied with normal orientation, so viewers don't rotate image again
+    if let Some(block) = find_exif_block(&data, container) {
+        let mut block_data = data[block].to_vec();
+
+        set_exif_block_tags(&mut block_data, &[(EditableExifTag::Orientation, String::from("1"))])?;
+        replace_exif_block(&mut rotated_data, container, block_data)?;
+    }
+
+    report.rotated = true;
+
+    return Ok((report, Some(rotated_data)))
+}
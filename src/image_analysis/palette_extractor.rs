@@ -0,0 +1,208 @@
+/*
+==================================================================================
+| This module extracts image dominant colors palette with median cut algorithm. |
+| Pixels are grouped into 5-bit per channel color bins (32768 bins) first, then  |
+| bins boxes are split at weighted median of the longest channel range.          |
+| {Functions:}                                                                   |
+| 1) rgb_to_hsl() - converts RGB color to HSL                                    |
+| 2) get_color_bins() - groups image pixels into color bins                      |
+| 3) split_colors_box() - splits colors box at median of the longest channel     |
+| 4) median_cut() - splits color bins into boxes (one box -> one palette color)  |
+| 5) get_image_palette() - extracts image dominant colors palette                |
+==================================================================================
+*/
+
+use image::DynamicImage;
+
+use std::cmp::Reverse;
+
+use crate::image_analysis::image_manager::{ check_image_file, load_image };
+use crate::image_analysis::image_reports::*;
+
+//pixels with same 5 high bits of every channel go to one bin
+const BIN_BITS_SHIFT: u8 = 3;
+const BINS_PER_CHANNEL: usize = 1 << (8 - BIN_BITS_SHIFT);
+
+//colors with same 5 high bits of every channel (sums for average bin color)
+#[derive(Clone, Copy)]
+struct ColorBin {
+    key: [u8; 3], //bin position for every channel (0-31)
+    sums: [u64; 3], //red, green, blue values sums
+    pixels_count: u64
+}
+
+//converts RGB color to HSL (hue in degrees, saturation and lightness in percent)
+pub fn rgb_to_hsl(rgb: [u8; 3]) -> [f64; 3] {
+    let [red, green, blue] = rgb.map(|channel| channel as f64 / 255.0);
+
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+
+    //gray colors have no hue and saturation
+    if delta == 0.0 {
+        return [0.0, 0.0, lightness * 100.0]
+    }
+
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+
+    let hue = if max == red {
+        60.0 * ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == green {
+        60.0 * ((blue - red) / delta + 2.0)
+    } else {
+        60.0 * ((red - green) / delta + 4.0)
+    };
+
+    return [hue, saturation * 100.0, lightness * 100.0]
+}
+
+//groups image pixels into color bins (fully transparent pixels are skipped)
+fn get_color_bins(image: &DynamicImage) -> Vec<ColorBin> {
+    let mut bins = vec![
+        ColorBin { key: [0; 3], sums: [0; 3], pixels_count: 0 };
+        BINS_PER_CHANNEL * BINS_PER_CHANNEL * BINS_PER_CHANNEL
+    ];
+
+    let rgba_image = image.to_rgba8();
+
+    for pixel in rgba_image.pixels() {
+        let [red, green, blue, alpha] = pixel.0;
+
+        if alpha == 0 {
+            continue
+        }
+
+        let key = [red >> BIN_BITS_SHIFT, green >> BIN_BITS_SHIFT, blue >> BIN_BITS_SHIFT];
+        let bin_index = (key[0] as usize * BINS_PER_CHANNEL + key[1] as usize) * BINS_PER_CHANNEL + key[2] as usize;
+
+        let bin = &mut bins[bin_index];
+        bin.key = key;
+        bin.sums[0] += red as u64;
+        bin.sums[1] += green as u64;
+        bin.sums[2] += blue as u64;
+        bin.pixels_count += 1;
+    }
+
+    return bins.into_iter().filter(|bin| bin.pixels_count > 0).collect()
+}
+
+//splits colors box at weighted median of the longest channel range
+fn split_colors_box(mut colors_box: Vec<ColorBin>) -> (Vec<ColorBin>, Vec<ColorBin>) {
+    //finding channel with the longest range
+    let mut longest_channel = 0;
+    let mut longest_range = 0;
+
+    for channel in 0..3 {
+        let min = colors_box.iter().map(|bin| bin.key[channel]).min().unwrap_or(0);
+        let max = colors_box.iter().map(|bin| bin.key[channel]).max().unwrap_or(0);
+
+        if max - min >= longest_range {
+            longest_range = max - min;
+            longest_channel = channel;
+        }
+    }
+
+    colors_box.sort_by_key(|bin| bin.key[longest_channel]);
+
+    //splitting at the bin, where half of box pixels is reached (both boxes keep at least one bin)
+    let box_pixels_count: u64 = colors_box.iter().map(|bin| bin.pixels_count).sum();
+    let mut counted_pixels = 0;
+    let mut split_index = 1;
+
+    for (index, bin) in colors_box.iter().enumerate() {
+        counted_pixels += bin.pixels_count;
+
+        if counted_pixels * 2 >= box_pixels_count {
+            split_index = (index + 1).clamp(1, colors_box.len() - 1);
+            break
+        }
+    }
+
+    let second_box = colors_box.split_off(split_index);
+
+    return (colors_box, second_box)
+}
+
+//splits color bins into boxes until boxes count reaches colors count (one box -> one palette color)
+fn median_cut(
+    bins: Vec<ColorBin>,
+    colors_count: usize
+) -> Vec<Vec<ColorBin>> {
+    let mut boxes = vec![bins];
+
+    while boxes.len() < colors_count {
+        //splitting box with the most pixels, which has more than one bin
+        let box_to_split = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, colors_box)| colors_box.len() > 1)
+            .max_by_key(|(_, colors_box)| colors_box.iter().map(|bin| bin.pixels_count).sum::<u64>())
+            .map(|(index, _)| index);
+
+        let Some(box_index) = box_to_split else {
+            break //all boxes contain one bin, image has less colors than requested
+        };
+
+        let (first_box, second_box) = split_colors_box(boxes.swap_remove(box_index));
+
+        boxes.push(first_box);
+        boxes.push(second_box);
+    }
+
+    return boxes
+}
+
+//extracts image dominant colors palette (colors_count -> max palette colors count)
+pub fn get_image_palette(
+    path: String,
+    colors_count: usize
+) -> Result<PaletteReport, ImageManagerError> {
+    let (file_name, _, _) = check_image_file(&path)?;
+
+    let img = match load_image(path.trim()) {
+        Ok(img) => img,
+        Err(error) => return Err(ImageManagerError::ImageOpen { path, error })
+    };
+
+    let bins = get_color_bins(&img);
+    let pixels_count: u64 = bins.iter().map(|bin| bin.pixels_count).sum();
+
+    let mut colors = Vec::new();
+
+    if pixels_count > 0 {
+        for colors_box in median_cut(bins, colors_count.max(1)) {
+            let box_pixels_count: u64 = colors_box.iter().map(|bin| bin.pixels_count).sum();
+
+            //box color is average color of all box pixels
+            let mut rgb = [0u8; 3];
+
+            for (channel, value) in rgb.iter_mut().enumerate() {
+                let channel_sum: u64 = colors_box.iter().map(|bin| bin.sums[channel]).sum();
+                *value = (channel_sum as f64 / box_pixels_count as f64).round() as u8;
+            }
+
+            colors.push(
+                PaletteColor {
+                    hex: format!("#{:02X}{:02X}{:02X}", rgb[0], rgb[1], rgb[2]),
+                    rgb,
+                    hsl: rgb_to_hsl(rgb),
+                    pixels_count: box_pixels_count,
+                    coverage: box_pixels_count as f64 / pixels_count as f64 * 100.0
+                }
+            );
+        }
+    }
+
+    //the most covering colors first
+    colors.sort_by_key(|color| Reverse(color.pixels_count));
+
+    return Ok(
+        PaletteReport {
+            file_name,
+            pixels_count,
+            colors
+        }
+    )
+}
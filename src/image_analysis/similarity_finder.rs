@@ -0,0 +1,258 @@
+/*
+==================================================================================
+| This module finds visually similar images (resized, recompressed or slightly   |
+| cropped copies) with perceptual hashes. Every image gets 64-bit aHash (average |
+| hash), dHash (difference hash) and pHash (DCT hash). Images distance is mean   |
+| Hamming distance of three hashes, images with distance below threshold are     |
+| joined into clusters.                                                          |
+| {Functions:}                                                                   |
+| 1) get_gray_pixels() - resizes image to grayscale pixels grid                  |
+| 2) get_average_hash() - counts aHash                                           |
+| 3) get_difference_hash() - counts dHash                                        |
+| 4) get_dct_hash() - counts pHash                                               |
+| 5) get_perceptual_hashes() - counts all image perceptual hashes                |
+| 6) get_hashes_distance() - counts mean Hamming distance of hashes             |
+| 7) find_root() - finds cluster root of image (union-find)                      |
+| 8) find_similar_images() - finds similar images clusters in scan roots        |
+==================================================================================
+*/
+
+use image::DynamicImage;
+use image::imageops::FilterType;
+
+use std::collections::HashSet;
+use std::f64::consts::PI;
+use std::path::PathBuf;
+
+use crate::image_analysis::image_manager::*;
+use crate::image_analysis::image_reports::*;
+
+//pHash is counted from 32x32 image DCT low frequencies (8x8)
+const DCT_SIZE: usize = 32;
+const HASH_SIZE: usize = 8;
+
+//resizes image to grayscale pixels grid (width x height), pixels are stored by rows
+fn get_gray_pixels(
+    image: &DynamicImage,
+    width: u32,
+    height: u32
+) -> Vec<f64> {
+    return image
+        .resize_exact(width, height, FilterType::Triangle)
+        .to_luma8()
+        .pixels()
+        .map(|pixel| pixel.0[0] as f64)
+        .collect()
+}
+
+//counts aHash: 8x8 pixel bit is 1 if pixel is brighter than mean
+fn get_average_hash(image: &DynamicImage) -> u64 {
+    let pixels = get_gray_pixels(image, HASH_SIZE as u32, HASH_SIZE as u32);
+    let mean = pixels.iter().sum::<f64>() / pixels.len() as f64;
+
+    return pixels.iter().fold(0, |hash, &pixel| (hash << 1) | (pixel > mean) as u64)
+}
+
+//counts dHash: 9x8 pixels, bit is 1 if pixel is brighter than right neighbour
+fn get_difference_hash(image: &DynamicImage) -> u64 {
+    let pixels = get_gray_pixels(image, HASH_SIZE as u32 + 1, HASH_SIZE as u32);
+    let mut hash = 0;
+
+    for row in 0..HASH_SIZE {
+        for column in 0..HASH_SIZE {
+            let index = row * (HASH_SIZE + 1) + column;
+            hash = (hash << 1) | (pixels[index] > pixels[index + 1]) as u64;
+        }
+    }
+
+    return hash
+}
+
+//counts pHash: 32x32 pixels DCT, 8x8 low frequencies bit is 1 if coefficient is greater than median (DC coefficient excluded from median)
+fn get_dct_hash(image: &DynamicImage) -> u64 {
+    let pixels = get_gray_pixels(image, DCT_SIZE as u32, DCT_SIZE as u32);
+
+    //DCT-II coefficients table
+    let mut cosines = vec![0.0; DCT_SIZE * DCT_SIZE];
+
+    for frequency in 0..DCT_SIZE {
+        for position in 0..DCT_SIZE {
+            cosines[frequency * DCT_SIZE + position] = ((2 * position + 1) as f64 * frequency as f64 * PI / (2 * DCT_SIZE) as f64).cos();
+        }
+    }
+
+    //rows DCT, then columns DCT (only low frequencies are needed)
+    let mut rows_dct = vec![0.0; DCT_SIZE * HASH_SIZE];
+
+    for row in 0..DCT_SIZE {
+        for frequency in 0..HASH_SIZE {
+            rows_dct[row * HASH_SIZE + frequency] = (0..DCT_SIZE)
+                .map(|column| pixels[row * DCT_SIZE + column] * cosines[frequency * DCT_SIZE + column])
+                .sum();
+        }
+    }
+
+    let mut coefficients = vec![0.0; HASH_SIZE * HASH_SIZE];
+
+    for vertical_frequency in 0..HASH_SIZE {
+        for horizontal_frequency in 0..HASH_SIZE {
+            coefficients[vertical_frequency * HASH_SIZE + horizontal_frequency] = (0..DCT_SIZE)
+                .map(|row| rows_dct[row * HASH_SIZE + horizontal_frequency] * cosines[vertical_frequency * DCT_SIZE + row])
+                .sum();
+        }
+    }
+
+    let mut sorted_coefficients = coefficients[1..].to_vec();
+    sorted_coefficients.sort_by(|first, second| first.total_cmp(second));
+    let median = sorted_coefficients[sorted_coefficients.len() / 2];
+
+    return coefficients.iter().fold(0, |hash, &coefficient| (hash << 1) | (coefficient > median) as u64)
+}
+
+//counts all image perceptual hashes
+fn get_perceptual_hashes(image: &DynamicImage) -> PerceptualHashes {
+    return PerceptualHashes {
+        average_hash: get_average_hash(image),
+        difference_hash: get_difference_hash(image),
+        dct_hash: get_dct_hash(image)
+    }
+}
+
+//counts mean Hamming distance of images aHash, dHash and pHash (0-64)
+fn get_hashes_distance(
+    first: &PerceptualHashes,
+    second: &PerceptualHashes
+) -> u32 {
+    let distances_sum = (first.average_hash ^ second.average_hash).count_ones()
+        + (first.difference_hash ^ second.difference_hash).count_ones()
+        + (first.dct_hash ^ second.dct_hash).count_ones();
+
+    return (distances_sum as f64 / 3.0).round() as u32
+}
+
+//finds cluster root of image (union-find with path halving)
+fn find_root(
+    parents: &mut [usize],
+    mut index: usize
+) -> usize {
+    while parents[index] != index {
+        parents[index] = parents[parents[index]];
+        index = parents[index];
+    }
+
+    return index
+}
+
+//finds clusters of similar images (distance below threshold) in scan roots, on_progress is called before every root scan
+pub fn find_similar_images(
+    scan_options: &ScanOptions,
+    threshold: u32,
+    mut on_progress: impl FnMut(&ScanProgress)
+) -> Result<SimilarImagesReport, ImageManagerError> {
+    let scan_roots = resolve_scan_roots(scan_options)?;
+
+    let mut images: Vec<SimilarImageInfo> = Vec::new();
+    let mut seen_paths: HashSet<PathBuf> = HashSet::new(); //real paths of already found images
+    let mut skipped_files = 0;
+
+    //decoding every image and counting its hashes
+    for (dir_index, (_, current_dir)) in scan_roots.iter().enumerate() {
+        on_progress(
+            &ScanProgress {
+                scanned_dirs: dir_index + 1,
+                dirs_count: scan_roots.len(),
+                images_found: images.len() as u64,
+                current_dir
+            }
+        );
+
+        for entry in walk_scan_root(current_dir, scan_options) {
+            let is_image = detect_scanned_file_format(entry.path()).is_some_and(|file_format| file_format.format.is_some());
+
+            if !is_image {
+                continue
+            }
+
+            let path: PathBuf = entry.path().canonicalize().unwrap_or(entry.path().to_path_buf());
+
+            //same file found by several roots or symlinks is counted once
+            if !seen_paths.insert(path.clone()) {
+                continue
+            }
+
+            let img = match load_image(&path.to_string_lossy()) {
+                Ok(img) => img,
+                Err(_) => {
+                    skipped_files += 1; //not decodable image (svg, raw, broken file)
+                    continue
+                }
+            };
+
+            images.push(
+                SimilarImageInfo {
+                    width: img.width(),
+                    height: img.height(),
+                    file_size: entry.metadata().map(|metadata| metadata.len()).unwrap_or(0),
+                    hashes: get_perceptual_hashes(&img),
+                    distance: 0,
+                    path
+                }
+            );
+        }
+    }
+
+    //joining images pairs with distance below threshold
+    let mut parents: Vec<usize> = (0..images.len()).collect();
+
+    for first in 0..images.len() {
+        for second in first + 1..images.len() {
+            if get_hashes_distance(&images[first].hashes, &images[second].hashes) < threshold {
+                let first_root = find_root(&mut parents, first);
+                let second_root = find_root(&mut parents, second);
+
+                parents[second_root] = first_root;
+            }
+        }
+    }
+
+    let images_count = images.len() as u64;
+    let mut clusters_images: Vec<Vec<SimilarImageInfo>> = (0..images.len()).map(|_| Vec::new()).collect();
+
+    for (index, image) in images.into_iter().enumerate() {
+        let root = find_root(&mut parents, index);
+        clusters_images[root].push(image);
+    }
+
+    let mut clusters = Vec::new();
+
+    for mut cluster_images in clusters_images.into_iter().filter(|cluster_images| cluster_images.len() > 1) {
+        //the biggest image (probably original) first, distances are counted to it
+        cluster_images.sort_by(|first, second| {
+            (second.width as u64 * second.height as u64)
+                .cmp(&(first.width as u64 * first.height as u64))
+                .then(second.file_size.cmp(&first.file_size))
+                .then(first.path.cmp(&second.path))
+        });
+
+        let reference_hashes = cluster_images[0].hashes.clone();
+
+        for image in cluster_images.iter_mut() {
+            image.distance = get_hashes_distance(&reference_hashes, &image.hashes);
+        }
+
+        clusters.push(SimilarImagesCluster { images: cluster_images });
+    }
+
+    //the biggest clusters first
+    clusters.sort_by(|first, second| second.images.len().cmp(&first.images.len()).then(first.images[0].path.cmp(&second.images[0].path)));
+
+    return Ok(
+        SimilarImagesReport {
+            directories: scan_roots.into_iter().map(|(_, path)| path).collect(),
+            threshold,
+            images_count,
+            skipped_files,
+            clusters
+        }
+    )
+}
@@ -0,0 +1,493 @@
+/*
+==================================================================================
+| This module extracts XMP packets (JPEG APP1 segment, PNG iTXt chunk, TIFF tag  |
+| 700, WebP "XMP " chunk) and IPTC-IIM blocks (JPEG APP13 Photoshop resource     |
+| 0x0404, TIFF tag 33723) and parses them into namespace/property listings.      |
+| {Functions:}                                                                   |
+| 1) find_xmp_packet() - finds XMP packet in image                               |
+| 2) find_iptc_block() - finds IPTC-IIM block in image                           |
+| 3) find_photoshop_iptc() - finds IPTC resource in Photoshop resources block    |
+| 4) get_node_prefix() - gets XML node namespace prefix                          |
+| 5) is_rdf_node() - checks if XML node is RDF element                           |
+| 6) collect_xmp_property() - collects XMP property value (with struct fields)   |
+| 7) parse_xmp_packet() - parses XMP packet into properties                      |
+| 8) get_iptc_dataset_name() - gets IPTC dataset name                            |
+| 9) decode_iptc_value() - decodes IPTC dataset value into readable text         |
+| 10) parse_iptc_block() - parses IPTC-IIM block into datasets                   |
+| 11) get_image_xmp_metadata() - gets image XMP and IPTC metadata                |
+==================================================================================
+*/
+
+use image::ImageError;
+use roxmltree::{ Document, Node };
+
+use crate::image_analysis::image_manager::check_image_file;
+use crate::image_analysis::format_detector::ImageContainer;
+use crate::image_analysis::container_reader::*;
+use crate::image_analysis::image_reports::*;
+
+//JPEG APP1 XMP segment identifier
+const XMP_IDENTIFIER: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+
+//JPEG APP13 Photoshop resources segment identifier
+const PHOTOSHOP_IDENTIFIER: &[u8] = b"Photoshop 3.0\0";
+
+//PNG iTXt chunk keyword of XMP packet
+const PNG_XMP_KEYWORD: &[u8] = b"XML:com.adobe.xmp";
+
+//TIFF tags: XMP packet and IPTC-IIM block
+const TIFF_XMP_TAG: u16 = 0x02BC;
+const TIFF_IPTC_TAG: u16 = 0x83BB;
+
+//Photoshop IPTC-IIM resource ID
+const PHOTOSHOP_IPTC_RESOURCE: u16 = 0x0404;
+
+//IPTC-IIM dataset tag marker
+const IPTC_TAG_MARKER: u8 = 0x1C;
+
+//RDF namespace (XMP packet structure, not properties)
+const RDF_NAMESPACE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+//IPTC-IIM application record (record 2) datasets names: (dataset, name)
+const IPTC_APPLICATION_DATASETS: &[(u8, &str)] = &[
+    (0, "Record version"),
+    (5, "Object name"),
+    (7, "Edit status"),
+    (10, "Urgency"),
+    (12, "Subject reference"),
+    (15, "Category"),
+    (20, "Supplemental categories"),
+    (22, "Fixture identifier"),
+    (25, "Keywords"),
+    (26, "Content location code"),
+    (27, "Content location name"),
+    (30, "Release date"),
+    (35, "Release time"),
+    (37, "Expiration date"),
+    (38, "Expiration time"),
+    (40, "Special instructions"),
+    (45, "Reference service"),
+    (47, "Reference date"),
+    (50, "Reference number"),
+    (55, "Date created"),
+    (60, "Time created"),
+    (62, "Digital creation date"),
+    (63, "Digital creation time"),
+    (65, "Originating program"),
+    (70, "Program version"),
+    (75, "Object cycle"),
+    (80, "By-line"),
+    (85, "By-line title"),
+    (90, "City"),
+    (92, "Sub-location"),
+    (95, "Province/State"),
+    (100, "Country code"),
+    (101, "Country name"),
+    (103, "Original transmission reference"),
+    (105, "Headline"),
+    (110, "Credit"),
+    (115, "Source"),
+    (116, "Copyright notice"),
+    (118, "Contact"),
+    (120, "Caption/Abstract"),
+    (122, "Writer/Editor"),
+    (130, "Image type"),
+    (131, "Image orientation"),
+    (135, "Language identifier")
+];
+
+//finds XMP packet in image (None -> no XMP packet embedded)
+fn find_xmp_packet(
+    data: &[u8],
+    container: ImageContainer
+) -> Option<Vec<u8>> {
+    match container {
+        ImageContainer::Jpeg => {
+            return get_jpeg_segments(data)
+                .into_iter()
+                .find(|(marker, payload)| *marker == 0xE1 && data[payload.clone()].starts_with(XMP_IDENTIFIER))
+                .map(|(_, payload)| data[payload.start + XMP_IDENTIFIER.len()..payload.end].to_vec())
+        },
+        ImageContainer::Png => {
+            for (chunk_type, payload) in get_png_chunks(data) {
+                let chunk = &data[payload];
+
+                //iTXt: keyword, null, compression flag, compression method, language, null, translated keyword, null, text
+                if &chunk_type != b"iTXt" || !chunk.starts_with(PNG_XMP_KEYWORD) || chunk.get(PNG_XMP_KEYWORD.len()) != Some(&0) {
+                    continue
+                }
+
+                let compressed = *chunk.get(PNG_XMP_KEYWORD.len() + 1)? == 1;
+                let mut text_parts = chunk.get(PNG_XMP_KEYWORD.len() + 3..)?.splitn(3, |byte| *byte == 0);
+                let text = text_parts.nth(2)?;
+
+                if compressed {
+                    return inflate_zlib(text)
+                }
+
+                return Some(text.to_vec())
+            }
+
+            return None
+        },
+        ImageContainer::Tiff => get_tiff_ifd0_value(data, TIFF_XMP_TAG).map(|value| value.to_vec()),
+        ImageContainer::WebP => get_webp_chunk(data, b"XMP ").map(|payload| data[payload].to_vec()),
+        _ => None
+    }
+}
+
+//finds IPTC-IIM block in image (None -> no IPTC block embedded)
+fn find_iptc_block(
+    data: &[u8],
+    container: ImageContainer
+) -> Option<Vec<u8>> {
+    match container {
+        ImageContainer::Jpeg => {
+            //big Photoshop resources block can be split into several APP13 segments
+            let mut resources = Vec::new();
+
+            for (marker, payload) in get_jpeg_segments(data) {
+                if marker == 0xED && data[payload.clone()].starts_with(PHOTOSHOP_IDENTIFIER) {
+                    resources.extend_from_slice(&data[payload.start + PHOTOSHOP_IDENTIFIER.len()..payload.end]);
+                }
+            }
+
+            return find_photoshop_iptc(&resources)
+        },
+        ImageContainer::Tiff => get_tiff_ifd0_value(data, TIFF_IPTC_TAG).map(|value| value.to_vec()),
+        _ => None
+    }
+}
+
+//finds IPTC resource in Photoshop image resources block
+//resource: signature (8BIM), ID, Pascal string name (padded to even size), data size, data (padded to even size)
+fn find_photoshop_iptc(resources: &[u8]) -> Option<Vec<u8>> {
+    let mut position = 0;
+
+    while position + 8 <= resources.len() {
+        let resource_id = u16::from_be_bytes([resources[position + 4], resources[position + 5]]);
+        let name_length = resources[position + 6] as usize;
+        let size_position = position + 6 + (name_length + 2) / 2 * 2;
+
+        let size_bytes = resources.get(size_position..size_position + 4)?;
+        let size = u32::from_be_bytes([size_bytes[0], size_bytes[1], size_bytes[2], size_bytes[3]]) as usize;
+        let data_start = size_position + 4;
+        let data_end = data_start.checked_add(size)?.min(resources.len());
+
+        if resource_id == PHOTOSHOP_IPTC_RESOURCE {
+            return Some(resources[data_start..data_end].to_vec())
+        }
+
+        position = data_end + size % 2;
+    }
+
+    return None
+}
+
+//gets XML node namespace prefix (namespace URI if prefix isn't declared)
+fn get_node_prefix(
+    node: Node,
+    namespace_uri: &str
+) -> String {
+    return node.lookup_prefix(namespace_uri).unwrap_or(namespace_uri).to_string()
+}
+
+//checks if XML node is RDF element with name
+fn is_rdf_node(
+    node: Node,
+    name: &str
+) -> bool {
+    return node.is_element() && node.tag_name().namespace() == Some(RDF_NAMESPACE) && node.tag_name().name() == name
+}
+
+//collects XMP property value: simple text, URI (rdf:resource), array items (rdf:Bag, rdf:Seq, rdf:Alt)
+//or struct fields (rdf:parseType="Resource", rdf:Description or fields attributes), which are collected as "property/field"
+fn collect_xmp_property(
+    node: Node,
+    namespace: &str,
+    namespace_uri: &str,
+    property: String,
+    properties: &mut Vec<XmpProperty>
+) {
+    let mut push_property = |property: String, value: String| {
+        properties.push(
+            XmpProperty {
+                namespace: namespace.to_string(),
+                namespace_uri: namespace_uri.to_string(),
+                property,
+                value
+            }
+        );
+    };
+
+    if let Some(resource) = node.attribute((RDF_NAMESPACE, "resource")) {
+        push_property(property, resource.to_string());
+        return
+    }
+
+    //struct fields written as attributes (<exif:Flash exif:Fired="False"/>)
+    let field_attributes: Vec<_> = node
+        .attributes()
+        .filter(|attribute| attribute.namespace().is_some_and(|uri| uri != RDF_NAMESPACE && uri != "http://www.w3.org/XML/1998/namespace"))
+        .collect();
+
+    for attribute in &field_attributes {
+        push_property(format!("{}/{}", property, attribute.name()), attribute.value().to_string());
+    }
+
+    let children: Vec<Node> = node.children().filter(|child| child.is_element()).collect();
+
+    if children.is_empty() {
+        let value = node.text().unwrap_or("").trim();
+
+        if !value.is_empty() || field_attributes.is_empty() {
+            push_property(property, value.to_string());
+        }
+
+        return
+    }
+
+    for child in children {
+        if is_rdf_node(child, "Bag") || is_rdf_node(child, "Seq") || is_rdf_node(child, "Alt") {
+            let items: Vec<Node> = child.children().filter(|item| is_rdf_node(*item, "li")).collect();
+
+            //language alternatives: default language value is used
+            let items = if is_rdf_node(child, "Alt") {
+                let default_item = items
+                    .iter()
+                    .find(|item| item.attribute(("http://www.w3.org/XML/1998/namespace", "lang")) == Some("x-default"))
+                    .or(items.first());
+
+                default_item.copied().into_iter().collect()
+            } else {
+                items
+            };
+
+            let mut values = Vec::new();
+
+            for (index, item) in items.into_iter().enumerate() {
+                let is_struct = item.children().any(|field| field.is_element()) || item.attribute((RDF_NAMESPACE, "parseType")) == Some("Resource");
+
+                if is_struct {
+                    collect_xmp_property(item, namespace, namespace_uri, format!("{}[{}]", property, index + 1), properties);
+                } else {
+                    values.push(item.attribute((RDF_NAMESPACE, "resource")).or(item.text()).unwrap_or("").trim().to_string());
+                }
+            }
+
+            if !values.is_empty() {
+                properties.push(
+                    XmpProperty {
+                        namespace: namespace.to_string(),
+                        namespace_uri: namespace_uri.to_string(),
+                        property: property.clone(),
+                        value: values.join(", ")
+                    }
+                );
+            }
+        } else if is_rdf_node(child, "Description") {
+            collect_xmp_property(child, namespace, namespace_uri, property.clone(), properties);
+        } else if let Some(field_uri) = child.tag_name().namespace() {
+            //struct field from other namespace keeps its prefix (Iptc4xmpCore:CreatorContactInfo/Iptc4xmpCore:CiEmailWork)
+            let field_name = if field_uri == namespace_uri {
+                child.tag_name().name().to_string()
+            } else {
+                format!("{}:{}", get_node_prefix(child, field_uri), child.tag_name().name())
+            };
+
+            collect_xmp_property(child, namespace, namespace_uri, format!("{}/{}", property, field_name), properties);
+        }
+    }
+}
+
+//parses XMP packet into properties (rdf:Description attributes and elements)
+fn parse_xmp_packet(packet: &[u8]) -> Result<Vec<XmpProperty>, ImageManagerError> {
+    let text = String::from_utf8_lossy(packet);
+    let text = text.trim_matches(|character: char| character == '\0' || character.is_whitespace());
+
+    let document = Document::parse(text).map_err(|error| ImageManagerError::XmpParse { reason: error.to_string() })?;
+
+    let mut properties = Vec::new();
+
+    //top level descriptions (rdf:RDF children), nested descriptions are structs
+    let descriptions = document
+        .descendants()
+        .filter(|node| is_rdf_node(*node, "Description") && node.parent().is_some_and(|parent| is_rdf_node(parent, "RDF")));
+
+    for description in descriptions {
+        //simple properties written as attributes (<rdf:Description xmp:Rating="5">)
+        for attribute in description.attributes() {
+            let Some(namespace_uri) = attribute.namespace() else {
+                continue
+            };
+
+            if namespace_uri == RDF_NAMESPACE || namespace_uri == "http://www.w3.org/XML/1998/namespace" {
+                continue
+            }
+
+            properties.push(
+                XmpProperty {
+                    namespace: get_node_prefix(description, namespace_uri),
+                    namespace_uri: namespace_uri.to_string(),
+                    property: attribute.name().to_string(),
+                    value: attribute.value().to_string()
+                }
+            );
+        }
+
+        for property_node in description.children().filter(|node| node.is_element()) {
+            let Some(namespace_uri) = property_node.tag_name().namespace() else {
+                continue
+            };
+
+            collect_xmp_property(
+                property_node,
+                &get_node_prefix(property_node, namespace_uri),
+                namespace_uri,
+                property_node.tag_name().name().to_string(),
+                &mut properties
+            );
+        }
+    }
+
+    return Ok(properties)
+}
+
+//gets IPTC dataset name (application record datasets have names, others - record:dataset numbers)
+fn get_iptc_dataset_name(
+    record: u8,
+    dataset: u8
+) -> String {
+    if record == 1 && dataset == 90 {
+        return String::from("Coded character set")
+    }
+
+    if record == 2 {
+        if let Some((_, name)) = IPTC_APPLICATION_DATASETS.iter().find(|(number, _)| *number == dataset) {
+            return name.to_string()
+        }
+    }
+
+    return format!("Dataset {}:{}", record, dataset)
+}
+
+//decodes IPTC dataset value: text (UTF-8 or Latin-1), dates (YYYYMMDD -> YYYY-MM-DD), times (HHMMSS+HHMM -> HH:MM:SS+HH:MM)
+fn decode_iptc_value(
+    record: u8,
+    dataset: u8,
+    value: &[u8]
+) -> String {
+    //binary values: record version and coded character set
+    if dataset == 0 && value.len() == 2 {
+        return u16::from_be_bytes([value[0], value[1]]).to_string()
+    }
+
+    if record == 1 && dataset == 90 {
+        return match value {
+            b"\x1b%G" => String::from("UTF-8"),
+            _ => value.iter().map(|byte| format!("{:02X}", byte)).collect::<Vec<_>>().join(" ")
+        }
+    }
+
+    let text = match std::str::from_utf8(value) {
+        Ok(text) => text.to_string(),
+        Err(_) => value.iter().map(|byte| *byte as char).collect() //Latin-1
+    };
+
+    let text = text.trim_matches(|character: char| character == '\0' || character.is_whitespace()).to_string();
+    let is_digits = |text: &str| text.chars().all(|character| character.is_ascii_digit());
+
+    //dates datasets: release, expiration, reference, created, digital creation dates
+    if record == 2 && [30, 37, 47, 55, 62].contains(&dataset) && text.len() == 8 && is_digits(&text) {
+        return format!("{}-{}-{}", &text[0..4], &text[4..6], &text[6..8])
+    }
+
+    //times datasets: release, expiration, created, digital creation times (checked as ASCII, so byte slices don't split characters)
+    if record == 2 && [35, 38, 60, 63].contains(&dataset) && text.len() == 11 && text.is_ascii() && is_digits(&text[0..6]) && is_digits(&text[7..11]) {
+        return format!("{}:{}:{}{}{}:{}", &text[0..2], &text[2..4], &text[4..6], &text[6..7], &text[7..9], &text[9..11])
+    }
+
+    return text
+}
+
+//parses IPTC-IIM block into datasets, repeated datasets values are joined
+//dataset: tag marker (0x1C), record, dataset, value size (2 bytes or extended size), value
+fn parse_iptc_block(block: &[u8]) -> Vec<IptcEntry> {
+    let mut entries: Vec<IptcEntry> = Vec::new();
+    let mut position = 0;
+
+    while position + 5 <= block.len() && block[position] == IPTC_TAG_MARKER {
+        let record = block[position + 1];
+        let dataset = block[position + 2];
+        let mut size = u16::from_be_bytes([block[position + 3], block[position + 4]]) as usize;
+        let mut value_start = position + 5;
+
+        //extended dataset: size field contains size bytes count
+        if size & 0x8000 != 0 {
+            let size_length = size & 0x7FFF;
+
+            let Some(size_bytes) = block.get(value_start..value_start + size_length).filter(|_| size_length <= 4) else {
+                break
+            };
+
+            size = size_bytes.iter().fold(0, |size, byte| (size << 8) | *byte as usize);
+            value_start += size_length;
+        }
+
+        let value_end = value_start.saturating_add(size).min(block.len());
+        let value = decode_iptc_value(record, dataset, &block[value_start..value_end]);
+
+        match entries.iter_mut().find(|entry| entry.record == record && entry.dataset == dataset) {
+            Some(entry) => {
+                entry.value.push_str(", ");
+                entry.value.push_str(&value);
+            },
+            None => entries.push(
+                IptcEntry {
+                    record,
+                    dataset,
+                    name: get_iptc_dataset_name(record, dataset),
+                    value
+                }
+            )
+        }
+
+        position = value_end;
+    }
+
+    return entries
+}
+
+//gets image XMP properties and IPTC-IIM datasets (JPEG, PNG, TIFF and WebP images)
+pub fn get_image_xmp_metadata(path: String) -> Result<XmpReport, ImageManagerError> {
+    let (file_name, file_extension, container) = check_image_file(&path)?;
+
+    let container = match container {
+        Some(container @ (ImageContainer::Jpeg | ImageContainer::Png | ImageContainer::Tiff | ImageContainer::WebP)) => container,
+        _ => return Err(ImageManagerError::MetadataNotSupported { extension: file_extension })
+    };
+
+    let data = std::fs::read(path.trim()).map_err(|error| {
+        ImageManagerError::ImageOpen { path: path.to_string(), error: ImageError::IoError(error) }
+    })?;
+
+    let xmp_properties = match find_xmp_packet(&data, container) {
+        Some(packet) => parse_xmp_packet(&packet)?,
+        None => Vec::new()
+    };
+
+    let iptc_entries = match find_iptc_block(&data, container) {
+        Some(block) => parse_iptc_block(&block),
+        None => Vec::new()
+    };
+
+    return Ok(
+        XmpReport {
+            file_name,
+            file_extension,
+            container,
+            xmp_properties,
+            iptc_entries
+        }
+    )
+}
@@ -0,0 +1,50 @@
+/*
+================================================================
+|                   Image Info library                         |
+|--------------------------------------------------------------|
+| Image analysis API used by img_info app: image info, Exif    |
+| metadata extraction, directories scanning, dominant colors   |
+| palette extraction, channels histograms, exact and visually  |
+| similar duplicates search, images comparison, Exif metadata  |
+| stripping and Exif tags editing, XMP and IPTC metadata       |
+| extraction, embedded ICC color profiles inspection, animated |
+| images (GIF, APNG, WebP) inspection and frames extraction,   |
+| images formats conversion, directories batch conversion and  |
+| resizing, directories images contact sheets, Exif            |
+| orientation auto-rotation.                                   |
+| Library doesn't depend on app screens, ANSI styles, rand and |
+| indicatif (they are enabled by "cli" feature for app only),  |
+| use it with default-features = false.                        |
+|--------------------------------------------------------------|
+| by Maxdgf, GitHub -> https://github.com/Maxdgf               |
+================================================================
+*/
+
+#![allow(clippy::needless_return)]
+
+pub mod image_analysis;
+
+pub use image_analysis::image_manager::{
+    get_image_data, get_image_exif_metadata, get_images_size_with_extensions,
+    default_scan_roots, ScanOptions
+};
+pub use image_analysis::image_reports::*;
+pub use image_analysis::format_detector::{ detect_image_container, ImageContainer };
+pub use image_analysis::palette_extractor::get_image_palette;
+pub use image_analysis::histogram_builder::get_image_histogram;
+pub use image_analysis::duplicates_finder::find_duplicate_images;
+pub use image_analysis::similarity_finder::find_similar_images;
+pub use image_analysis::image_comparator::compare_images;
+pub use image_analysis::exif_location::get_gps_location;
+pub use image_analysis::exif_writer::{
+    strip_exif_metadata, set_exif_tags, ExifStripTarget, EditableExifTag
+};
+pub use image_analysis::xmp_reader::get_image_xmp_metadata;
+pub use image_analysis::icc_profile_reader::get_image_icc_profile;
+pub use image_analysis::animation_reader::{ extract_animation_frames, FrameSelection };
+pub use image_analysis::image_converter::{ convert_image, ConversionFormat, ConversionOptions };
+pub use image_analysis::batch_converter::{
+    batch_convert_images, BatchOptions, ResizeOptions, ResizeMode, ResizeFilter
+};
+pub use image_analysis::contact_sheet_builder::{ build_contact_sheet, ContactSheetOptions };
+pub use image_analysis::orientation_manager::{ auto_rotate_image, get_orientation_name };
@@ -9,14 +9,25 @@
 ================================================================ 
 */
 
+#![allow(clippy::needless_return)]
+
 mod modules;
 
+use std::env;
+use std::process;
 use std::io::stdin;
 
 use modules::app_design_managment::screens_manager::*;
-use modules::main_mechanics::command_parser::parse_command;
+use modules::main_mechanics::command_parser::{ parse_command, parse_command_line_arguments };
 
 fn main() {
+    let arguments: Vec<String> = env::args().skip(1).collect(); //command-line arguments without app path
+
+    //command-line mode (img_info gii photo.jpg): runs one command without screens and exits with exit code
+    if !arguments.is_empty() {
+        process::exit(parse_command_line_arguments(&arguments));
+    }
+
     draw_main_screen(); //draws main screen
 
     //main cycle
@@ -7,6 +7,10 @@
 //reset all styles code
 pub const RESET: u8 = 0;
 
+//24-bit (truecolor) color codes prefixes, color code is prefix;red;green;blue
+pub const FONT_RGB_COLOR: &str = "38;2";
+pub const BACKGROUND_RGB_COLOR: &str = "48;2";
+
 //ansi text font color codes struct
 pub struct AnsiFontColors {
     pub f_red: i32,
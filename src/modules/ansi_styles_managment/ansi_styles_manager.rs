@@ -9,10 +9,14 @@
 | 4) font_and_style() - setts text color and style              |
 | 5) full_style() - setts text color, background and style      |
 | 6) reset_all() - resets text color, background and style (all)|
+| 7) background_rgb_color() - setts text 24-bit background color|
+| 8) font_rgb_color() - setts text 24-bit color                 |
+| 9) font_and_background_rgb_color() - setts text 24-bit color  |
+| and 24-bit background color                                   |
 =================================================================
 */
 
-use super::ansi_escape_codes::RESET;
+use super::ansi_escape_codes::{ RESET, FONT_RGB_COLOR, BACKGROUND_RGB_COLOR };
 
 //parts of ansi escape code
 //ESCAPE - start | m - end
@@ -123,6 +127,71 @@ pub fn full_style(
     return result
 }
 
+//creating ansi escape code for text 24-bit (truecolor) background color
+pub fn background_rgb_color(
+    rgb: [u8; 3],
+    content: &str
+) -> String {
+    let result = format!(
+        "{}{};{};{};{}{}{}{}",
+        ESCAPE,
+        BACKGROUND_RGB_COLOR,
+        rgb[0],
+        rgb[1],
+        rgb[2],
+        END,
+        content,
+        &reset_all()
+    );
+
+    return result
+}
+
+//creating ansi escape code for text 24-bit (truecolor) font color
+pub fn font_rgb_color(
+    rgb: [u8; 3],
+    content: &str
+) -> String {
+    let result = format!(
+        "{}{};{};{};{}{}{}{}",
+        ESCAPE,
+        FONT_RGB_COLOR,
+        rgb[0],
+        rgb[1],
+        rgb[2],
+        END,
+        content,
+        &reset_all()
+    );
+
+    return result
+}
+
+//creating ansi escape code for text 24-bit (truecolor) font color and background color
+pub fn font_and_background_rgb_color(
+    font_rgb: [u8; 3],
+    background_rgb: [u8; 3],
+    content: &str
+) -> String {
+    let result = format!(
+        "{}{};{};{};{};{};{};{};{}{}{}{}",
+        ESCAPE,
+        FONT_RGB_COLOR,
+        font_rgb[0],
+        font_rgb[1],
+        font_rgb[2],
+        BACKGROUND_RGB_COLOR,
+        background_rgb[0],
+        background_rgb[1],
+        background_rgb[2],
+        END,
+        content,
+        &reset_all()
+    );
+
+    return result
+}
+
 //resets all
 fn reset_all() -> String {
     let result = format!(
@@ -11,6 +11,8 @@
 | 6) app_author_panel() - returns app author decorated panel               |
 | 7) help_panel() - returns help panel with info about commands            |
 | 8) app_commands_list_panel() - returns decorated panel with commands     |
+| 9) command_line_usage() - returns usage text for command-line mode       |
+| 10) build_panel() - joins panel rows into panel with borders             |
 ============================================================================
 */
 
@@ -128,159 +130,256 @@ pub fn app_author_panel() -> String {
     return app_author
 }
 
-pub fn help_panel() -> String {
-    let help_panel = format!(
-"=============================================================================================================================
-|                                                           [{}]                                                          |
-|---------------------------------------------------------------------------------------------------------------------------|
-| {} | {}. Get info about image in form of dimensions, color model, filename and extension, file size.  |
-| {} | {}. Fetch Exif metadata from image. Exif-supporting formats: [{}].   |
-| {}  | {}. Get all images size with specific extension.                                                    |
-| {}       | {}. Closing application.                                                                               |
-=============================================================================================================================",
-
-        font_and_style(
-            FONT_STYLES.italic, 
-            FONT_COLORS.f_light_purple, 
-            "Help"
-        ),
-        
-        font_and_style(
-            FONT_STYLES.underline, 
-            FONT_COLORS.f_light_white, 
-            "gii=(path)"
-        ),
-
-        font_and_style(
-            FONT_STYLES.bold, 
-            FONT_COLORS.f_light_white, 
-            "Get Image Info", 
-        ),
-
-        font_and_style(
-            FONT_STYLES.underline, 
-            FONT_COLORS.f_light_white, 
-            "fem=(path)", 
-        ),
-
-        font_and_style(
-            FONT_STYLES.bold, 
-            FONT_COLORS.f_light_white, 
-            "Fetch Exif Metadata"
-        ),
-
-        font_and_style(
-            FONT_STYLES.italic, 
-            FONT_COLORS.f_light_yellow, 
-            ".JPEG, .TIFF, .PNG, .WEBP"
-        ),
+//help panel commands: (command, title, description)
+const HELP_PANEL_COMMANDS: &[(&str, &str, &str)] = &[
+    ("gii=(path; icc; frames=1,3-5)", "Get Image Info", "Get image dimensions, color model, file size, ICC profile, animation frames (icc - save .icc file, frames=all|N,N-M - save frames png)."),
+    ("fem=(path; format)", "Fetch Exif Metadata", "Fetch Exif metadata from image [.JPEG, .TIFF, .HEIF, .WEBP]. Export: txt, json, csv, xmp, dir=DIR, name=TEMPLATE."),
+    ("is=(types; dirs)", "Images Size", "Get images size by extensions (jpg,png or *). Options: dirs, depth=N, symlinks, hidden."),
+    ("palette=(path; count)", "Image Palette", "Get image dominant colors (count - colors count 1-64, 8 by default)."),
+    ("hist=(path; csv)", "Image Histogram", "Get red, green, blue and luminance histograms (csv - save bins to csv file)."),
+    ("dups=(dirs)", "Duplicate Images", "Find byte-identical images in directories. Options: depth=N, symlinks, hidden."),
+    ("similar=(dirs; threshold=N)", "Similar Images", "Find resized, recompressed or cropped copies (threshold=1-64, 10 by default)."),
+    ("cmp=(a; b)", "Compare Images", "Get MSE, PSNR, SSIM of two images and save difference heatmap to png file."),
+    ("strip=(path; gps; serial)", "Strip Exif Metadata", "Save JPEG, WebP, TIFF copy without all or selected (gps, serial) Exif entries."),
+    ("exif-set=(path; Tag=Value; copy)", "Set Exif Tags", "Set Artist, Copyright, DateTimeOriginal, ImageDescription, Orientation (copy - save to copy)."),
+    ("xmp=(path)", "Fetch XMP Metadata", "Fetch XMP properties and IPTC datasets from image. Supporting formats: [.JPEG, .PNG, .TIFF, .WEBP]."),
+    ("conv=(path; format; options)", "Convert Image", "Convert to png, jpeg, webp, bmp, tiff, gif, ico, avif. Options: quality=1-100 (jpeg, avif), exif=keep|strip."),
+    ("batch=(in; out; options)", "Batch Convert Images", "Convert, resize dir images to mirrored out dir: format=, resize=WxH, mode=fit|fill|exact, filter=, update."),
+    ("sheet=(dir; columns; thumb_size)", "Contact Sheet", "Save png with labelled thumbnails of all dir images (columns 1-32, 6 by default, size 32-1024, 160 by default)."),
+    ("autorotate=(path)", "Auto-rotate Image", "Save JPEG, PNG, WebP copy with Exif orientation applied to pixels and reset to 1 (normal)."),
+    ("exit", "Exit app", "Closing application.")
+];
+
+//main screen commands list: (command, description)
+const APP_COMMANDS_LIST: &[(&str, &str)] = &[
+    ("gii=(path)", "Get info about image from path."),
+    ("fem=(path)", "Fetch Exif image matadata from path"),
+    ("is=(types)", "Get all images size with specific extensions."),
+    ("palette=(path)", "Get image dominant colors palette."),
+    ("hist=(path)", "Get image channels histograms."),
+    ("dups=(dir)", "Find duplicate images in directory."),
+    ("similar=(dir; threshold=N)", "Find visually similar images in directory."),
+    ("cmp=(a; b)", "Compare two images."),
+    ("strip=(path)", "Save image copy without Exif metadata."),
+    ("exif-set=(path; Tag=Value)", "Set image Exif tags."),
+    ("xmp=(path)", "Fetch XMP and IPTC image metadata."),
+    ("conv=(path; format)", "Convert image to other format."),
+    ("batch=(in; out; format)", "Convert and resize directory images."),
+    ("sheet=(dir)", "Build directory images contact sheet."),
+    ("autorotate=(path)", "Apply image Exif orientation."),
+    ("help", "Show help."),
+    ("exit", "Exit app.")
+];
+
+//panel row: text visible length (without ANSI codes) and styled text
+struct PanelRow {
+    length: usize,
+    text: String
+}
 
-        font_and_style(
-            FONT_STYLES.underline, 
-            FONT_COLORS.f_light_white, 
-            "is=(type)", 
-        ),
+//joins panel rows into panel with borders, every row is padded to panel width
+fn build_panel(
+    title: Option<String>,
+    title_length: usize,
+    rows: Vec<PanelRow>
+) -> String {
+    let content_width = rows.iter().map(|row| row.length).max().unwrap_or(0).max(title_length);
+    let border = "=".repeat(content_width + 4);
+
+    let mut panel = format!("{}\n", border);
+
+    if let Some(title_text) = title {
+        let left_padding = (content_width - title_length) / 2;
+
+        panel.push_str(
+            &format!(
+                "| {}{}{} |\n|{}|\n",
+                " ".repeat(left_padding),
+                title_text,
+                " ".repeat(content_width - title_length - left_padding),
+                "-".repeat(content_width + 2)
+            )
+        );
+    }
+
+    for row in rows {
+        panel.push_str(
+            &format!(
+                "| {}{} |\n",
+                row.text,
+                " ".repeat(content_width - row.length)
+            )
+        );
+    }
+
+    panel.push_str(&border);
+
+    return panel
+}
 
-        font_and_style(
-            FONT_STYLES.bold, 
-            FONT_COLORS.f_light_white, 
-            "Images Size"
-        ),
+pub fn help_panel() -> String {
+    let command_width = HELP_PANEL_COMMANDS.iter().map(|(command, _, _)| command.chars().count()).max().unwrap_or(0);
+    let mut rows = Vec::new();
+
+    for (command, title, description) in HELP_PANEL_COMMANDS {
+        let (command_color, title_color) = if *command == "exit" {
+            (FONT_COLORS.f_light_purple, FONT_COLORS.f_light_red)
+        } else {
+            (FONT_COLORS.f_light_white, FONT_COLORS.f_light_white)
+        };
+
+        rows.push(
+            PanelRow {
+                length: command_width + title.chars().count() + description.chars().count() + 5,
+                text: format!(
+                    "{} | {}. {}",
+                    font_and_style(
+                        FONT_STYLES.underline, 
+                        command_color, 
+                        &format!("{:<width$}", command, width = command_width)
+                    ),
+                    font_and_style(
+                        FONT_STYLES.bold, 
+                        title_color, 
+                        title
+                    ),
+                    description
+                )
+            }
+        );
+    }
+
+    //JSON output note
+    let json_note = "Add :json after command name (gii:json=(path)) to get result as JSON document.";
+
+    rows.push(
+        PanelRow {
+            length: json_note.chars().count(),
+            text: font_and_style(
+                FONT_STYLES.italic, 
+                FONT_COLORS.f_light_yellow, 
+                json_note
+            )
+        }
+    );
 
-        font_and_style(
-            FONT_STYLES.underline, 
-            FONT_COLORS.f_light_purple, 
-            "exit"
+    let help_panel = build_panel(
+        Some(
+            format!(
+                "[{}]",
+                font_and_style(
+                    FONT_STYLES.italic, 
+                    FONT_COLORS.f_light_purple, 
+                    "Help"
+                )
+            )
         ),
-
-        font_and_style(
-            FONT_STYLES.bold, 
-            FONT_COLORS.f_light_red, 
-            "Exit app"
-        )
+        "[Help]".len(),
+        rows
     );
 
-    return  help_panel
+    return help_panel
 }
 
 pub fn app_commands_list_panel() -> String {
-    let commands_list_panel = format!(
-"====================================================================
-| {} | {} | {}               |
-| {} | {} | {}           |
-| {} | {} | {}  |
-| {} | {} | {}                                    |
-| {} | {} | {}                                     |
-====================================================================", 
-    font_color(
-        FONT_COLORS.f_yellow, 
-        "[1]"
-    ),
-    font_style(
-        FONT_STYLES.reverse, 
-        "gii=(path)"
-    ),
-    font_and_style(
-        FONT_STYLES.underline, 
-        FONT_COLORS.f_light_green, 
-        "Get info about image from path."
-    ),
-
-    font_color(
-        FONT_COLORS.f_yellow, 
-        "[2]"
-    ),
-    font_style(
-        FONT_STYLES.reverse, 
-        "fem=(path)"
-    ),
-    font_and_style(
-        FONT_STYLES.underline, 
-        FONT_COLORS.f_light_green, 
-        "Fetch Exif image matadata from path"
-    ),
-
-    font_color(
-        FONT_COLORS.f_yellow, 
-        "[3]"
-    ),
-    font_style(
-        FONT_STYLES.reverse, 
-        "is=(type) "
-    ),
-    font_and_style(
-        FONT_STYLES.underline, 
-        FONT_COLORS.f_light_green, 
-        "Get all images size with specific extension."
-    ),
-
-    font_color(
-        FONT_COLORS.f_yellow, 
-        "[4]"
-    ),
-    font_style(
-        FONT_STYLES.reverse, 
-        "help      "
-    ),
-    font_and_style(
-        FONT_STYLES.underline, 
-        FONT_COLORS.f_light_purple, 
-        "Show help."
-    ),
+    let command_width = APP_COMMANDS_LIST.iter().map(|(command, _)| command.chars().count()).max().unwrap_or(0);
+    let mut rows = Vec::new();
+
+    for (index, (command, description)) in APP_COMMANDS_LIST.iter().enumerate() {
+        let number = format!("[{}]", index + 1);
+
+        let description_color = match *command {
+            "help" => FONT_COLORS.f_light_purple,
+            "exit" => FONT_COLORS.f_light_red,
+            _ => FONT_COLORS.f_light_green
+        };
+
+        rows.push(
+            PanelRow {
+                length: number.len() + command_width + description.chars().count() + 6,
+                text: format!(
+                    "{} | {} | {}",
+                    font_color(
+                        FONT_COLORS.f_yellow, 
+                        &number
+                    ),
+                    font_style(
+                        FONT_STYLES.reverse, 
+                        &format!("{:<width$}", command, width = command_width)
+                    ),
+                    font_and_style(
+                        FONT_STYLES.underline, 
+                        description_color, 
+                        description
+                    )
+                )
+            }
+        );
+    }
+
+    return build_panel(None, 0, rows)
+}
 
-    font_color(
-        FONT_COLORS.f_yellow, 
-        "[5]"
-    ),
-    font_style(
-        FONT_STYLES.reverse, 
-        "exit      "
-    ),
-    font_and_style(
-        FONT_STYLES.underline, 
-        FONT_COLORS.f_light_red, 
-        "Exit app."
-    ));
+pub fn command_line_usage() -> String {
+    let usage = format!(
+"{}
+    img_info                      start interactive mode
+    img_info gii <path> [icc] [frames=all|1,3-5]
+                                  get info about image (icc - save embedded ICC color profile
+                                  to .icc file, frames - save animation frames to png files)
+    img_info fem <path> [txt|json|csv|xmp] [dir=DIR] [name=TEMPLATE]
+                                  fetch Exif image metadata and export it to file (dir - export
+                                  directory, name - filename template: {{name}}, {{ext}}, {{format}})
+    img_info is <types> [dirs] [depth=N] [symlinks] [hidden]
+                                  get all images size with specific extensions (jpg,png or
+                                  * - all image formats) in default or
+                                  entered directories (depth - max depth, symlinks - follow
+                                  symbolic links, hidden - scan hidden files)
+    img_info palette <path> [count]
+                                  get image dominant colors palette (1-64 colors, 8 by default)
+    img_info hist <path> [csv]    get image channels histograms (csv - save bins to csv file)
+    img_info dups <dirs> [depth=N] [symlinks] [hidden]
+                                  find byte-identical images in directories
+    img_info similar <dirs> [threshold=N] [depth=N] [symlinks] [hidden]
+                                  find visually similar images (perceptual hashes distance
+                                  below threshold 1-64, 10 by default)
+    img_info cmp <a> <b>          compare two images (MSE, PSNR, SSIM) and save difference
+                                  heatmap to png file
+    img_info strip <path> [gps] [serial]
+                                  save image copy without Exif metadata (all or only GPS
+                                  location and serial numbers)
+    img_info exif-set <path> <Tag=Value>... [copy]
+                                  set Exif tags (Artist, Copyright, DateTimeOriginal,
+                                  ImageDescription, Orientation) in image file or in its copy
+    img_info xmp <path>           fetch XMP and IPTC image metadata
+    img_info conv <path> <format> [quality=N] [exif=keep|strip]
+                                  convert image to png, jpeg, webp, bmp, tiff, gif, ico or avif
+                                  (quality - jpeg and avif quality 1-100, exif - keep or strip
+                                  Exif metadata, strip by default)
+    img_info batch <input dir> <output dir> [format=FORMAT] [resize=WxH] [mode=fit|fill|exact]
+                   [filter=nearest|triangle|catmullrom|gaussian|lanczos] [quality=N]
+                   [types=jpg,png] [update] [depth=N] [symlinks] [hidden]
+                                  convert and/or resize all images in input directory tree and
+                                  save them to output directory with same relative paths
+                                  (update - skip images, which output is newer than source)
+    img_info sheet <dir> [columns] [thumb_size] [depth=N] [symlinks] [hidden]
+                                  save contact sheet png with thumbnails of all directory images
+                                  labelled with filename, dimensions and file size (columns 1-32,
+                                  6 by default, thumbnails size 32-1024 pixels, 160 by default)
+    img_info autorotate <path>    save JPEG, PNG or WebP image copy with Exif orientation applied
+                                  to pixels (Orientation tag is reset to 1)
+    img_info help                 show help
+
+Options:
+    --format <text|json>          output format (default: text)
+
+Exit codes: 0 - success, 1 - command failed, 2 - invalid arguments",
+
+        font_style(
+            FONT_STYLES.bold, 
+            "Usage:"
+        )
+    );
 
-    return commands_list_panel
+    return usage
 }
\ No newline at end of file
@@ -0,0 +1,236 @@
+/*
+===========================================================================
+| This module renders image thumbnail preview in terminal.                |
+| Preview protocols: half-block characters with 24-bit colors (works in   |
+| every truecolor terminal), kitty graphics protocol and sixel graphics,  |
+| when terminal advertises their support (TERM, TERM_PROGRAM,             |
+| KITTY_WINDOW_ID environment variables).                                 |
+| IMG_INFO_PREVIEW environment variable overrides protocol:               |
+| [blocks, kitty, sixel, none]. used libs: [base64-kitty image data]      |
+| {Functions:}                                                            |
+| 1) detect_preview_protocol() - detects terminal preview protocol        |
+| 2) preview_thumbnail_size() - returns thumbnail size for protocol       |
+| 3) render_image_preview() - renders thumbnail with preview protocol     |
+| 4) render_half_blocks_image() - renders thumbnail with half blocks      |
+| 5) render_kitty_image() - renders thumbnail with kitty graphics protocol|
+| 6) render_sixel_image() - renders thumbnail with sixel graphics         |
+| 7) get_sixel_color_index() - gets pixel color index in sixel palette    |
+===========================================================================
+*/
+
+use std::env;
+use std::io::{ stdout, IsTerminal };
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use image::{ Rgba, RgbaImage };
+
+use crate::modules::ansi_styles_managment::ansi_styles_manager::*;
+
+//pixels with alpha less than this value aren't drawn (terminal background is visible)
+const MIN_VISIBLE_ALPHA: u8 = 128;
+
+//kitty graphics protocol max base64 data chunk size
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+//sixel palette is 6x6x6 colors cube (216 colors)
+const SIXEL_CUBE_LEVELS: u32 = 6;
+
+//image preview protocol
+#[derive(Clone, Copy, PartialEq)]
+pub enum PreviewProtocol {
+    HalfBlocks, //"▀" characters with 24-bit font (top pixel) and background (bottom pixel) colors
+    Kitty, //kitty terminal graphics protocol
+    Sixel, //sixel graphics (xterm, mlterm, foot, WezTerm)
+    Disabled //no preview
+}
+
+//detects preview protocol (preview is drawn only when stdout is terminal, redirected output gets no ANSI image art)
+pub fn detect_preview_protocol() -> PreviewProtocol {
+    let variable = |name: &str| env::var(name).unwrap_or_default();
+
+    match variable("IMG_INFO_PREVIEW").as_str() {
+        "none" => return PreviewProtocol::Disabled,
+        "blocks" => return PreviewProtocol::HalfBlocks,
+        "kitty" => return PreviewProtocol::Kitty,
+        "sixel" => return PreviewProtocol::Sixel,
+        _ => {}
+    }
+
+    if !stdout().is_terminal() {
+        return PreviewProtocol::Disabled
+    }
+
+    let term = variable("TERM");
+    let term_program = variable("TERM_PROGRAM");
+
+    if env::var_os("KITTY_WINDOW_ID").is_some() || term.contains("kitty") || term.contains("ghostty") {
+        return PreviewProtocol::Kitty
+    }
+
+    if term.contains("sixel") || term.starts_with("mlterm") || term.starts_with("foot") || term_program == "WezTerm" {
+        return PreviewProtocol::Sixel
+    }
+
+    return PreviewProtocol::HalfBlocks
+}
+
+//returns thumbnail max size in pixels for preview protocol (half blocks: one pixel -> half of character)
+pub fn preview_thumbnail_size(protocol: PreviewProtocol) -> u32 {
+    match protocol {
+        PreviewProtocol::HalfBlocks => 40,
+        PreviewProtocol::Kitty | PreviewProtocol::Sixel => 240,
+        PreviewProtocol::Disabled => 0
+    }
+}
+
+//renders thumbnail with preview protocol (None -> preview disabled)
+pub fn render_image_preview(
+    thumbnail: &RgbaImage,
+    protocol: PreviewProtocol
+) -> Option<String> {
+    match protocol {
+        PreviewProtocol::HalfBlocks => Some(render_half_blocks_image(thumbnail)),
+        PreviewProtocol::Kitty => Some(render_kitty_image(thumbnail)),
+        PreviewProtocol::Sixel => Some(render_sixel_image(thumbnail)),
+        PreviewProtocol::Disabled => None
+    }
+}
+
+//renders thumbnail with half block characters, one character -> two pixels (top and bottom)
+fn render_half_blocks_image(thumbnail: &RgbaImage) -> String {
+    let mut result = String::new();
+    let is_visible = |pixel: &Rgba<u8>| pixel.0[3] >= MIN_VISIBLE_ALPHA;
+
+    for y in (0..thumbnail.height()).step_by(2) {
+        result.push_str("|  ");
+
+        for x in 0..thumbnail.width() {
+            let top_pixel = thumbnail.get_pixel(x, y);
+            let bottom_pixel = if y + 1 < thumbnail.height() {
+                Some(thumbnail.get_pixel(x, y + 1)).filter(|pixel| is_visible(pixel))
+            } else {
+                None
+            };
+
+            let top_rgb = [top_pixel.0[0], top_pixel.0[1], top_pixel.0[2]];
+
+            let cell = match (is_visible(top_pixel), bottom_pixel) {
+                (true, Some(bottom)) => font_and_background_rgb_color(top_rgb, [bottom.0[0], bottom.0[1], bottom.0[2]], "▀"),
+                (true, None) => font_rgb_color(top_rgb, "▀"),
+                (false, Some(bottom)) => font_rgb_color([bottom.0[0], bottom.0[1], bottom.0[2]], "▄"),
+                (false, None) => " ".to_string()
+            };
+
+            result.push_str(&cell);
+        }
+
+        result.push('\n');
+    }
+
+    return result
+}
+
+//renders thumbnail with kitty graphics protocol (raw RGBA data in base64 chunks)
+fn render_kitty_image(thumbnail: &RgbaImage) -> String {
+    let encoded_data = STANDARD.encode(thumbnail.as_raw());
+    let chunks: Vec<&[u8]> = encoded_data.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+
+    let mut result = String::new();
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        let has_more_chunks = if index + 1 < chunks.len() { 1 } else { 0 };
+
+        //first chunk contains image parameters: a=T (transmit and display), f=32 (RGBA), s/v (width/height)
+        let control_data = if index == 0 {
+            format!("a=T,f=32,s={},v={},m={}", thumbnail.width(), thumbnail.height(), has_more_chunks)
+        } else {
+            format!("m={}", has_more_chunks)
+        };
+
+        result.push_str(
+            &format!(
+                "\x1b_G{};{}\x1b\\",
+                control_data,
+                String::from_utf8_lossy(chunk)
+            )
+        );
+    }
+
+    result.push('\n');
+
+    return result
+}
+
+//renders thumbnail with sixel graphics (colors are reduced to 6x6x6 colors cube)
+fn render_sixel_image(thumbnail: &RgbaImage) -> String {
+    let colors_count = (SIXEL_CUBE_LEVELS * SIXEL_CUBE_LEVELS * SIXEL_CUBE_LEVELS) as usize;
+
+    //P2=1 -> not drawn pixels keep terminal background, raster attributes: 1:1 aspect ratio, width, height
+    let mut result = format!("\x1bP0;1;0q\"1;1;{};{}", thumbnail.width(), thumbnail.height());
+
+    //palette colors in percents (0-100)
+    for index in 0..colors_count as u32 {
+        let levels = [index / 36, index / 6 % 6, index % 6].map(|level| level * 100 / (SIXEL_CUBE_LEVELS - 1));
+
+        result.push_str(&format!("#{};2;{};{};{}", index, levels[0], levels[1], levels[2]));
+    }
+
+    //one sixel band -> 6 pixels rows
+    for band_y in (0..thumbnail.height()).step_by(6) {
+        let band_height = (thumbnail.height() - band_y).min(6);
+        let mut band_colors = vec![vec![0u8; thumbnail.width() as usize]; colors_count]; //sixel bits for every color and column
+        let mut used_colors = vec![false; colors_count];
+
+        for x in 0..thumbnail.width() {
+            for row in 0..band_height {
+                if let Some(color_index) = get_sixel_color_index(thumbnail.get_pixel(x, band_y + row)) {
+                    band_colors[color_index][x as usize] |= 1 << row;
+                    used_colors[color_index] = true;
+                }
+            }
+        }
+
+        for color_index in (0..colors_count).filter(|&index| used_colors[index]) {
+            result.push_str(&format!("#{}", color_index));
+
+            //run-length encoding of sixel characters (!count char)
+            let columns = &band_colors[color_index];
+            let mut x = 0;
+
+            while x < columns.len() {
+                let run_length = columns[x..].iter().take_while(|&&bits| bits == columns[x]).count();
+                let sixel_char = (63 + columns[x]) as char;
+
+                if run_length > 3 {
+                    result.push_str(&format!("!{}{}", run_length, sixel_char));
+                } else {
+                    result.push_str(&sixel_char.to_string().repeat(run_length));
+                }
+
+                x += run_length;
+            }
+
+            result.push('$'); //carriage return to band start
+        }
+
+        result.push('-'); //next band
+    }
+
+    result.push_str("\x1b\\\n");
+
+    return result
+}
+
+//gets pixel color index in sixel 6x6x6 colors cube palette (None -> transparent pixel)
+fn get_sixel_color_index(pixel: &Rgba<u8>) -> Option<usize> {
+    if pixel.0[3] < MIN_VISIBLE_ALPHA {
+        return None
+    }
+
+    let [red, green, blue] = [pixel.0[0], pixel.0[1], pixel.0[2]].map(|value| {
+        (value as u32 * (SIXEL_CUBE_LEVELS - 1) + 127) / 255
+    });
+
+    return Some((red * 36 + green * 6 + blue) as usize)
+}
@@ -1,4 +1,5 @@
 //app_design_managment app directory public modules
 pub mod decoration_patterns;
 pub mod screens_manager;
-pub mod progress_bar;
\ No newline at end of file
+pub mod progress_bar;
+pub mod image_preview;
\ No newline at end of file
@@ -5,11 +5,13 @@
 | {Functions:}                                                        |
 | 1) draw_progress_bar_title() - draws progress bar decorated title   |
 | 2) create_loading_spinner() - creating indicatif progress spinner   |
+| 3) create_progress_bar() - creating indicatif determinate progress  |
+| bar (for tasks with known steps count)                              |
 =======================================================================
 */
 
 use rand::Rng;
-use indicatif::ProgressBar;
+use indicatif::{ ProgressBar, ProgressStyle };
 
 use crate::modules::ansi_styles_managment::ansi_escape_codes::{
     BACKGROUND_COLORS_LIST, FONT_COLORS_LIST, FONT_STYLES_LIST
@@ -27,24 +29,21 @@ pub fn draw_progress_bar_title(task_name: String) {
     let fsl_index = rnd.random_range(0..FONT_STYLES_LIST.len());
 
     println!(
-        "{}", 
-        format!(
-            "|[{}]-[{}]-({})",
-
-            background_color(
-                BACKGROUND_COLORS_LIST[bcl_index], 
-                "     "
-            ),
-
-            font_color(
-                FONT_COLORS_LIST[fcs_index], 
-                "Please, wait."
-            ),
-
-            font_style(
-                FONT_STYLES_LIST[fsl_index], 
-                &task_name
-            )
+        "|[{}]-[{}]-({})",
+
+        background_color(
+            BACKGROUND_COLORS_LIST[bcl_index], 
+            "     "
+        ),
+
+        font_color(
+            FONT_COLORS_LIST[fcs_index], 
+            "Please, wait."
+        ),
+
+        font_style(
+            FONT_STYLES_LIST[fsl_index], 
+            &task_name
         )
     );  
 }
@@ -55,4 +54,21 @@ pub fn create_loading_spinner(msg: String) -> ProgressBar {
     spinner.set_message(msg);
 
     return spinner
-}
\ No newline at end of file
+}
+
+//creating indicatif determinate progress bar (steps count can be changed later with set_length())
+pub fn create_progress_bar(
+    length: u64,
+    msg: String
+) -> ProgressBar {
+    let progress_bar = ProgressBar::new(length);
+
+    progress_bar.set_style(
+        ProgressStyle::with_template("|[{bar:40.cyan/blue}] {pos}/{len} ({percent}%) ~{eta}\n{msg}")
+            .unwrap()
+            .progress_chars("=>-")
+    );
+    progress_bar.set_message(msg);
+
+    return progress_bar
+}
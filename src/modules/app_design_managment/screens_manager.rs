@@ -13,7 +13,72 @@
 | 9) draw_all_images_sizes_and_info() - draws all images with specific |
 | extension sizes and info                                             |
 | 10) draw_command_error_message() - draws input command error message |
-| 11) reset_buffer() - resets buffer for print!()                      |
+| 11) draw_command_result() - draws command result screen or prints    |
+| command result in command-line mode                                  |
+| 12) wait_any_key() - waits any key, then draws main screen           |
+| 13) reset_buffer() - resets buffer for print!()                      |
+| 14) render_image_info() - renders image info result (with preview)   |
+| 14.1) render_channel_statistics() - renders color channel statistics |
+| 15) render_exif_report() - renders image Exif metadata result        |
+| 16) render_extension_scan_report() - renders images scan result      |
+| (formats x directories matrix)                                       |
+| 16.1) render_scan_matrix_cell() - renders images count and size cell |
+| 17) render_image_manager_error() - renders image_manager error       |
+| 18) render_json_document() - renders command result as JSON document |
+| 19) get_image_exif_metadata_and_save() - gets Exif metadata and saves|
+| it to output txt, json, csv or xmp file (optional)                   |
+| 20) set_scan_progress_message() - sets scan progress spinner message |
+| 21) draw_image_palette_screen() - draws image palette result screen  |
+| 22) render_palette_report() - renders image palette result           |
+| 23) run_task() - runs command task with loading spinner              |
+| 24) draw_report_result() - renders command report and draws it      |
+| 25) draw_image_histogram_screen() - draws image histograms result    |
+| 26) get_image_histogram_and_save() - gets image histograms and saves |
+| bins to output csv file (optional)                                   |
+| 27) render_histogram_report() - renders image histograms result      |
+| 27.1) render_histogram_chart() - renders one histogram bar chart     |
+| 28) get_image_data_and_preview() - gets image data, renders image   |
+| thumbnail preview, saves ICC profile to .icc file and animation      |
+| frames to png files (optional)                                       |
+| 29) draw_duplicate_images_screen() - draws duplicate images result   |
+| 30) run_scan_task() - runs directories scan task with scan progress  |
+| spinner                                                              |
+| 31) render_duplicates_report() - renders duplicate images result     |
+| 32) draw_similar_images_screen() - draws similar images result       |
+| 33) render_similar_images_report() - renders similar images clusters |
+| 34) draw_image_comparison_screen() - draws images comparison result  |
+| 35) compare_images_and_save() - compares images and saves difference |
+| heatmap to output png file                                           |
+| 36) render_comparison_report() - renders images comparison result    |
+| 37) render_exif_location() - renders image location section         |
+| 38) draw_exif_strip_screen() - draws Exif metadata stripping result  |
+| 39) strip_exif_metadata_and_save() - strips Exif metadata and saves  |
+| stripped image copy                                                  |
+| 40) render_exif_strip_report() - renders removed Exif entries        |
+| 41) draw_exif_set_screen() - draws Exif tags setting result          |
+| 42) set_exif_tags_and_save() - sets Exif tags and saves edited image |
+| 43) render_exif_set_report() - renders set Exif tags values          |
+| 44) draw_xmp_metadata_screen() - draws XMP and IPTC metadata result  |
+| 45) render_xmp_report() - renders XMP properties and IPTC datasets   |
+| 46) render_icc_profile() - renders image ICC color profile section   |
+| 47) render_animation_info() - renders animation frames and timing    |
+| 48) draw_image_conversion_screen() - draws image conversion result   |
+| 49) convert_image_and_save() - converts image and saves converted    |
+| image file                                                           |
+| 50) render_conversion_report() - renders converted image sizes       |
+| 51) draw_batch_conversion_screen() - draws directory batch conversion|
+| result                                                               |
+| 52) run_batch_task() - runs batch conversion task with determinate   |
+| progress bar                                                         |
+| 53) render_batch_report() - renders batch conversion summary         |
+| 54) draw_contact_sheet_screen() - draws contact sheet result         |
+| 55) build_contact_sheet_and_save() - builds directory contact sheet  |
+| and saves it to output png file                                      |
+| 56) render_contact_sheet_report() - renders contact sheet grid info  |
+| 57) draw_autorotate_screen() - draws image auto-rotation result      |
+| 58) auto_rotate_image_and_save() - applies Exif orientation to image |
+| pixels and saves auto-rotated image copy                             |
+| 59) render_auto_rotate_report() - renders orientation and dimensions |
 ========================================================================
 */
 
@@ -22,11 +87,137 @@ use std::thread;
 use std::time::Duration;
 use std::process::Command;
 
-use crate::modules::main_mechanics::image_manager::*;
+use indicatif::ProgressBar;
+use serde::Serialize;
+use serde_json::json;
+
+use img_info::image_analysis::image_manager::*;
+use img_info::image_analysis::image_reports::*;
+use img_info::image_analysis::palette_extractor::*;
+use img_info::image_analysis::histogram_builder::*;
+use img_info::image_analysis::duplicates_finder::*;
+use img_info::image_analysis::similarity_finder::*;
+use img_info::image_analysis::image_comparator::*;
+use img_info::image_analysis::exif_writer::*;
+use img_info::image_analysis::xmp_reader::*;
+use img_info::image_analysis::animation_reader::*;
+use img_info::image_analysis::image_converter::*;
+use img_info::image_analysis::batch_converter::*;
+use img_info::image_analysis::contact_sheet_builder::*;
+use img_info::image_analysis::orientation_manager::*;
+
+use crate::modules::main_mechanics::output_files_manager::*;
 use crate::modules::app_design_managment::decoration_patterns::*;
 use crate::modules::ansi_styles_managment::ansi_escape_codes::*;
 use crate::modules::ansi_styles_managment::ansi_styles_manager::*;
 use crate::modules::app_design_managment::progress_bar::*;
+use crate::modules::app_design_managment::image_preview::*;
+
+//JSON document schema version (increase it only on incompatible JSON changes)
+const JSON_SCHEMA_VERSION: u32 = 3;
+
+//gii command result (image info, saved ICC profile file path and animation frames files paths, if saving requested,
+//and rendered thumbnail preview, preview isn't included in JSON)
+#[derive(Serialize)]
+struct ImageInfoResult {
+    #[serde(flatten)]
+    image_info: ImageInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_file_path: Option<std::path::PathBuf>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    frames_file_paths: Vec<std::path::PathBuf>,
+    #[serde(skip)]
+    preview: Option<String>
+}
+
+//fem command result (Exif metadata and saved export file path, if export requested)
+#[derive(Serialize)]
+struct ExifMetadataResult {
+    #[serde(flatten)]
+    exif_report: ExifReport,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_file_path: Option<std::path::PathBuf>,
+    #[serde(skip)]
+    export_format: Option<ExifExportFormat>
+}
+
+//hist command result (histograms and saved csv file path, if csv export requested)
+#[derive(Serialize)]
+struct HistogramResult {
+    #[serde(flatten)]
+    histogram_report: HistogramReport,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_file_path: Option<std::path::PathBuf>
+}
+
+//cmp command result (images differences and saved difference heatmap png file path)
+#[derive(Serialize)]
+struct ComparisonResult {
+    #[serde(flatten)]
+    comparison_report: ComparisonReport,
+    output_file_path: std::path::PathBuf
+}
+
+//sheet command result (contact sheet tiles and saved contact sheet png file path)
+#[derive(Serialize)]
+struct ContactSheetResult {
+    #[serde(flatten)]
+    contact_sheet_report: ContactSheetReport,
+    output_file_path: std::path::PathBuf
+}
+
+//strip command result (removed Exif entries and saved stripped image copy path)
+#[derive(Serialize)]
+struct ExifStripResult {
+    #[serde(flatten)]
+    strip_report: ExifStripReport,
+    output_file_path: std::path::PathBuf
+}
+
+//exif-set command result (set tags values and edited image path)
+#[derive(Serialize)]
+struct ExifSetResult {
+    #[serde(flatten)]
+    set_report: ExifSetReport,
+    output_file_path: std::path::PathBuf,
+    in_place: bool //true -> original image file is overwritten
+}
+
+//conv command result (converted image sizes and converted image file path)
+#[derive(Serialize)]
+struct ConversionResult {
+    #[serde(flatten)]
+    conversion_report: ConversionReport,
+    output_file_path: std::path::PathBuf
+}
+
+//autorotate command result (orientation report and auto-rotated image file path, None -> image orientation is normal)
+#[derive(Serialize)]
+struct AutoRotateResult {
+    #[serde(flatten)]
+    auto_rotate_report: AutoRotateReport,
+    output_file_path: Option<std::path::PathBuf>
+}
+
+//histogram bar chart size (256 bins are grouped by 4 into 64 columns)
+const HISTOGRAM_CHART_COLUMNS: usize = 64;
+const HISTOGRAM_CHART_ROWS: usize = 8;
+
+//max mismatched files shown in is text output (all files are listed in JSON output)
+const MAX_SHOWN_MISMATCHED_FILES: usize = 20;
+
+//max animation frames shown in gii text output (all frames are listed in JSON output)
+const MAX_SHOWN_ANIMATION_FRAMES: usize = 20;
+
+//block characters for bar chart cell filling (0/8 - 8/8 of cell)
+const HISTOGRAM_BLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+//command result output format
+#[derive(Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text, //decorated text
+    Json //JSON document (see README)
+}
 
 //clears screen
 fn clear_screen() {
@@ -34,7 +225,7 @@ fn clear_screen() {
     if cfg!(target_os = "windows") {
         //for Windows
         Command::new("cmd")//cls command in windows built-in command cmd.exe, you can't call it as a separate program in Command::new().
-            .args(&["/C", "cls"])
+            .args(["/C", "cls"])
             .status()
             .expect("Failed to clear terminal before drawing app screen!");
     } else {
@@ -59,64 +250,81 @@ pub fn draw_main_screen() {
     reset_buffer(); //resetting buffer for print!()
 }
 
-//draws image info command result screen 
+//draws image info command result screen (save_icc_profile -> saves embedded ICC profile to .icc file,
+//frames_selection -> saves selected animation frames to png files)
+//interactive -> false: command-line mode, without clearing and waiting, returns true if command succeeded
 pub fn draw_image_info_screen(
     path: String,
-    task_name: &str
-) {
-    clear_screen();
+    save_icc_profile: bool,
+    frames_selection: Option<FrameSelection>,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    //preview is drawn only in text output
+    let preview_protocol = if format == OutputFormat::Text {
+        detect_preview_protocol()
+    } else {
+        PreviewProtocol::Disabled
+    };
 
-    draw_progress_bar_title(
-        "Get Image Info".to_string(),
+    let image_data = run_task(
+        "Get Image Info",
+        interactive,
+        move || get_image_data_and_preview(path, preview_protocol, save_icc_profile, frames_selection)
     );
 
-    //creating progress bar and starting
-    let loading_bar = create_loading_spinner("Loading...".to_string());
-    loading_bar.enable_steady_tick(Duration::from_millis(150));
-
-    let get_image_data_handle = thread::spawn(move || {
-        get_image_data(path)
-    });
-    let image_data = get_image_data_handle.join().unwrap();
-    let mut input = String::new();
-
-    loading_bar.finish(); //finishing
-
-    clear_screen();
+    return draw_report_result("gii", image_data, render_image_info, task_name, interactive, format)
+}
 
-    println!("{}", task_view(task_name));
-    println!("{}", image_data);
-    print!("{}", command_cursor("Enter any key to continue"));
+//draws image palette command result screen (interactive -> false: command-line mode, without clearing and waiting)
+//returns true if command succeeded
+pub fn draw_image_palette_screen(
+    path: String,
+    colors_count: usize,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let palette = run_task(
+        "Get Image Palette",
+        interactive,
+        move || get_image_palette(path, colors_count)
+    );
 
-    reset_buffer(); //resetting buffer for print!()
+    return draw_report_result("palette", palette, render_palette_report, task_name, interactive, format)
+}
 
-    stdin().read_line(&mut input).expect("Failed to read input string!");
+//draws image histograms command result screen (export_csv -> saves bins to csv file)
+//returns true if command succeeded
+pub fn draw_image_histogram_screen(
+    path: String,
+    export_csv: bool,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let histogram = run_task(
+        "Get Image Histogram",
+        interactive,
+        move || get_image_histogram_and_save(path, export_csv)
+    );
 
-    //clears screen and draws main screen
-    if !input.is_empty() {
-        clear_screen();
-        draw_main_screen();
-    }
+    return draw_report_result("hist", histogram, render_histogram_report, task_name, interactive, format)
 }
 
 //draws help panel screen
-pub fn draw_help_screen() {
-    clear_screen();
+pub fn draw_help_screen(interactive: bool) {
+    if !interactive {
+        println!("{}", help_panel());
+        return
+    }
 
-    let mut input = String::new();
+    clear_screen();
 
     println!("{}", help_panel());
-    print!("{}", command_cursor("Enter any key to continue"));
-
-    reset_buffer(); //reesetting buffer for print!()
 
-    stdin().read_line(&mut input).expect("Failed to read input string!");
-
-    //clears screen and draws main screen
-    if !input.is_empty() {
-        clear_screen();
-        draw_main_screen();
-    }
+    wait_any_key();
 }
 
 //draws bye message then exit command
@@ -134,70 +342,400 @@ pub fn draw_command_error_message(input_command: String) {
     clear_screen(); //clear screen
 }
 
-//draws image exif metadata command result screen
+//draws command-line mode usage message (printed to stderr)
+pub fn draw_command_line_usage_message() {
+    eprintln!("{}", command_line_usage());
+}
+
+//draws image exif metadata command result screen (interactive -> false: command-line mode, without clearing and waiting)
+//returns true if command succeeded
 pub fn draw_image_exif_metadata_screen(
     path: String,
-    task_name: &str
-) {
-    clear_screen();
+    export_options: Option<ExifExportOptions>,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let exif_metadata = run_task(
+        "Get Image Exif Metadata",
+        interactive,
+        move || get_image_exif_metadata_and_save(path, export_options)
+    );
 
-    draw_progress_bar_title("Get Image Exif Metadata".to_string());
+    return draw_report_result("fem", exif_metadata, render_exif_report, task_name, interactive, format)
+}
 
-    //creating progress bar and starting
-    let loading_bar = create_loading_spinner("Loading...".to_string());
-    loading_bar.enable_steady_tick(Duration::from_millis(150));
+//draws all images sizes and info command result screen (interactive -> false: command-line mode, without clearing and waiting)
+//returns true if command succeeded
+pub fn draw_all_images_sizes_and_info(
+    extensions: Vec<String>,
+    scan_options: ScanOptions,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let info = run_scan_task(
+        "Get All Images Sizes And Info",
+        interactive,
+        move |on_progress| get_images_size_with_extensions(&extensions, &scan_options, on_progress)
+    );
 
-    let get_exif_metadata_handle = thread::spawn(move || {
-        get_image_exif_metadata(path)
-    });
-    let exif_metadata = get_exif_metadata_handle.join().unwrap();
-    let mut input = String::new();
+    return draw_report_result("is", info, render_extension_scan_report, task_name, interactive, format)
+}
 
-    loading_bar.finish(); //finishing
+//draws duplicate images command result screen (interactive -> false: command-line mode, without clearing and waiting)
+//returns true if command succeeded
+pub fn draw_duplicate_images_screen(
+    scan_options: ScanOptions,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let duplicates = run_scan_task(
+        "Find Duplicate Images",
+        interactive,
+        move |on_progress| find_duplicate_images(&scan_options, on_progress)
+    );
 
-    clear_screen();
+    return draw_report_result("dups", duplicates, render_duplicates_report, task_name, interactive, format)
+}
 
-    println!("{}", task_view(task_name));
-    println!("{}", exif_metadata);
-    print!("{}", command_cursor("Enter any key to continue"));
+//draws similar images command result screen (interactive -> false: command-line mode, without clearing and waiting)
+//returns true if command succeeded
+pub fn draw_similar_images_screen(
+    scan_options: ScanOptions,
+    threshold: u32,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let similar_images = run_scan_task(
+        "Find Similar Images",
+        interactive,
+        move |on_progress| find_similar_images(&scan_options, threshold, on_progress)
+    );
 
-    reset_buffer(); //resetting buffer for print!()
+    return draw_report_result("similar", similar_images, render_similar_images_report, task_name, interactive, format)
+}
 
-    stdin().read_line(&mut input).expect("Failed to read input string!");
+//draws images comparison command result screen (interactive -> false: command-line mode, without clearing and waiting)
+//returns true if command succeeded
+pub fn draw_image_comparison_screen(
+    first_path: String,
+    second_path: String,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let comparison = run_task(
+        "Compare Images",
+        interactive,
+        move || compare_images_and_save(first_path, second_path)
+    );
 
-    //clears screen and draws main screen
-    if !input.is_empty() {
-        clear_screen();
-        draw_main_screen();
+    return draw_report_result("cmp", comparison, render_comparison_report, task_name, interactive, format)
+}
+
+//draws Exif metadata stripping command result screen (targets is empty -> all metadata is stripped)
+//returns true if command succeeded
+pub fn draw_exif_strip_screen(
+    path: String,
+    targets: Vec<ExifStripTarget>,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let strip_result = run_task(
+        "Strip Exif Metadata",
+        interactive,
+        move || strip_exif_metadata_and_save(path, &targets)
+    );
+
+    return draw_report_result("strip", strip_result, render_exif_strip_report, task_name, interactive, format)
+}
+
+//draws Exif tags setting command result screen (save_copy -> edited image is saved as copy, original isn't changed)
+//returns true if command succeeded
+pub fn draw_exif_set_screen(
+    path: String,
+    edits: Vec<(String, String)>,
+    save_copy: bool,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let set_result = run_task(
+        "Set Exif Tags",
+        interactive,
+        move || set_exif_tags_and_save(path, &edits, save_copy)
+    );
+
+    return draw_report_result("exif-set", set_result, render_exif_set_report, task_name, interactive, format)
+}
+
+//draws image XMP and IPTC metadata command result screen (interactive -> false: command-line mode, without clearing and waiting)
+//returns true if command succeeded
+pub fn draw_xmp_metadata_screen(
+    path: String,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let xmp_metadata = run_task(
+        "Get Image XMP Metadata",
+        interactive,
+        move || get_image_xmp_metadata(path)
+    );
+
+    return draw_report_result("xmp", xmp_metadata, render_xmp_report, task_name, interactive, format)
+}
+
+//draws image conversion command result screen (converted image is saved to output directory, source isn't changed)
+//returns true if command succeeded
+pub fn draw_image_conversion_screen(
+    path: String,
+    target_format: ConversionFormat,
+    options: ConversionOptions,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let conversion_result = run_task(
+        "Convert Image",
+        interactive,
+        move || convert_image_and_save(path, target_format, &options)
+    );
+
+    return draw_report_result("conv", conversion_result, render_conversion_report, task_name, interactive, format)
+}
+
+//draws directory batch conversion command result screen (converted images are saved to mirrored output directory tree)
+//returns true if command succeeded
+pub fn draw_batch_conversion_screen(
+    input_dir: std::path::PathBuf,
+    output_dir: std::path::PathBuf,
+    options: BatchOptions,
+    scan_options: ScanOptions,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let batch_report = run_batch_task(
+        "Batch Convert Images",
+        interactive,
+        move |on_progress| batch_convert_images(&input_dir, &output_dir, &options, &scan_options, on_progress)
+    );
+
+    return draw_report_result("batch", batch_report, render_batch_report, task_name, interactive, format)
+}
+
+//draws directory contact sheet command result screen (contact sheet is saved to output png file)
+//returns true if command succeeded
+pub fn draw_contact_sheet_screen(
+    directory: std::path::PathBuf,
+    options: ContactSheetOptions,
+    scan_options: ScanOptions,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let contact_sheet = run_batch_task(
+        "Build Contact Sheet",
+        interactive,
+        move |on_progress| build_contact_sheet_and_save(&directory, &options, &scan_options, on_progress)
+    );
+
+    return draw_report_result("sheet", contact_sheet, render_contact_sheet_report, task_name, interactive, format)
+}
+
+//draws image auto-rotation command result screen (auto-rotated image copy is saved to output directory, source isn't changed)
+//returns true if command succeeded
+pub fn draw_autorotate_screen(
+    path: String,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let auto_rotate_result = run_task(
+        "Auto-rotate Image",
+        interactive,
+        move || auto_rotate_image_and_save(path)
+    );
+
+    return draw_report_result("autorotate", auto_rotate_result, render_auto_rotate_report, task_name, interactive, format)
+}
+
+//builds directory contact sheet and saves it to output png file
+fn build_contact_sheet_and_save(
+    directory: &std::path::Path,
+    options: &ContactSheetOptions,
+    scan_options: &ScanOptions,
+    on_progress: &mut dyn FnMut(&BatchProgress)
+) -> std::result::Result<ContactSheetResult, ImageManagerError> {
+    let (contact_sheet_report, sheet) = build_contact_sheet(directory, options, scan_options, on_progress)?;
+    let output_file_path = save_contact_sheet(&contact_sheet_report, &sheet)?;
+
+    return Ok(
+        ContactSheetResult {
+            contact_sheet_report,
+            output_file_path
+        }
+    )
+}
+
+//runs batch conversion task in separate thread with determinate progress bar (images count is known after directory scan),
+//in command-line mode runs task directly
+fn run_batch_task<T: Send + 'static>(
+    progress_bar_title: &str,
+    interactive: bool,
+    task: impl FnOnce(&mut dyn FnMut(&BatchProgress)) -> T + Send + 'static
+) -> T {
+    if !interactive {
+        return task(&mut |_| {}) //no progress bar in command-line mode
     }
+
+    clear_screen();
+
+    draw_progress_bar_title(progress_bar_title.to_string());
+
+    //creating progress bar (length is set by first progress report)
+    let progress_bar = create_progress_bar(0, "|📂Scanning directory... 🔎".to_string());
+    progress_bar.enable_steady_tick(Duration::from_millis(150));
+
+    let task_handle = thread::spawn(move || {
+        let result = task(&mut |batch_progress| {
+            progress_bar.set_length(batch_progress.images_count as u64);
+            progress_bar.set_position(batch_progress.processed_images as u64);
+            progress_bar.set_message(format!("|🖼️file: • {:?} 🔄", batch_progress.current_file));
+        });
+
+        progress_bar.finish_with_message("|✅Done");
+
+        result
+    });
+
+    return task_handle.join().unwrap()
 }
 
-//draws all images sizes and info command result screen
-pub fn draw_all_images_sizes_and_info(
-    extension: String,
-    task_name: &str
-) {
+//runs directories scan task in separate thread with loading spinner showing scan progress, in command-line mode runs task directly
+fn run_scan_task<T: Send + 'static>(
+    progress_bar_title: &str,
+    interactive: bool,
+    task: impl FnOnce(&mut dyn FnMut(&ScanProgress)) -> T + Send + 'static
+) -> T {
+    if !interactive {
+        return task(&mut |_| {}) //no spinner in command-line mode
+    }
+
     clear_screen();
 
-    draw_progress_bar_title("Get All Images Sizes And Info".to_string());
+    draw_progress_bar_title(progress_bar_title.to_string());
 
     //creating progress bar and starting
     let loading_bar = create_loading_spinner("Loading...".to_string());
     loading_bar.enable_steady_tick(Duration::from_millis(150));
 
-    let get_all_images_sizes_and_info_handle = thread::spawn(move || {
-        get_images_size_with_extension(
-            extension,
-            loading_bar
-        )
+    let task_handle = thread::spawn(move || {
+        let result = task(&mut |scan_progress| set_scan_progress_message(&loading_bar, scan_progress));
+
+        loading_bar.finish(); //finishing
+
+        result
     });
-    let info = get_all_images_sizes_and_info_handle.join().unwrap();
-    let mut input = String::new();
+
+    return task_handle.join().unwrap()
+}
+
+//runs command task in separate thread with loading spinner, in command-line mode runs task directly
+fn run_task<T: Send + 'static>(
+    progress_bar_title: &str,
+    interactive: bool,
+    task: impl FnOnce() -> T + Send + 'static
+) -> T {
+    if !interactive {
+        return task()
+    }
 
     clear_screen();
 
-    println!("{}", task_view(task_name));
-    println!("{}", info);
+    draw_progress_bar_title(progress_bar_title.to_string());
+
+    //creating progress bar and starting
+    let loading_bar = create_loading_spinner("Loading...".to_string());
+    loading_bar.enable_steady_tick(Duration::from_millis(150));
+
+    let task_handle = thread::spawn(task);
+    let result = task_handle.join().unwrap();
+
+    loading_bar.finish(); //finishing
+
+    return result
+}
+
+//renders command report (text or JSON document) and draws it, returns true if command succeeded
+fn draw_report_result<T: Serialize>(
+    command: &str,
+    report: std::result::Result<T, ImageManagerError>,
+    render_report: fn(&T) -> String,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let result = match format {
+        OutputFormat::Text => report
+            .as_ref()
+            .map(render_report)
+            .map_err(render_image_manager_error),
+        OutputFormat::Json => render_json_document(command, &report)
+    };
+
+    return draw_command_result(result, task_name, interactive, format)
+}
+
+//draws command result screen, in command-line mode prints result to stdout (text error to stderr, JSON error to stdout)
+//returns true if command succeeded
+fn draw_command_result(
+    result: std::result::Result<String, String>,
+    task_name: &str,
+    interactive: bool,
+    format: OutputFormat
+) -> bool {
+    let is_succeeded = result.is_ok();
+
+    if interactive {
+        let text = match result {
+            Ok(text) => text,
+            Err(text) => text
+        };
+
+        clear_screen();
+
+        println!("{}", task_view(task_name));
+        println!("{}", text);
+
+        wait_any_key();
+    } else {
+        let write_result = match result {
+            Ok(text) => writeln!(stdout().lock(), "{}", text),
+            Err(text) if format == OutputFormat::Json => writeln!(stdout().lock(), "{}", text),
+            Err(text) => writeln!(stderr().lock(), "{}", text)
+        };
+
+        //closed pipe (img_info fem photo.jpg --format json | head) isn't an error, output reader just doesn't need the rest
+        if let Err(error) = write_result {
+            if error.kind() != ErrorKind::BrokenPipe {
+                panic!("Failed to print command result: {}", error);
+            }
+        }
+    }
+
+    return is_succeeded
+}
+
+//waits any key from user, then clears screen and draws main screen
+fn wait_any_key() {
+    let mut input = String::new();
+
     print!("{}", command_cursor("Enter any key to continue"));
 
     reset_buffer(); //resetting buffer for print!()
@@ -215,3 +753,2164 @@ pub fn draw_all_images_sizes_and_info(
 fn reset_buffer() {
     stdout().flush().unwrap();
 }
+
+//gets image data, renders image thumbnail preview with preview protocol, saves embedded ICC profile
+//and selected animation frames (optional)
+fn get_image_data_and_preview(
+    path: String,
+    preview_protocol: PreviewProtocol,
+    save_profile: bool,
+    frames_selection: Option<FrameSelection>
+) -> std::result::Result<ImageInfoResult, ImageManagerError> {
+    let (image_info, preview) = if preview_protocol == PreviewProtocol::Disabled {
+        (get_image_data(path.clone())?, None)
+    } else {
+        let (image_info, thumbnail) = get_image_data_with_thumbnail(path.clone(), preview_thumbnail_size(preview_protocol))?;
+
+        (image_info, render_image_preview(&thumbnail, preview_protocol))
+    };
+
+    let output_file_path = if save_profile {
+        //profile bytes are taken from image data read above (file isn't read again)
+        let profile_data = image_info
+            .icc_profile_data
+            .as_deref()
+            .ok_or_else(|| ImageManagerError::IccProfileNotFound { file_name: image_info.file_name.clone() })?;
+
+        Some(save_icc_profile(&image_info, profile_data)?)
+    } else {
+        None
+    };
+
+    let mut frames_file_paths = Vec::new();
+
+    if let Some(selection) = frames_selection {
+        extract_animation_frames(path, &selection, |frame_number, frame| {
+            frames_file_paths.push(save_animation_frame(&image_info, frame_number, &frame)?);
+
+            return Ok(())
+        })?;
+    }
+
+    return Ok(
+        ImageInfoResult {
+            image_info,
+            output_file_path,
+            frames_file_paths,
+            preview
+        }
+    )
+}
+
+//renders image info result (thumbnail preview above image dimensions)
+fn render_image_info(image_info_result: &ImageInfoResult) -> String {
+    let image_info = &image_info_result.image_info;
+    let pixels_statistics = &image_info.pixels_statistics;
+    let pixels_count = pixels_statistics.pixels_count;
+
+    let all_pixels_info = format!(
+        "{}\n{}\n{}\n{}\n|-[{}]-> ({} %)\n|-[{}]-> ({} %)",
+
+        render_channel_statistics(
+            font_and_style(FONT_STYLES.bold, FONT_COLORS.f_red, "Red"),
+            &pixels_statistics.red,
+            Some(convert_to_percent(pixels_count, pixels_statistics.red_dominant_pixels))
+        ),
+        render_channel_statistics(
+            font_and_style(FONT_STYLES.bold, FONT_COLORS.f_green, "Green"),
+            &pixels_statistics.green,
+            Some(convert_to_percent(pixels_count, pixels_statistics.green_dominant_pixels))
+        ),
+        render_channel_statistics(
+            font_and_style(FONT_STYLES.bold, FONT_COLORS.f_blue, "Blue"),
+            &pixels_statistics.blue,
+            Some(convert_to_percent(pixels_count, pixels_statistics.blue_dominant_pixels))
+        ),
+        render_channel_statistics(
+            font_and_style(FONT_STYLES.bold, FONT_COLORS.f_light_white, "Alpha"),
+            &pixels_statistics.alpha,
+            None
+        ),
+
+        font_and_style(
+            FONT_STYLES.bold, 
+            FONT_COLORS.f_gray, 
+            "No dominant channel"
+        ),
+        font_style(
+            FONT_STYLES.bold, 
+            &convert_to_percent(pixels_count, pixels_statistics.no_dominant_pixels)
+        ),
+
+        font_and_style(
+            FONT_STYLES.bold, 
+            FONT_COLORS.f_gray, 
+            "Transparent"
+        ),
+        font_style(
+            FONT_STYLES.bold, 
+            &convert_to_percent(pixels_count, pixels_statistics.transparent_pixels)
+        )
+    );
+
+    //detected container (by file content) with extension mismatch warning
+    let mut container_info = match image_info.container {
+        Some(container) => font_style(FONT_STYLES.bold, container.name()),
+        None => "not detectable (checked by extension)".to_string()
+    };
+
+    if image_info.extension_mismatch {
+        container_info.push_str(
+            &format!(
+                " [{}]-> file extension doesn't match content!",
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_red, 
+                    FONT_STYLES.bold, 
+                    "Extension mismatch!"
+                )
+            )
+        );
+    }
+
+    //Exif orientation with displayed dimensions (orientations 5-8 swap width and height)
+    let orientation_info = match image_info.orientation {
+        Some(orientation) => format!(
+            "|-Image orientation: {} ({}), displayed as {}\n",
+            orientation,
+            get_orientation_name(orientation),
+            font_style(FONT_STYLES.bold, &format!("({}x{})px", image_info.display_width, image_info.display_height))
+        ),
+        None => String::new()
+    };
+
+    let mut result = format!(
+        "{}|-Image dimensions: ({}x{})px\n{}|-Image color model: {:?}\n|-Image file name: {}\n|-Image file extension: {}\n|-Image container: {}\n|-Image file size: {}\n{}|-Image pixels info ({} pixels):\n{}\n|", 
+        image_info_result.preview.as_deref().map(|preview| format!("{}|\n", preview)).unwrap_or_default(),
+        image_info.width,
+        image_info.height,
+        orientation_info,
+        image_info.color_model,
+        image_info.file_name,
+        image_info.file_extension,
+        container_info,
+        adapt_file_size(image_info.file_size as f64),
+        render_icc_profile(image_info.icc_profile.as_ref()),
+        pixels_count,
+        all_pixels_info
+    );
+
+    if let Some(animation_info) = &image_info.animation {
+        result.push_str(&render_animation_info(animation_info));
+    }
+
+    //output file description
+    if let Some(output_file_path) = &image_info_result.output_file_path {
+        result.push_str(
+            &format!(
+                "\n|-[{}]-> See in path: {}\n|",
+
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_light_yellow, 
+                    FONT_STYLES.bold, 
+                    "Output icc file created!"
+                ),
+
+                full_style(
+                    FONT_COLORS.f_light_white, 
+                    BACKGROUND_COLORS.b_black, 
+                    FONT_STYLES.italic,    
+                    &output_file_path.to_string_lossy()
+                )
+            )
+        );
+    }
+
+    //extracted frames files description (directory of first frame, all frames are saved together)
+    if let Some(first_frame_path) = image_info_result.frames_file_paths.first() {
+        result.push_str(
+            &format!(
+                "\n|-[{}]-> See in path: {}\n|",
+
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_light_yellow, 
+                    FONT_STYLES.bold, 
+                    &format!("{} frames png files created!", image_info_result.frames_file_paths.len())
+                ),
+
+                full_style(
+                    FONT_COLORS.f_light_white, 
+                    BACKGROUND_COLORS.b_black, 
+                    FONT_STYLES.italic,    
+                    &first_frame_path.parent().unwrap_or(first_frame_path).to_string_lossy()
+                )
+            )
+        );
+    }
+
+    return result
+}
+
+//renders animation frames and timing section (frames count, loops, duration, every frame area, delay, disposal and blend)
+fn render_animation_info(animation_info: &AnimationInfo) -> String {
+    let loops = if animation_info.loop_count == 0 {
+        "infinite".to_string()
+    } else {
+        animation_info.loop_count.to_string()
+    };
+
+    let mut result = format!(
+        "\n|-[{}]-> frames: {} | duration: {} | loops: {}\n",
+        font_and_style(
+            FONT_STYLES.bold, 
+            FONT_COLORS.f_cyan, 
+            "Animation"
+        ),
+        font_style(
+            FONT_STYLES.bold, 
+            &animation_info.frames_count.to_string()
+        ),
+        font_style(
+            FONT_STYLES.bold, 
+            &format!("{:.2} s", animation_info.duration_ms as f64 / 1000.0)
+        ),
+        loops
+    );
+
+    for frame in animation_info.frames.iter().take(MAX_SHOWN_ANIMATION_FRAMES) {
+        result.push_str(
+            &format!(
+                "|-[Frame {}]-> ({}x{})px at ({}, {}) | delay: {} ms | disposal: {} | blend: {}\n",
+                frame.number,
+                frame.width,
+                frame.height,
+                frame.x_offset,
+                frame.y_offset,
+                font_style(
+                    FONT_STYLES.bold, 
+                    &frame.delay_ms.to_string()
+                ),
+                frame.disposal,
+                frame.blend
+            )
+        );
+    }
+
+    if animation_info.frames.len() > MAX_SHOWN_ANIMATION_FRAMES {
+        result.push_str(&format!("|-...and {} more frames (see JSON output)\n", animation_info.frames.len() - MAX_SHOWN_ANIMATION_FRAMES));
+    }
+
+    result.push('|');
+
+    return result
+}
+
+//renders image ICC color profile section (description with recognized profile name and header fields)
+fn render_icc_profile(icc_profile: Option<&IccProfileInfo>) -> String {
+    let Some(icc_profile) = icc_profile else {
+        return "|-Image color profile: no ICC profile embedded\n".to_string()
+    };
+
+    let mut profile_name = font_style(
+        FONT_STYLES.bold, 
+        icc_profile.description.as_deref().unwrap_or("Unnamed profile")
+    );
+
+    if let Some(known_profile) = &icc_profile.known_profile {
+        profile_name.push_str(
+            &format!(
+                " [{}]",
+                full_style(
+                    FONT_COLORS.f_black, 
+                    BACKGROUND_COLORS.b_light_blue, 
+                    FONT_STYLES.bold, 
+                    known_profile
+                )
+            )
+        );
+    }
+
+    return format!(
+        "|-Image color profile: {}\n|-Profile info-> version: {} | class: {} | color space: {} | PCS: {} | intent: {} | size: {}\n",
+        profile_name,
+        icc_profile.version,
+        icc_profile.device_class,
+        icc_profile.color_space,
+        icc_profile.pcs,
+        icc_profile.rendering_intent,
+        adapt_file_size(icc_profile.size as f64)
+    )
+}
+
+//renders one color channel statistics line (dominant_percent -> share of pixels where channel dominates)
+fn render_channel_statistics(
+    channel_name: String,
+    channel_statistics: &ChannelStatistics,
+    dominant_percent: Option<String>
+) -> String {
+    let mut result = format!(
+        "|-[{}]-> mean: {} | median: {} | std dev: {} | min: {} | max: {}",
+        channel_name,
+        font_style(
+            FONT_STYLES.bold, 
+            &format!("{:.2}", channel_statistics.mean)
+        ),
+        channel_statistics.median,
+        format_args!("{:.2}", channel_statistics.standard_deviation),
+        channel_statistics.min,
+        channel_statistics.max
+    );
+
+    if let Some(percent) = dominant_percent {
+        result.push_str(
+            &format!(
+                " | dominates in: ({} %)",
+                font_style(
+                    FONT_STYLES.bold, 
+                    &percent
+                )
+            )
+        );
+    }
+
+    return result
+}
+
+//renders image dominant colors palette result
+fn render_palette_report(palette_report: &PaletteReport) -> String {
+    let mut result = format!(
+        "|-Image {} palette: {}, colors-> {}, counted pixels-> {}\n|\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "dominant colors"
+        ),
+        palette_report.file_name,
+        palette_report.colors.len(),
+        palette_report.pixels_count
+    );
+
+    for (index, color) in palette_report.colors.iter().enumerate() {
+        result.push_str(
+            &format!(
+                "|-[{}]->[{}] {} | rgb({}, {}, {}) | hsl({:.0}, {:.0}%, {:.0}%) | ({} %)\n",
+                index + 1,
+                background_rgb_color(
+                    color.rgb, 
+                    "        "
+                ),
+                font_style(
+                    FONT_STYLES.bold, 
+                    &color.hex
+                ),
+                color.rgb[0],
+                color.rgb[1],
+                color.rgb[2],
+                color.hsl[0],
+                color.hsl[1],
+                color.hsl[2],
+                font_style(
+                    FONT_STYLES.bold, 
+                    &format!("{:.2}", color.coverage)
+                )
+            )
+        );
+    }
+
+    result.push('|');
+
+    return result
+}
+
+//gets image Exif metadata and saves it to output file (if export_options), nothing is saved if metadata isn't fetched
+fn get_image_exif_metadata_and_save(
+    path: String,
+    export_options: Option<ExifExportOptions>
+) -> std::result::Result<ExifMetadataResult, ImageManagerError> {
+    let exif_report = get_image_exif_metadata(path)?;
+
+    let output_file_path = match &export_options {
+        Some(options) => Some(save_exif_report(&exif_report, options)?),
+        None => None
+    };
+
+    return Ok(
+        ExifMetadataResult {
+            exif_report,
+            output_file_path,
+            export_format: export_options.map(|options| options.format)
+        }
+    )
+}
+
+//gets image histograms and saves bins to output csv file (if export_csv)
+fn get_image_histogram_and_save(
+    path: String,
+    export_csv: bool
+) -> std::result::Result<HistogramResult, ImageManagerError> {
+    let histogram_report = get_image_histogram(path)?;
+
+    let output_file_path = if export_csv {
+        Some(save_histogram_to_csv(&histogram_report)?)
+    } else {
+        None
+    };
+
+    return Ok(
+        HistogramResult {
+            histogram_report,
+            output_file_path
+        }
+    )
+}
+
+//renders image histograms result
+fn render_histogram_report(histogram_result: &HistogramResult) -> String {
+    let histogram_report = &histogram_result.histogram_report;
+
+    let mut result = format!(
+        "|-Image {}: {}, counted pixels-> {}\n|\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "histograms"
+        ),
+        histogram_report.file_name,
+        histogram_report.pixels_count
+    );
+
+    let charts = [
+        ("Red", FONT_COLORS.f_red, &histogram_report.red),
+        ("Green", FONT_COLORS.f_green, &histogram_report.green),
+        ("Blue", FONT_COLORS.f_blue, &histogram_report.blue),
+        ("Luminance", FONT_COLORS.f_light_white, &histogram_report.luminance)
+    ];
+
+    for (channel_name, color, bins) in charts {
+        result.push_str(
+            &render_histogram_chart(
+                channel_name,
+                color,
+                bins,
+                histogram_report.pixels_count
+            )
+        );
+    }
+
+    //output file description
+    if let Some(output_file_path) = &histogram_result.output_file_path {
+        result.push_str(
+            &format!(
+                "|-[{}]-> See in path: {}\n",
+
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_light_yellow, 
+                    FONT_STYLES.bold, 
+                    "Output csv file created!"
+                ),
+
+                full_style(
+                    FONT_COLORS.f_light_white, 
+                    BACKGROUND_COLORS.b_black, 
+                    FONT_STYLES.italic,    
+                    &output_file_path.to_string_lossy()
+                )
+            )
+        );
+    }
+
+    result.push('|');
+
+    return result
+}
+
+//renders one histogram bar chart with clipped shadows (bin 0) and highlights (bin 255) percents
+fn render_histogram_chart(
+    channel_name: &str,
+    color: i32,
+    bins: &[u64],
+    pixels_count: u64
+) -> String {
+    let mut result = format!(
+        "|-[{}]-> shadows clipped: ({} %) | highlights clipped: ({} %)\n",
+        font_and_style(
+            FONT_STYLES.bold, 
+            color, 
+            channel_name
+        ),
+        font_style(
+            FONT_STYLES.bold, 
+            &convert_to_percent(pixels_count, bins.first().copied().unwrap_or(0))
+        ),
+        font_style(
+            FONT_STYLES.bold, 
+            &convert_to_percent(pixels_count, bins.last().copied().unwrap_or(0))
+        )
+    );
+
+    //grouping bins into chart columns
+    let bins_per_column = bins.len().div_ceil(HISTOGRAM_CHART_COLUMNS).max(1);
+    let columns: Vec<u64> = bins.chunks(bins_per_column).map(|chunk| chunk.iter().sum()).collect();
+    let max_column = columns.iter().copied().max().unwrap_or(0).max(1);
+
+    //column height in cell eighths (not empty columns are at least 1/8 high)
+    let heights: Vec<usize> = columns
+        .iter()
+        .map(|&column| {
+            let height = (column as f64 / max_column as f64 * (HISTOGRAM_CHART_ROWS * 8) as f64).round() as usize;
+
+            if column > 0 { height.max(1) } else { 0 }
+        })
+        .collect();
+
+    //drawing chart rows from top to bottom
+    for row in (0..HISTOGRAM_CHART_ROWS).rev() {
+        let row_text: String = heights
+            .iter()
+            .map(|height| HISTOGRAM_BLOCKS[height.saturating_sub(row * 8).min(8)])
+            .collect();
+
+        result.push_str(
+            &format!(
+                "|  {}\n",
+                font_color(
+                    color, 
+                    &row_text
+                )
+            )
+        );
+    }
+
+    //values axis
+    result.push_str(
+        &format!(
+            "|  {}\n|\n",
+            font_style(
+                FONT_STYLES.dim, 
+                &format!("{:<width$}{:^8}{:>width$}", 0, 128, 255, width = (columns.len() - 8) / 2)
+            )
+        )
+    );
+
+    return result
+}
+
+//sets directories scan progress message to loading spinner
+fn set_scan_progress_message(
+    loading_bar: &ProgressBar,
+    scan_progress: &ScanProgress
+) {
+    //configuring loading spinner message
+    let progress_bar_message = format!(
+        "-|🗂️Scanned dirs ({} ✅)/({}) => images found: [{}🖼️]\n|📂dir: • {:?} 🔎",
+        scan_progress.scanned_dirs,
+        scan_progress.dirs_count,
+        scan_progress.images_found,
+        scan_progress.current_dir
+    );
+
+    loading_bar.set_message(progress_bar_message);
+}
+
+//renders image Exif metadata result
+fn render_exif_report(exif_metadata_result: &ExifMetadataResult) -> String {
+    let mut result = String::new();
+    let exif_report = &exif_metadata_result.exif_report;
+    let exif_entries_count = exif_report.entries.len();
+
+    //creating description title
+    result.push_str(
+        &format!(
+            "|-Image {} metadata fetched succesfully!\n|-Image filename-> {}, type-> {}, exif entries-> {}\n|===================================================|\n|\n",
+
+            font_style(
+                FONT_STYLES.bold, 
+                "Exif"
+            ),
+
+            exif_report.file_name,
+            exif_report.file_extension,
+            exif_entries_count
+        )
+    );
+
+    //configuring entry info string
+    for (index, entry) in exif_report.entries.iter().enumerate() {
+        result.push_str(
+            &format!(
+                "|-[{}]->[{}]|{}|\t{}\n",
+                index + 1,
+                entry.ifd,
+                entry.tag,
+                entry.readable_value
+            )
+        );
+
+        if index + 1 == exif_entries_count {
+            result.push('|');
+        }
+    }
+
+    result.push_str(&render_exif_location(exif_report.location.as_ref()));
+
+    //output file description (only if export requested)
+    if let (Some(output_file_path), Some(export_format)) = (&exif_metadata_result.output_file_path, exif_metadata_result.export_format) {
+        result.push_str(
+            &format!(
+                "\n|-[{}]-> See in path: {}\n|",
+
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_light_yellow, 
+                    FONT_STYLES.bold, 
+                    &format!("Output {} file created!", export_format.name())
+                ),
+
+                full_style(
+                    FONT_COLORS.f_light_white, 
+                    BACKGROUND_COLORS.b_black, 
+                    FONT_STYLES.italic,    
+                    &output_file_path.to_string_lossy()
+                )
+            )
+        );
+    }
+
+    return result
+}
+
+//renders images with specific extensions scan result: formats x directories matrix of images counts and sizes
+fn render_extension_scan_report(scan_report: &ExtensionScanReport) -> String {
+    let mut result = String::new();
+
+    let formats_names: Vec<&str> = scan_report.formats.iter().map(|format_info| format_info.format.as_str()).collect();
+
+    if scan_report.images_count == 0 {
+        result.push_str(
+            &format!(
+                "|[{}]-> Images with extensions |{}| not found in scanned directories!\n|[{}]-App scans the main directories of your computer where photos may be stored\n|-or directories from command (is=(type; dir; dir)).\n|\n",
+
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_red, 
+                    FONT_STYLES.bold, 
+                    "Images not found!"
+                ),
+                font_and_style(
+                    FONT_STYLES.underline, 
+                    FONT_COLORS.f_green, 
+                    &formats_names.join(", ")
+                ),
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_blue, 
+                    FONT_STYLES.bold, 
+                    "Description"
+                )
+            )
+        );
+    } else {
+        result.push_str(
+            &format!(
+                "|Found (≈{}) image files with extensions-> |{}| in directories:\n|\n|All size (≈{})\n|\n",
+                scan_report.images_count,
+            
+                font_and_style(
+                    FONT_STYLES.underline, 
+                    FONT_COLORS.f_green, 
+                    &formats_names.join(", ")
+                ),
+
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_yellow, 
+                    &adapt_file_size(scan_report.total_size as f64)
+                )
+            )
+        );
+    }
+
+    //matrix rows: directory name, every found format cell, all formats cell (if there isn't exactly one found format)
+    //formats without images are hidden (they are listed in JSON output)
+    let found_formats: Vec<usize> = (0..scan_report.formats.len())
+        .filter(|&format_index| scan_report.formats[format_index].images_count > 0)
+        .collect();
+    let show_all_column = found_formats.len() != 1;
+    let mut rows: Vec<Vec<String>> = Vec::new();
+
+    let mut header = vec!["Directory".to_string()];
+    header.extend(found_formats.iter().map(|&format_index| formats_names[format_index].to_string()));
+
+    if show_all_column {
+        header.push("All".to_string());
+    }
+
+    rows.push(header);
+
+    let render_formats_row = |name: &str, formats: &[FormatScanInfo], images_count: u64, images_size: u64| {
+        let mut row = vec![name.to_string()];
+
+        row.extend(
+            found_formats
+                .iter()
+                .map(|&format_index| render_scan_matrix_cell(formats[format_index].images_count, formats[format_index].images_size))
+        );
+
+        if show_all_column {
+            row.push(render_scan_matrix_cell(images_count, images_size));
+        }
+
+        return row
+    };
+
+    for directory in &scan_report.directories {
+        rows.push(render_formats_row(&directory.name, &directory.formats, directory.images_count, directory.images_size));
+    }
+
+    rows.push(render_formats_row("All", &scan_report.formats, scan_report.images_count, scan_report.total_size));
+
+    //columns widths (cells are padded before styling, styles don't change visible width)
+    let mut columns_widths = vec![0; rows[0].len()];
+
+    for row in &rows {
+        for (column, cell) in row.iter().enumerate() {
+            columns_widths[column] = columns_widths[column].max(cell.chars().count());
+        }
+    }
+
+    let last_row_index = rows.len() - 1;
+
+    for (row_index, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(column, cell)| {
+                let padded_cell = format!("{:<width$}", cell, width = columns_widths[column]);
+
+                if row_index == 0 {
+                    font_style(FONT_STYLES.bold, &padded_cell)
+                } else if row_index == last_row_index {
+                    font_and_style(FONT_STYLES.bold, FONT_COLORS.f_yellow, &padded_cell)
+                } else if column == 0 {
+                    font_and_style(FONT_STYLES.italic, FONT_COLORS.f_cyan, &padded_cell)
+                } else {
+                    padded_cell
+                }
+            })
+            .collect();
+
+        result.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+
+    //scanned directories real paths
+    result.push_str("|\n");
+
+    for directory in &scan_report.directories {
+        result.push_str(
+            &format!(
+                "|-[{}]-> {}\n",
+
+                font_and_style(
+                    FONT_STYLES.italic, 
+                    FONT_COLORS.f_cyan, 
+                    &directory.name
+                ),
+                font_style(
+                    FONT_STYLES.dim, 
+                    &directory.path.to_string_lossy()
+                )
+            )
+        );
+    }
+
+    //files with extension, which doesn't match content
+    let mismatched_files: Vec<&MismatchedFileInfo> = scan_report.directories
+        .iter()
+        .flat_map(|directory| &directory.mismatched_files)
+        .collect();
+
+    if !mismatched_files.is_empty() {
+        result.push_str(
+            &format!(
+                "|\n|[{}]-> ({}) files extension doesn't match content:\n",
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_red, 
+                    FONT_STYLES.bold, 
+                    "Extension mismatch!"
+                ),
+                mismatched_files.len()
+            )
+        );
+
+        for mismatched_file in mismatched_files.iter().take(MAX_SHOWN_MISMATCHED_FILES) {
+            result.push_str(
+                &format!(
+                    "|-[{} -> {}] {}\n",
+                    font_style(
+                        FONT_STYLES.underline, 
+                        &mismatched_file.extension
+                    ),
+                    font_style(
+                        FONT_STYLES.bold, 
+                        mismatched_file.container.map(|container| container.name()).unwrap_or("not image")
+                    ),
+                    mismatched_file.path.to_string_lossy()
+                )
+            );
+        }
+
+        if mismatched_files.len() > MAX_SHOWN_MISMATCHED_FILES {
+            result.push_str(&format!("|-...and {} more (see JSON output)\n", mismatched_files.len() - MAX_SHOWN_MISMATCHED_FILES));
+        }
+    }
+
+    result.push('|');
+
+    return result
+}
+
+//renders images count and size matrix cell (3 files (1.2 MB))
+fn render_scan_matrix_cell(
+    images_count: u64,
+    images_size: u64
+) -> String {
+    return format!("{} files ({})", images_count, adapt_file_size(images_size as f64))
+}
+
+//renders exact duplicate images result
+fn render_duplicates_report(duplicates_report: &DuplicatesReport) -> String {
+    let mut result = String::new();
+
+    if duplicates_report.duplicate_groups.is_empty() {
+        result.push_str(
+            &format!(
+                "|[{}]-> All ({}) scanned images are unique.\n|\n",
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_green, 
+                    FONT_STYLES.bold, 
+                    "Duplicates not found!"
+                ),
+                duplicates_report.images_count
+            )
+        );
+    } else {
+        result.push_str(
+            &format!(
+                "|Found ({}) duplicate groups, ({}) copies in ({}) scanned images\n|\n|Wasted size (≈{})\n|\n",
+                duplicates_report.duplicate_groups.len(),
+                duplicates_report.duplicate_files,
+                duplicates_report.images_count,
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_yellow, 
+                    &adapt_file_size(duplicates_report.wasted_size as f64)
+                )
+            )
+        );
+    }
+
+    //duplicate groups with files paths
+    for (index, group) in duplicates_report.duplicate_groups.iter().enumerate() {
+        result.push_str(
+            &format!(
+                "|-[{}]->[{} files x {}]->[wasted {}] {}\n",
+                index + 1,
+                font_style(
+                    FONT_STYLES.bold, 
+                    &group.files.len().to_string()
+                ),
+                adapt_file_size(group.file_size as f64),
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_yellow, 
+                    &adapt_file_size(group.wasted_size as f64)
+                ),
+                font_style(
+                    FONT_STYLES.dim, 
+                    &format!("sha256: {}", &group.hash[..16])
+                )
+            )
+        );
+
+        for file in &group.files {
+            result.push_str(&format!("|  {}\n", file.to_string_lossy()));
+        }
+    }
+
+    //scanned directories real paths
+    if !duplicates_report.duplicate_groups.is_empty() {
+        result.push_str("|\n");
+    }
+
+    for directory in &duplicates_report.directories {
+        result.push_str(
+            &format!(
+                "|-[{}]-> {}\n",
+                font_and_style(
+                    FONT_STYLES.italic, 
+                    FONT_COLORS.f_cyan, 
+                    "Scanned"
+                ),
+                font_style(
+                    FONT_STYLES.dim, 
+                    &directory.to_string_lossy()
+                )
+            )
+        );
+    }
+
+    result.push('|');
+
+    return result
+}
+
+//renders similar images clusters result
+fn render_similar_images_report(similar_images_report: &SimilarImagesReport) -> String {
+    let mut result = String::new();
+
+    if similar_images_report.clusters.is_empty() {
+        result.push_str(
+            &format!(
+                "|[{}]-> No similar images in ({}) compared images (threshold-> {}).\n",
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_green, 
+                    FONT_STYLES.bold, 
+                    "Similar images not found!"
+                ),
+                similar_images_report.images_count,
+                similar_images_report.threshold
+            )
+        );
+    } else {
+        result.push_str(
+            &format!(
+                "|Found ({}) clusters of similar images in ({}) compared images (threshold-> {})\n",
+                font_style(
+                    FONT_STYLES.bold, 
+                    &similar_images_report.clusters.len().to_string()
+                ),
+                similar_images_report.images_count,
+                similar_images_report.threshold
+            )
+        );
+    }
+
+    if similar_images_report.skipped_files > 0 {
+        result.push_str(&format!("|-({}) images couldn't be decoded and were skipped\n", similar_images_report.skipped_files));
+    }
+
+    result.push_str("|\n");
+
+    //clusters, first image is the biggest, distance to it is shown for other images
+    for (index, cluster) in similar_images_report.clusters.iter().enumerate() {
+        result.push_str(
+            &format!(
+                "|-[{}]->[{} images]\n",
+                index + 1,
+                font_style(
+                    FONT_STYLES.bold, 
+                    &cluster.images.len().to_string()
+                )
+            )
+        );
+
+        for (image_index, image) in cluster.images.iter().enumerate() {
+            let distance = if image_index == 0 {
+                font_and_style(FONT_STYLES.italic, FONT_COLORS.f_green, "(biggest)")
+            } else {
+                format!("(distance: {})", image.distance)
+            };
+
+            result.push_str(
+                &format!(
+                    "|  [{}x{}]->[{}] {} {}\n",
+                    image.width,
+                    image.height,
+                    font_and_style(
+                        FONT_STYLES.bold, 
+                        FONT_COLORS.f_yellow, 
+                        &adapt_file_size(image.file_size as f64)
+                    ),
+                    image.path.to_string_lossy(),
+                    distance
+                )
+            );
+        }
+    }
+
+    //scanned directories real paths
+    if !similar_images_report.clusters.is_empty() {
+        result.push_str("|\n");
+    }
+
+    for directory in &similar_images_report.directories {
+        result.push_str(
+            &format!(
+                "|-[{}]-> {}\n",
+                font_and_style(
+                    FONT_STYLES.italic, 
+                    FONT_COLORS.f_cyan, 
+                    "Scanned"
+                ),
+                font_style(
+                    FONT_STYLES.dim, 
+                    &directory.to_string_lossy()
+                )
+            )
+        );
+    }
+
+    result.push('|');
+
+    return result
+}
+
+//compares images and saves difference heatmap to output png file
+fn compare_images_and_save(
+    first_path: String,
+    second_path: String
+) -> std::result::Result<ComparisonResult, ImageManagerError> {
+    let (comparison_report, heatmap) = compare_images(first_path, second_path)?;
+    let output_file_path = save_heatmap_to_png(&comparison_report, &heatmap)?;
+
+    return Ok(
+        ComparisonResult {
+            comparison_report,
+            output_file_path
+        }
+    )
+}
+
+//renders images comparison result
+fn render_comparison_report(comparison_result: &ComparisonResult) -> String {
+    let comparison_report = &comparison_result.comparison_report;
+
+    let mut result = format!(
+        "|-Images {}: {} vs {}\n|\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "comparison"
+        ),
+        comparison_report.first_image.file_name,
+        comparison_report.second_image.file_name
+    );
+
+    for (label, image) in [("First", &comparison_report.first_image), ("Second", &comparison_report.second_image)] {
+        result.push_str(
+            &format!(
+                "|-[{}]-> {} [{}x{}] [{:?}]\n",
+                font_and_style(
+                    FONT_STYLES.italic, 
+                    FONT_COLORS.f_cyan, 
+                    label
+                ),
+                image.file_name,
+                image.width,
+                image.height,
+                image.color_model
+            )
+        );
+    }
+
+    //dimensions and color models differences
+    let dimensions = if comparison_report.dimensions_match {
+        font_color(FONT_COLORS.f_green, "match")
+    } else {
+        format!(
+            "{} second image is resized to {}x{} for comparison",
+            full_style(
+                FONT_COLORS.f_white, 
+                BACKGROUND_COLORS.b_red, 
+                FONT_STYLES.bold, 
+                "Different!"
+            ),
+            comparison_report.first_image.width,
+            comparison_report.first_image.height
+        )
+    };
+
+    let color_models = if comparison_report.color_models_match {
+        font_color(FONT_COLORS.f_green, "match")
+    } else {
+        full_style(
+            FONT_COLORS.f_white, 
+            BACKGROUND_COLORS.b_red, 
+            FONT_STYLES.bold, 
+            "Different!"
+        )
+    };
+
+    let psnr = match comparison_report.psnr {
+        Some(psnr) => format!("{:.2} dB", psnr),
+        None => String::from("infinity (identical pixels)")
+    };
+
+    result.push_str(
+        &format!(
+            "|-Dimensions-> {}\n|-Color models-> {}\n|\n|-MSE-> {}\n|-PSNR-> {}\n|-SSIM-> {}\n|-Different pixels-> {} of {} ({} %)\n|\n",
+            dimensions,
+            color_models,
+            font_style(
+                FONT_STYLES.bold, 
+                &format!("{:.4}", comparison_report.mse)
+            ),
+            font_style(
+                FONT_STYLES.bold, 
+                &psnr
+            ),
+            font_style(
+                FONT_STYLES.bold, 
+                &format!("{:.4}", comparison_report.ssim)
+            ),
+            comparison_report.different_pixels,
+            comparison_report.pixels_count,
+            convert_to_percent(comparison_report.pixels_count, comparison_report.different_pixels)
+        )
+    );
+
+    //output file description
+    result.push_str(
+        &format!(
+            "|-[{}]-> See in path: {}\n|",
+
+            full_style(
+                FONT_COLORS.f_white, 
+                BACKGROUND_COLORS.b_light_yellow, 
+                FONT_STYLES.bold, 
+                "Output heatmap png file created!"
+            ),
+
+            full_style(
+                FONT_COLORS.f_light_white, 
+                BACKGROUND_COLORS.b_black, 
+                FONT_STYLES.italic,    
+                &comparison_result.output_file_path.to_string_lossy()
+            )
+        )
+    );
+
+    return result
+}
+
+//renders image location section (decoded Exif GPS entries)
+fn render_exif_location(location: Option<&GpsLocation>) -> String {
+    let mut result = format!(
+        "\n|-[{}]\n",
+        font_and_style(
+            FONT_STYLES.bold, 
+            FONT_COLORS.f_cyan, 
+            "Location"
+        )
+    );
+
+    let Some(location) = location else {
+        result.push_str(
+            &format!(
+                "|-[{}]-> Image doesn't contain GPS coordinates\n|",
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_gray, 
+                    FONT_STYLES.bold, 
+                    "No location embedded"
+                )
+            )
+        );
+
+        return result
+    };
+
+    result.push_str(
+        &format!(
+            "|-Latitude-> {}\n|-Longitude-> {}\n",
+            font_style(
+                FONT_STYLES.bold, 
+                &format!("{:.6}°", location.latitude)
+            ),
+            font_style(
+                FONT_STYLES.bold, 
+                &format!("{:.6}°", location.longitude)
+            )
+        )
+    );
+
+    if let Some(altitude) = location.altitude {
+        result.push_str(&format!("|-Altitude-> {:.1} m\n", altitude));
+    }
+
+    if let Some(timestamp) = &location.timestamp {
+        result.push_str(&format!("|-GPS timestamp-> {}\n", timestamp));
+    }
+
+    if let Some(direction) = location.direction {
+        let direction_ref = match &location.direction_ref {
+            Some(direction_ref) => format!(" ({})", direction_ref),
+            None => String::new()
+        };
+
+        result.push_str(&format!("|-Direction-> {:.1}°{}\n", direction, direction_ref));
+    }
+
+    result.push_str(
+        &format!(
+            "|-[{}]-> {}\n|",
+            font_and_style(
+                FONT_STYLES.italic, 
+                FONT_COLORS.f_cyan, 
+                "Geo URI"
+            ),
+            font_and_style(
+                FONT_STYLES.underline, 
+                FONT_COLORS.f_blue, 
+                &location.geo_uri
+            )
+        )
+    );
+
+    return result
+}
+
+//strips Exif metadata and saves stripped image copy to output file
+fn strip_exif_metadata_and_save(
+    path: String,
+    targets: &[ExifStripTarget]
+) -> std::result::Result<ExifStripResult, ImageManagerError> {
+    let (strip_report, image_data) = strip_exif_metadata(path, targets)?;
+    let output_file_path = save_stripped_image(&strip_report, &image_data)?;
+
+    return Ok(
+        ExifStripResult {
+            strip_report,
+            output_file_path
+        }
+    )
+}
+
+//renders removed Exif entries and stripped image copy path
+fn render_exif_strip_report(strip_result: &ExifStripResult) -> String {
+    let strip_report = &strip_result.strip_report;
+
+    let targets = if strip_report.targets.is_empty() {
+        String::from("all metadata")
+    } else {
+        strip_report.targets.iter().map(|target| target.name()).collect::<Vec<&str>>().join(", ")
+    };
+
+    let mut result = format!(
+        "|-Image {} metadata stripped: {}, type-> {}\n|-Stripped-> {}\n|===================================================|\n|\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "Exif"
+        ),
+        strip_report.file_name,
+        strip_report.file_extension,
+        font_style(
+            FONT_STYLES.bold, 
+            &targets
+        )
+    );
+
+    if strip_report.removed_entries.is_empty() {
+        result.push_str(
+            &format!(
+                "|-[{}]-> Image doesn't contain selected metadata\n",
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_green, 
+                    FONT_STYLES.bold, 
+                    "Nothing to strip"
+                )
+            )
+        );
+    } else {
+        result.push_str(
+            &format!(
+                "|-[{}]-> ({}) entries\n",
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_red, 
+                    "Removed"
+                ),
+                strip_report.removed_entries.len()
+            )
+        );
+
+        for (index, entry) in strip_report.removed_entries.iter().enumerate() {
+            result.push_str(
+                &format!(
+                    "|-[{}]->[{}]|{}|\t{}\n",
+                    index + 1,
+                    entry.ifd,
+                    entry.tag,
+                    entry.readable_value
+                )
+            );
+        }
+    }
+
+    //output file description
+    result.push_str(
+        &format!(
+            "|-Remaining entries-> {}\n|\n|-[{}]-> See in path: {}\n|",
+
+            strip_report.remaining_entries_count,
+
+            full_style(
+                FONT_COLORS.f_white, 
+                BACKGROUND_COLORS.b_light_yellow, 
+                FONT_STYLES.bold, 
+                "Stripped image copy created!"
+            ),
+
+            full_style(
+                FONT_COLORS.f_light_white, 
+                BACKGROUND_COLORS.b_black, 
+                FONT_STYLES.italic,    
+                &strip_result.output_file_path.to_string_lossy()
+            )
+        )
+    );
+
+    return result
+}
+
+//sets Exif tags and saves edited image (to image file or to copy in output directory)
+fn set_exif_tags_and_save(
+    path: String,
+    edits: &[(String, String)],
+    save_copy: bool
+) -> std::result::Result<ExifSetResult, ImageManagerError> {
+    let (set_report, image_data) = set_exif_tags(path.clone(), edits)?;
+
+    let output_file_path = if save_copy {
+        save_edited_image(&set_report, &image_data)?
+    } else {
+        overwrite_image_file(&path, &image_data)?
+    };
+
+    return Ok(
+        ExifSetResult {
+            set_report,
+            output_file_path,
+            in_place: !save_copy
+        }
+    )
+}
+
+//renders set Exif tags old and new values and edited image path
+fn render_exif_set_report(set_result: &ExifSetResult) -> String {
+    let set_report = &set_result.set_report;
+
+    let mut result = format!(
+        "|-Image {} tags set: {}, type-> {}, tags-> {}\n|===================================================|\n|\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "Exif"
+        ),
+        set_report.file_name,
+        set_report.file_extension,
+        set_report.changed_tags.len()
+    );
+
+    for (index, change) in set_report.changed_tags.iter().enumerate() {
+        let old_value = match &change.old_value {
+            Some(old_value) => font_style(FONT_STYLES.strike_through, old_value),
+            None => font_and_style(FONT_STYLES.italic, FONT_COLORS.f_gray, "(not set)")
+        };
+
+        result.push_str(
+            &format!(
+                "|-[{}]->|{}|\t{} -> {}\n",
+                index + 1,
+                change.tag,
+                old_value,
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_green, 
+                    &change.new_value
+                )
+            )
+        );
+    }
+
+    let output_description = if set_result.in_place {
+        "Image file updated!"
+    } else {
+        "Edited image copy created!"
+    };
+
+    //output file description
+    result.push_str(
+        &format!(
+            "|\n|-[{}]-> See in path: {}\n|",
+
+            full_style(
+                FONT_COLORS.f_white, 
+                BACKGROUND_COLORS.b_light_yellow, 
+                FONT_STYLES.bold, 
+                output_description
+            ),
+
+            full_style(
+                FONT_COLORS.f_light_white, 
+                BACKGROUND_COLORS.b_black, 
+                FONT_STYLES.italic,    
+                &set_result.output_file_path.to_string_lossy()
+            )
+        )
+    );
+
+    return result
+}
+
+//renders image XMP properties (namespace prefix, property, value) and IPTC-IIM datasets
+fn render_xmp_report(xmp_report: &XmpReport) -> String {
+    let mut result = format!(
+        "|-Image {} metadata fetched succesfully!\n|-Image filename-> {}, type-> {}, container-> {}\n|===================================================|\n|\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "XMP and IPTC"
+        ),
+        xmp_report.file_name,
+        xmp_report.file_extension,
+        xmp_report.container.name()
+    );
+
+    //metadata sections: (title, missing metadata description, rows: (label, name, value))
+    let sections = [
+        (
+            "XMP",
+            "Image doesn't contain XMP packet",
+            xmp_report.xmp_properties
+                .iter()
+                .map(|property| (property.namespace.clone(), property.property.as_str(), property.value.as_str()))
+                .collect::<Vec<_>>()
+        ),
+        (
+            "IPTC",
+            "Image doesn't contain IPTC-IIM block",
+            xmp_report.iptc_entries
+                .iter()
+                .map(|entry| (format!("{}:{}", entry.record, entry.dataset), entry.name.as_str(), entry.value.as_str()))
+                .collect::<Vec<_>>()
+        )
+    ];
+
+    for (title, missing_description, rows) in sections {
+        result.push_str(
+            &format!(
+                "|-[{}]-> ({}) entries\n",
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_cyan, 
+                    title
+                ),
+                rows.len()
+            )
+        );
+
+        if rows.is_empty() {
+            result.push_str(
+                &format!(
+                    "|-[{}]-> {}\n",
+                    full_style(
+                        FONT_COLORS.f_white, 
+                        BACKGROUND_COLORS.b_gray, 
+                        FONT_STYLES.bold, 
+                        &format!("No {} embedded", title)
+                    ),
+                    missing_description
+                )
+            );
+        }
+
+        for (index, (label, name, value)) in rows.iter().enumerate() {
+            result.push_str(
+                &format!(
+                    "|-[{}]->[{}]|{}|\t{}\n",
+                    index + 1,
+                    label,
+                    name,
+                    value
+                )
+            );
+        }
+
+        result.push_str("|\n");
+    }
+
+    result.pop();
+
+    return result
+}
+
+//converts image to target format and saves converted image file
+fn convert_image_and_save(
+    path: String,
+    target_format: ConversionFormat,
+    options: &ConversionOptions
+) -> std::result::Result<ConversionResult, ImageManagerError> {
+    let (conversion_report, image_data) = convert_image(path, target_format, options)?;
+    let output_file_path = save_converted_image(&conversion_report, &image_data)?;
+
+    return Ok(
+        ConversionResult {
+            conversion_report,
+            output_file_path
+        }
+    )
+}
+
+//renders converted image sizes (before and after conversion), quality, Exif state and converted image path
+fn render_conversion_report(conversion_result: &ConversionResult) -> String {
+    let conversion_report = &conversion_result.conversion_report;
+
+    //size change in percent of source size (negative -> converted image is smaller)
+    let size_change = (conversion_report.converted_size as f64 - conversion_report.source_size as f64) / conversion_report.source_size.max(1) as f64 * 100.0;
+
+    let size_change_color = if size_change <= 0.0 {
+        FONT_COLORS.f_green
+    } else {
+        FONT_COLORS.f_red
+    };
+
+    let exif_state = match (conversion_report.exif_preserved, conversion_report.source_has_exif) {
+        (true, _) => "preserved",
+        (false, true) => "stripped",
+        (false, false) => "source image has no Exif metadata"
+    };
+
+    let mut result = format!(
+        "|-Image {}: {} -> {}\n|-Image dimensions: ({}x{})px\n|-File size: {} -> {} ({})\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "converted"
+        ),
+        conversion_report.file_name,
+        font_style(
+            FONT_STYLES.bold, 
+            conversion_report.target_format.name()
+        ),
+        conversion_report.width,
+        conversion_report.height,
+        adapt_file_size(conversion_report.source_size as f64),
+        font_style(
+            FONT_STYLES.bold, 
+            &adapt_file_size(conversion_report.converted_size as f64)
+        ),
+        font_and_style(
+            FONT_STYLES.bold, 
+            size_change_color, 
+            &format!("{:+.1} %", size_change)
+        )
+    );
+
+    if let Some(quality) = conversion_report.quality {
+        result.push_str(&format!("|-Quality: {}\n", quality));
+    }
+
+    //output file description
+    result.push_str(
+        &format!(
+            "|-Exif metadata: {}\n|\n|-[{}]-> See in path: {}\n|",
+
+            exif_state,
+
+            full_style(
+                FONT_COLORS.f_white, 
+                BACKGROUND_COLORS.b_light_yellow, 
+                FONT_STYLES.bold, 
+                &format!("Output {} file created!", conversion_report.target_format.extension())
+            ),
+
+            full_style(
+                FONT_COLORS.f_light_white, 
+                BACKGROUND_COLORS.b_black, 
+                FONT_STYLES.italic,    
+                &conversion_result.output_file_path.to_string_lossy()
+            )
+        )
+    );
+
+    return result
+}
+
+//renders batch conversion summary: images counts, sizes before and after conversion, saved size and failed images
+fn render_batch_report(batch_report: &BatchReport) -> String {
+    let target_format = batch_report.target_format.map_or("original formats", |format| format.name());
+
+    let mut result = format!(
+        "|-Images {}: ({}) -> {}\n|-From: {}\n|-To: {}\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "batch converted"
+        ),
+        batch_report.images_count,
+        font_style(
+            FONT_STYLES.bold, 
+            target_format
+        ),
+        batch_report.input_dir.to_string_lossy(),
+        batch_report.output_dir.to_string_lossy()
+    );
+
+    if let Some(resize) = &batch_report.resize {
+        result.push_str(
+            &format!(
+                "|-Resize: ({}x{})px, mode-> {}, filter-> {}\n",
+                resize.width,
+                resize.height,
+                resize.mode.name(),
+                resize.filter.name()
+            )
+        );
+    }
+
+    //processing results table
+    let statuses = [
+        ("Converted", batch_report.converted_count, BACKGROUND_COLORS.b_green),
+        ("Skipped (up to date)", batch_report.skipped_count, BACKGROUND_COLORS.b_light_blue),
+        ("Failed", batch_report.failed_count, BACKGROUND_COLORS.b_red)
+    ];
+
+    result.push_str("|\n");
+
+    for (status_name, count, color) in statuses {
+        result.push_str(
+            &format!(
+                "|-[{}]-> {}\n",
+                full_style(
+                    FONT_COLORS.f_white, 
+                    color, 
+                    FONT_STYLES.bold, 
+                    status_name
+                ),
+                count
+            )
+        );
+    }
+
+    //saved size in percent of converted images source size
+    if batch_report.converted_count > 0 {
+        let saved_percent = batch_report.saved_size as f64 / batch_report.source_size.max(1) as f64 * 100.0;
+
+        let (saved_label, saved_color) = if batch_report.saved_size >= 0 {
+            ("Saved", FONT_COLORS.f_green)
+        } else {
+            ("Added", FONT_COLORS.f_red)
+        };
+
+        result.push_str(
+            &format!(
+                "|\n|-Size: {} -> {}\n|-{}: {} ({:.1} %)\n",
+                adapt_file_size(batch_report.source_size as f64),
+                font_style(
+                    FONT_STYLES.bold, 
+                    &adapt_file_size(batch_report.output_size as f64)
+                ),
+                saved_label,
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    saved_color, 
+                    &adapt_file_size(batch_report.saved_size.unsigned_abs() as f64)
+                ),
+                saved_percent.abs()
+            )
+        );
+    }
+
+    //failed images with errors
+    let failed_files: Vec<&BatchFileInfo> = batch_report.files
+        .iter()
+        .filter(|file| file.status == BatchFileStatus::Failed)
+        .collect();
+
+    if !failed_files.is_empty() {
+        result.push_str("|\n");
+    }
+
+    for file in failed_files {
+        result.push_str(
+            &format!(
+                "|-[{}] {}\n|  {}\n",
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_red, 
+                    "x"
+                ),
+                file.source_path.to_string_lossy(),
+                font_style(
+                    FONT_STYLES.dim, 
+                    file.error.as_deref().unwrap_or_default()
+                )
+            )
+        );
+    }
+
+    result.push('|');
+
+    return result
+}
+
+//renders contact sheet result: grid size, placed and skipped images, output file
+fn render_contact_sheet_report(contact_sheet_result: &ContactSheetResult) -> String {
+    let contact_sheet_report = &contact_sheet_result.contact_sheet_report;
+
+    let mut result = format!(
+        "|-Contact sheet {}: {}\n|-Images: ({}) in ({}) columns x ({}) rows, thumbnails ({}x{})px\n|-Sheet dimensions: ({}x{})px\n",
+        font_style(
+            FONT_STYLES.bold, 
+            "built"
+        ),
+        contact_sheet_report.directory.to_string_lossy(),
+        contact_sheet_report.tiles.len(),
+        contact_sheet_report.columns,
+        contact_sheet_report.rows,
+        contact_sheet_report.thumbnail_size,
+        contact_sheet_report.thumbnail_size,
+        contact_sheet_report.width,
+        contact_sheet_report.height
+    );
+
+    //images, which didn't fit in sheet images limit or pixel budget
+    let not_placed_count = contact_sheet_report.images_count - (contact_sheet_report.tiles.len() + contact_sheet_report.skipped_files.len()) as u64;
+
+    if not_placed_count > 0 {
+        result.push_str(
+            &format!(
+                "|-[{}]-> ({}) images aren't placed, max images count is {}, max sheet size is {} megapixels\n",
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_yellow, 
+                    "Warning"
+                ),
+                not_placed_count,
+                MAX_SHEET_IMAGES,
+                MAX_SHEET_PIXELS / 1_000_000
+            )
+        );
+    }
+
+    //images, which couldn't be decoded
+    if !contact_sheet_report.skipped_files.is_empty() {
+        result.push_str(&format!("|\n|-Skipped images (couldn't be decoded): ({})\n", contact_sheet_report.skipped_files.len()));
+    }
+
+    for file in &contact_sheet_report.skipped_files {
+        result.push_str(
+            &format!(
+                "|  {}\n",
+                font_style(
+                    FONT_STYLES.dim, 
+                    &file.to_string_lossy()
+                )
+            )
+        );
+    }
+
+    //output file description
+    result.push_str(
+        &format!(
+            "|\n|-[{}]-> See in path: {}\n|",
+
+            full_style(
+                FONT_COLORS.f_white, 
+                BACKGROUND_COLORS.b_light_yellow, 
+                FONT_STYLES.bold, 
+                "Output png file created!"
+            ),
+
+            full_style(
+                FONT_COLORS.f_light_white, 
+                BACKGROUND_COLORS.b_black, 
+                FONT_STYLES.italic,    
+                &contact_sheet_result.output_file_path.to_string_lossy()
+            )
+        )
+    );
+
+    return result
+}
+
+//renders image_manager error
+fn render_image_manager_error(error: &ImageManagerError) -> String {
+    let error_label = full_style(
+        FONT_COLORS.f_white, 
+        BACKGROUND_COLORS.b_red, 
+        FONT_STYLES.bold, 
+        "Error!"
+    );
+
+    let result = match error {
+        ImageManagerError::NotImage { extension } => format!(
+            "|[{}]->({})->This is not image!",
+            error_label,
+            font_style(
+                FONT_STYLES.underline, 
+                extension
+            )
+        ),
+
+        ImageManagerError::ImageOpen { path, error } => format!(
+            "|-[{}] Image from path [{}] couldn't open.\n|-{}\n|", 
+            error_label,
+            background_color(
+                BACKGROUND_COLORS.b_light_green, 
+                path
+            ),
+            error
+        ),
+
+        ImageManagerError::ExifNotSupported { extension } => format!(
+            "|-Sorry, this image format-> |{}| not support {} metadata.\n|-Supporting formats -> [{}]\n|",
+            extension,
+
+            font_style(
+                FONT_STYLES.bold, 
+                "Exif"
+            ),
+
+            full_style(
+                FONT_COLORS.f_black, 
+                BACKGROUND_COLORS.b_light_blue, 
+                FONT_STYLES.bold, 
+                "JPEG, JPG, TIFF, HEIF, WEBP"
+            )
+        ),
+
+        ImageManagerError::ExifParse(error) => format!(
+            "|-Image {} Exif metadata not fetched!\n|-[{}]->{}\n|",
+
+            font_style(
+                FONT_STYLES.bold,
+                "Exif"
+            ),
+            error_label,
+            error
+        ),
+
+        ImageManagerError::ExifWriteNotSupported { extension } => format!(
+            "|-Sorry, {} metadata can't be written to this image format-> |{}|\n|-Supporting formats -> [{}]\n|",
+            font_style(
+                FONT_STYLES.bold, 
+                "Exif"
+            ),
+
+            extension,
+
+            full_style(
+                FONT_COLORS.f_black, 
+                BACKGROUND_COLORS.b_light_blue, 
+                FONT_STYLES.bold, 
+                "JPEG, JPG, TIFF, WEBP"
+            )
+        ),
+
+        ImageManagerError::ExifTagNotEditable { tag } => format!(
+            "|-[{}]->{} tag ({}) can't be set.\n|-Editable tags -> [{}]\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.bold, 
+                "Exif"
+            ),
+            font_style(
+                FONT_STYLES.underline, 
+                tag
+            ),
+            full_style(
+                FONT_COLORS.f_black, 
+                BACKGROUND_COLORS.b_light_blue, 
+                FONT_STYLES.bold, 
+                &EditableExifTag::ALL.map(|tag| tag.name()).join(", ")
+            )
+        ),
+
+        ImageManagerError::InvalidExifValue { tag, value, expected } => format!(
+            "|-[{}]->Invalid value [{}] of {} tag ({}).\n|-Expected -> {}\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.underline, 
+                value
+            ),
+            font_style(
+                FONT_STYLES.bold, 
+                "Exif"
+            ),
+            tag,
+            expected
+        ),
+
+        ImageManagerError::ExifWrite { reason } => format!(
+            "|-[{}]->{} metadata couldn't be written.\n|-{}\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.bold, 
+                "Exif"
+            ),
+            reason
+        ),
+
+        ImageManagerError::MetadataNotSupported { extension } => format!(
+            "|-Sorry, this image format-> |{}| not support {} metadata.\n|-Supporting formats -> [{}]\n|",
+            extension,
+
+            font_style(
+                FONT_STYLES.bold, 
+                "XMP and IPTC"
+            ),
+
+            full_style(
+                FONT_COLORS.f_black, 
+                BACKGROUND_COLORS.b_light_blue, 
+                FONT_STYLES.bold, 
+                "JPEG, JPG, PNG, TIFF, WEBP"
+            )
+        ),
+
+        ImageManagerError::XmpParse { reason } => format!(
+            "|-Image {} packet not parsed!\n|-[{}]->{}\n|",
+            font_style(
+                FONT_STYLES.bold, 
+                "XMP"
+            ),
+            error_label,
+            reason
+        ),
+
+        ImageManagerError::IccProfileNotFound { file_name } => format!(
+            "|-Image [{}] has no valid embedded {}, nothing to save.\n|",
+            file_name,
+            font_style(
+                FONT_STYLES.bold, 
+                "ICC color profile"
+            )
+        ),
+
+        ImageManagerError::NotAnimated { file_name } => format!(
+            "|-Image [{}] isn't {}, it has no frames to extract.\n|-Supporting formats -> [{}]\n|",
+            file_name,
+            font_style(
+                FONT_STYLES.bold, 
+                "animated"
+            ),
+            full_style(
+                FONT_COLORS.f_black, 
+                BACKGROUND_COLORS.b_light_blue, 
+                FONT_STYLES.bold, 
+                "GIF, APNG, WEBP"
+            )
+        ),
+
+        ImageManagerError::FrameOutOfRange { frame, frames_count } => format!(
+            "|-[{}]->Frame {} is out of range, animation has {} frames.\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.bold, 
+                &frame.to_string()
+            ),
+            frames_count
+        ),
+
+        ImageManagerError::ImageEncode { format, error } => format!(
+            "|-[{}]->Image couldn't be converted to {}.\n|-{}\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.bold, 
+                format
+            ),
+            error
+        ),
+
+        ImageManagerError::ExifKeepNotSupported { format } => format!(
+            "|-Sorry, {} metadata can't be kept in converted image format-> |{}|\n|-Supporting formats -> [{}]\n|",
+            font_style(
+                FONT_STYLES.bold, 
+                "Exif"
+            ),
+            format,
+            full_style(
+                FONT_COLORS.f_black, 
+                BACKGROUND_COLORS.b_light_blue, 
+                FONT_STYLES.bold, 
+                "JPEG, PNG, WEBP"
+            )
+        ),
+
+        ImageManagerError::OrientationNotSupported { extension } => format!(
+            "|-Sorry, {} can't be applied to image format-> |{}|\n|-Supporting formats -> [{}]\n|",
+            font_style(
+                FONT_STYLES.bold, 
+                "Exif orientation"
+            ),
+            extension,
+            full_style(
+                FONT_COLORS.f_black, 
+                BACKGROUND_COLORS.b_light_blue, 
+                FONT_STYLES.bold, 
+                "JPEG, PNG, WEBP"
+            )
+        ),
+
+        ImageManagerError::BatchOutputDir { path } => format!(
+            "|-[{}]->Output directory [{}] is input directory.\n|-Converted images would overwrite source images, choose other output directory.\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.underline, 
+                &path.to_string_lossy()
+            )
+        ),
+
+        ImageManagerError::NoImagesFound { path } => format!(
+            "|-[{}]->Directory [{}] has no images, which can be decoded.\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.underline, 
+                &path.to_string_lossy()
+            )
+        ),
+
+        ImageManagerError::ContactSheetTooBig { max_pixels } => format!(
+            "|-[{}]->Contact sheet row is bigger than {} pixels.\n|-Reduce columns count or thumbnails size.\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.bold, 
+                &max_pixels.to_string()
+            )
+        ),
+
+        ImageManagerError::OutputFile(error) => format!(
+            "|-[{}]->Output file couldn't be written.\n|-{}\n|",
+            error_label,
+            error
+        ),
+
+        ImageManagerError::ScanRoot { path, error } => format!(
+            "|-[{}]->Directory [{}] couldn't be scanned.\n|-{}\n|",
+            error_label,
+            font_style(
+                FONT_STYLES.underline, 
+                &path.to_string_lossy()
+            ),
+            error
+        )
+    };
+
+    return result
+}
+
+//renders command result as JSON document (Ok -> result document, Err -> error document)
+fn render_json_document<T: Serialize>(
+    command: &str,
+    result: &std::result::Result<T, ImageManagerError>
+) -> std::result::Result<String, String> {
+    let document = match result {
+        Ok(report) => json!({
+            "schema_version": JSON_SCHEMA_VERSION,
+            "command": command,
+            "status": "ok",
+            "result": report
+        }),
+        Err(error) => json!({
+            "schema_version": JSON_SCHEMA_VERSION,
+            "command": command,
+            "status": "error",
+            "error": {
+                "kind": error.kind(),
+                "message": error.to_string()
+            }
+        })
+    };
+
+    let text = serde_json::to_string_pretty(&document).expect("Failed to serialize JSON document!");
+
+    if result.is_ok() {
+        return Ok(text)
+    } else {
+        return Err(text)
+    }
+}
+
+//applies Exif orientation to image pixels and saves auto-rotated image copy (image with normal orientation isn't saved)
+fn auto_rotate_image_and_save(path: String) -> std::result::Result<AutoRotateResult, ImageManagerError> {
+    let (auto_rotate_report, image_data) = auto_rotate_image(path)?;
+
+    let output_file_path = match image_data {
+        Some(image_data) => Some(save_autorotated_image(&auto_rotate_report, &image_data)?),
+        None => None
+    };
+
+    return Ok(
+        AutoRotateResult {
+            auto_rotate_report,
+            output_file_path
+        }
+    )
+}
+
+//renders image Exif orientation, stored and auto-rotated dimensions and auto-rotated image path
+fn render_auto_rotate_report(auto_rotate_result: &AutoRotateResult) -> String {
+    let auto_rotate_report = &auto_rotate_result.auto_rotate_report;
+
+    let orientation = match auto_rotate_report.orientation {
+        Some(orientation) => format!("{} ({})", orientation, auto_rotate_report.transformation),
+        None => "no Exif Orientation tag (normal)".to_string()
+    };
+
+    let mut result = format!(
+        "|-Image file name: {}\n|-Exif orientation: {}\n|-Stored dimensions: ({}x{})px\n|-Displayed dimensions: {}\n|\n",
+        auto_rotate_report.file_name,
+        font_style(
+            FONT_STYLES.bold, 
+            &orientation
+        ),
+        auto_rotate_report.stored_width,
+        auto_rotate_report.stored_height,
+        font_style(
+            FONT_STYLES.bold, 
+            &format!("({}x{})px", auto_rotate_report.width, auto_rotate_report.height)
+        )
+    );
+
+    //output file description
+    match &auto_rotate_result.output_file_path {
+        Some(output_file_path) => result.push_str(
+            &format!(
+                "|-Image pixels {}, Exif orientation is reset to 1 (normal)\n|\n|-[{}]-> See in path: {}\n|",
+
+                font_style(
+                    FONT_STYLES.bold, 
+                    &auto_rotate_report.transformation
+                ),
+
+                full_style(
+                    FONT_COLORS.f_white, 
+                    BACKGROUND_COLORS.b_light_yellow, 
+                    FONT_STYLES.bold, 
+                    &format!("Output {} file created!", auto_rotate_report.file_extension)
+                ),
+
+                full_style(
+                    FONT_COLORS.f_light_white, 
+                    BACKGROUND_COLORS.b_black, 
+                    FONT_STYLES.italic,    
+                    &output_file_path.to_string_lossy()
+                )
+            )
+        ),
+        None => result.push_str(
+            &format!(
+                "|-[{}]-> Image is already displayed as stored, output file isn't created.\n|",
+                font_and_style(
+                    FONT_STYLES.bold, 
+                    FONT_COLORS.f_green, 
+                    "Nothing to rotate"
+                )
+            )
+        )
+    }
+
+    return result
+}
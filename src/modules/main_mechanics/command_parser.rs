@@ -4,54 +4,400 @@
 | {Functions:}                                                 |
 | 1) parse_command() - parses commands from user, uses regexes |
 | patterns (Regex library)                                     |
+| 2) parse_command_line_arguments() - runs one command from    |
+| command-line arguments, returns process exit code            |
+| 3) parse_output_format() - parses output format name         |
+| 4) captured_output_format() - gets output format from command|
+| ":json" suffix (gii:json=(path))                             |
+| 5) split_parameters() - splits command parameters by ";"     |
+| 6) parse_palette_parameters() - parses palette command       |
+| parameters (path; colors count)                              |
+| 7) parse_hist_parameters() - parses hist command parameters  |
+| (path; csv)                                                  |
+| 8) parse_is_parameters() - parses is command parameters      |
+| (types; dirs; depth=N; symlinks; hidden)                     |
+| 9) expand_home_dir() - expands "~" in path to home directory |
+| 10) parse_scan_options() - parses scan directories and scan  |
+| options (dirs; depth=N; symlinks; hidden)                    |
+| 11) parse_dups_parameters() - parses dups command parameters |
+| 12) parse_similar_parameters() - parses similar command      |
+| parameters (dirs; threshold=N; options)                      |
+| 13) parse_strip_parameters() - parses strip command          |
+| parameters (path; gps; serial)                               |
+| 14) parse_exif_set_parameters() - parses exif-set command    |
+| parameters (path; Tag=Value; ...; copy)                      |
+| 15) parse_fem_parameters() - parses fem command parameters   |
+| (path; txt|json|csv|xmp; dir=DIR; name=TEMPLATE)             |
+| 16) parse_gii_parameters() - parses gii command parameters   |
+| (path; icc; frames=SELECTION)                                |
+| 17) parse_frame_selection() - parses animation frames        |
+| selection (all or 1,3,5-8)                                   |
+| 18) parse_conv_parameters() - parses conv command parameters |
+| (path; format; quality=N; exif=keep|strip)                   |
+| 19) parse_batch_parameters() - parses batch command          |
+| parameters (input dir; output dir; options)                  |
+| 20) parse_resize_size() - parses resize size (800x600)       |
+| 21) parse_sheet_parameters() - parses sheet command          |
+| parameters (dir; columns; thumbnail size; options)           |
 ================================================================
 */
 
 use std::thread;
 use std::process;
+use std::path::PathBuf;
 use std::time::Duration;
 
-use regex::Regex;
+use regex::{ Captures, Regex };
+
+use img_info::image_analysis::image_manager::ScanOptions;
+use img_info::image_analysis::exif_writer::ExifStripTarget;
+use img_info::image_analysis::animation_reader::FrameSelection;
+use img_info::image_analysis::image_converter::{ ConversionFormat, ConversionOptions };
+use img_info::image_analysis::batch_converter::{ BatchOptions, ResizeFilter, ResizeMode, ResizeOptions };
+use img_info::image_analysis::contact_sheet_builder::ContactSheetOptions;
 
 use crate::modules::app_design_managment::screens_manager::*;
+use crate::modules::main_mechanics::output_files_manager::{ ExifExportFormat, ExifExportOptions };
+
+//palette command default colors count and max colors count
+const DEFAULT_PALETTE_COLORS_COUNT: usize = 8;
+const MAX_PALETTE_COLORS_COUNT: usize = 64;
+
+//similar command default hashes distance threshold and max threshold (64-bit hashes)
+const DEFAULT_SIMILARITY_THRESHOLD: u32 = 10;
+const MAX_SIMILARITY_THRESHOLD: u32 = 64;
+
+//exif-set command parameters: image path, tags values (tag name, value) and copy flag
+type ExifSetParameters = (String, Vec<(String, String)>, bool);
+
+//gii command parameters: image path, save ICC profile flag and animation frames to extract
+type GiiParameters = (String, bool, Option<FrameSelection>);
+
+//conv command parameters: image path, target format and conversion options
+type ConvParameters = (String, ConversionFormat, ConversionOptions);
+
+//batch command parameters: input directory, output directory, batch options and scan options
+type BatchParameters = (PathBuf, PathBuf, BatchOptions, ScanOptions);
+
+//max batch resize width and height
+const MAX_RESIZE_SIZE: u32 = 16384;
+
+//sheet command default and max columns count, default, min and max thumbnails size
+const DEFAULT_SHEET_COLUMNS: u32 = 6;
+const MAX_SHEET_COLUMNS: u32 = 32;
+const DEFAULT_SHEET_THUMBNAIL_SIZE: u32 = 160;
+const MIN_SHEET_THUMBNAIL_SIZE: u32 = 32;
+const MAX_SHEET_THUMBNAIL_SIZE: u32 = 1024;
+
+//sheet command parameters: directory, contact sheet options and scan options
+type SheetParameters = (PathBuf, ContactSheetOptions, ScanOptions);
+
+//max animation frame number in frames selection (protects from huge ranges: frames=1-999999999)
+const MAX_SELECTED_FRAME_NUMBER: usize = 100_000;
+
+//command-line mode process exit codes
+pub const EXIT_SUCCESS: i32 = 0;
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_USAGE_ERROR: i32 = 2;
 
 pub fn parse_command(input: &str) {
     let input_command = input.trim(); //trim input
 
     //commands (used regexes for better commands syntax and functionality)
-    let gii_command_regex = Regex::new(r"^gii=\(([^()]+)\)$").unwrap();
-    let fem_command_regex = Regex::new(r"^fem=\(([^()]+)\)$").unwrap();
-    let is_command_regex = Regex::new(r"^is=\(([^()]+)\)$").unwrap();
+    //optional ":json" suffix switches output to JSON document (gii:json=(path))
+    let gii_command_regex = Regex::new(r"^gii(:json)?=\(([^()]+)\)$").unwrap();
+    let fem_command_regex = Regex::new(r"^fem(:json)?=\(([^()]+)\)$").unwrap();
+    let is_command_regex = Regex::new(r"^is(:json)?=\(([^()]+)\)$").unwrap();
+    let palette_command_regex = Regex::new(r"^palette(:json)?=\(([^()]+)\)$").unwrap();
+    let hist_command_regex = Regex::new(r"^hist(:json)?=\(([^()]+)\)$").unwrap();
+    let dups_command_regex = Regex::new(r"^dups(:json)?=\(([^()]+)\)$").unwrap();
+    let similar_command_regex = Regex::new(r"^similar(:json)?=\(([^()]+)\)$").unwrap();
+    let cmp_command_regex = Regex::new(r"^cmp(:json)?=\(([^()]+)\)$").unwrap();
+    let strip_command_regex = Regex::new(r"^strip(:json)?=\(([^()]+)\)$").unwrap();
+    let exif_set_command_regex = Regex::new(r"^exif-set(:json)?=\((.+)\)$").unwrap(); //tags values can contain brackets: (c)
+    let xmp_command_regex = Regex::new(r"^xmp(:json)?=\(([^()]+)\)$").unwrap();
+    let conv_command_regex = Regex::new(r"^conv(:json)?=\(([^()]+)\)$").unwrap();
+    let batch_command_regex = Regex::new(r"^batch(:json)?=\(([^()]+)\)$").unwrap();
+    let sheet_command_regex = Regex::new(r"^sheet(:json)?=\(([^()]+)\)$").unwrap();
+    let autorotate_command_regex = Regex::new(r"^autorotate(:json)?=\(([^()]+)\)$").unwrap();
 
     //processing commands
     if let Some(text) = gii_command_regex.captures(input_command) {
-        let path = &text[1]; //getting value from () command
+        //getting values from () command (path; icc; frames=SELECTION)
+        match parse_gii_parameters(&split_parameters(&text[2])) {
+            Some((path, save_icc_profile, frames_selection)) => {
+                //Launch function
+                draw_image_info_screen(
+                    path,
+                    save_icc_profile,
+                    frames_selection,
+                    "Get Image Info",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
 
-        //Launch function
-        draw_image_info_screen(
-            path.to_string(),
-            "Get Image Info"
-        );
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
     } else if let Some(text) = fem_command_regex.captures(input_command) {
-        let path = &text[1]; //getting value from () command
-        
+        //getting values from () command (path; export format; dir=DIR; name=TEMPLATE)
+        match parse_fem_parameters(&split_parameters(&text[2])) {
+            Some((path, export_options)) => {
+                //Launch function
+                draw_image_exif_metadata_screen(
+                    path, 
+                    export_options,
+                    "Get image Exif metadata",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = is_command_regex.captures(input_command) {
+        //getting values from () command (type; dirs; options)
+        match parse_is_parameters(&split_parameters(&text[2])) {
+            Some((extensions, scan_options)) => {
+                //Launch function
+                draw_all_images_sizes_and_info( 
+                    extensions, 
+                    scan_options,
+                    "Get All Images Sizes And Info",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = palette_command_regex.captures(input_command) {
+        //getting values from () command (path; colors count)
+        match parse_palette_parameters(&split_parameters(&text[2])) {
+            Some((path, colors_count)) => {
+                //Launch function
+                draw_image_palette_screen(
+                    path,
+                    colors_count,
+                    "Get Image Palette",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = hist_command_regex.captures(input_command) {
+        //getting values from () command (path; csv)
+        match parse_hist_parameters(&split_parameters(&text[2])) {
+            Some((path, export_csv)) => {
+                //Launch function
+                draw_image_histogram_screen(
+                    path,
+                    export_csv,
+                    "Get Image Histogram",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = dups_command_regex.captures(input_command) {
+        //getting values from () command (dirs; options)
+        match parse_dups_parameters(&split_parameters(&text[2])) {
+            Some(scan_options) => {
+                //Launch function
+                draw_duplicate_images_screen(
+                    scan_options,
+                    "Find Duplicate Images",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = similar_command_regex.captures(input_command) {
+        //getting values from () command (dirs; threshold=N; options)
+        match parse_similar_parameters(&split_parameters(&text[2])) {
+            Some((scan_options, threshold)) => {
+                //Launch function
+                draw_similar_images_screen(
+                    scan_options,
+                    threshold,
+                    "Find Similar Images",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = cmp_command_regex.captures(input_command) {
+        //getting values from () command (first path; second path)
+        match split_parameters(&text[2]).as_slice() {
+            [first_path, second_path] => {
+                //Launch function
+                draw_image_comparison_screen(
+                    first_path.clone(),
+                    second_path.clone(),
+                    "Compare Images",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            _ => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = strip_command_regex.captures(input_command) {
+        //getting values from () command (path; gps; serial)
+        match parse_strip_parameters(&split_parameters(&text[2])) {
+            Some((path, targets)) => {
+                //Launch function
+                draw_exif_strip_screen(
+                    path,
+                    targets,
+                    "Strip Exif Metadata",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = exif_set_command_regex.captures(input_command) {
+        //getting values from () command (path; Tag=Value; ...; copy)
+        match parse_exif_set_parameters(&split_parameters(&text[2])) {
+            Some((path, edits, save_copy)) => {
+                //Launch function
+                draw_exif_set_screen(
+                    path,
+                    edits,
+                    save_copy,
+                    "Set Exif Tags",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = xmp_command_regex.captures(input_command) {
+        let path = &text[2]; //getting value from () command
+
         //Launch function
-        draw_image_exif_metadata_screen(
-            path.to_string(), 
-            "Get image Exif metadata"
+        draw_xmp_metadata_screen(
+            path.to_string(),
+            "Get image XMP metadata",
+            true,
+            captured_output_format(&text)
         );
-    } else if let Some(text) = is_command_regex.captures(input_command) {
-        let path = &text[1]; //getting value from () command
-           
+    } else if let Some(text) = conv_command_regex.captures(input_command) {
+        //getting values from () command (path; format; options)
+        match parse_conv_parameters(&split_parameters(&text[2])) {
+            Some((path, target_format, options)) => {
+                //Launch function
+                draw_image_conversion_screen(
+                    path,
+                    target_format,
+                    options,
+                    "Convert Image",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = batch_command_regex.captures(input_command) {
+        //getting values from () command (input dir; output dir; options)
+        match parse_batch_parameters(&split_parameters(&text[2])) {
+            Some((input_dir, output_dir, options, scan_options)) => {
+                //Launch function
+                draw_batch_conversion_screen(
+                    input_dir,
+                    output_dir,
+                    options,
+                    scan_options,
+                    "Batch Convert Images",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = sheet_command_regex.captures(input_command) {
+        //getting values from () command (dir; columns; thumbnail size; options)
+        match parse_sheet_parameters(&split_parameters(&text[2])) {
+            Some((directory, options, scan_options)) => {
+                //Launch function
+                draw_contact_sheet_screen(
+                    directory,
+                    options,
+                    scan_options,
+                    "Build Contact Sheet",
+                    true,
+                    captured_output_format(&text)
+                );
+            }
+
+            None => {
+                draw_command_error_message(input_command.to_string());
+                draw_main_screen();
+            }
+        }
+    } else if let Some(text) = autorotate_command_regex.captures(input_command) {
+        let path = &text[2]; //getting value from () command
+
         //Launch function
-        draw_all_images_sizes_and_info( 
-            path.to_string(), 
-            "test"
+        draw_autorotate_screen(
+            path.to_string(),
+            "Auto-rotate Image",
+            true,
+            captured_output_format(&text)
         );
     } else {
         //processing other commands (without regexes)
         if input_command == "help" {
-            draw_help_screen();
+            draw_help_screen(true);
         } else if input_command == "exit" {
             draw_bye_message();
 
@@ -70,4 +416,559 @@ pub fn parse_command(input: &str) {
             }    
         }
     }
-}
\ No newline at end of file
+}
+
+//runs one command from command-line arguments (img_info gii photo.jpg), returns process exit code
+pub fn parse_command_line_arguments(arguments: &[String]) -> i32 {
+    let mut format = OutputFormat::Text;
+    let mut command_arguments = Vec::new();
+
+    //extracting output format option (--format json, --format=json) from arguments
+    let mut arguments_iter = arguments.iter();
+
+    while let Some(argument) = arguments_iter.next() {
+        let format_name = if argument == "--format" {
+            arguments_iter.next().map(|name| name.as_str())
+        } else if let Some(name) = argument.strip_prefix("--format=") {
+            Some(name)
+        } else {
+            command_arguments.push(argument.clone());
+            continue
+        };
+
+        match format_name.and_then(parse_output_format) {
+            Some(output_format) => format = output_format,
+            None => {
+                draw_command_line_usage_message();
+
+                return EXIT_USAGE_ERROR
+            }
+        }
+    }
+
+    let command = command_arguments.first().map(|command| command.as_str()).unwrap_or("");
+    let parameters = command_arguments.get(1..).unwrap_or(&[]);
+
+    //None -> invalid command or parameters
+    let is_succeeded = match (command, parameters) {
+        ("gii", _) => parse_gii_parameters(parameters).map(|(path, save_icc_profile, frames_selection)| {
+            draw_image_info_screen(path, save_icc_profile, frames_selection, "Get Image Info", false, format)
+        }),
+        ("fem", _) => parse_fem_parameters(parameters).map(|(path, export_options)| {
+            draw_image_exif_metadata_screen(path, export_options, "Get image Exif metadata", false, format)
+        }),
+        ("is", _) => parse_is_parameters(parameters).map(|(extensions, scan_options)| {
+            draw_all_images_sizes_and_info(extensions, scan_options, "Get All Images Sizes And Info", false, format)
+        }),
+        ("palette", _) => parse_palette_parameters(parameters).map(|(path, colors_count)| {
+            draw_image_palette_screen(path, colors_count, "Get Image Palette", false, format)
+        }),
+        ("hist", _) => parse_hist_parameters(parameters).map(|(path, export_csv)| {
+            draw_image_histogram_screen(path, export_csv, "Get Image Histogram", false, format)
+        }),
+        ("dups", _) => parse_dups_parameters(parameters).map(|scan_options| {
+            draw_duplicate_images_screen(scan_options, "Find Duplicate Images", false, format)
+        }),
+        ("similar", _) => parse_similar_parameters(parameters).map(|(scan_options, threshold)| {
+            draw_similar_images_screen(scan_options, threshold, "Find Similar Images", false, format)
+        }),
+        ("cmp", [first_path, second_path]) => Some(
+            draw_image_comparison_screen(first_path.clone(), second_path.clone(), "Compare Images", false, format)
+        ),
+        ("strip", _) => parse_strip_parameters(parameters).map(|(path, targets)| {
+            draw_exif_strip_screen(path, targets, "Strip Exif Metadata", false, format)
+        }),
+        ("exif-set", _) => parse_exif_set_parameters(parameters).map(|(path, edits, save_copy)| {
+            draw_exif_set_screen(path, edits, save_copy, "Set Exif Tags", false, format)
+        }),
+        ("xmp", [path]) => Some(draw_xmp_metadata_screen(path.clone(), "Get image XMP metadata", false, format)),
+        ("conv", _) => parse_conv_parameters(parameters).map(|(path, target_format, options)| {
+            draw_image_conversion_screen(path, target_format, options, "Convert Image", false, format)
+        }),
+        ("batch", _) => parse_batch_parameters(parameters).map(|(input_dir, output_dir, options, scan_options)| {
+            draw_batch_conversion_screen(input_dir, output_dir, options, scan_options, "Batch Convert Images", false, format)
+        }),
+        ("sheet", _) => parse_sheet_parameters(parameters).map(|(directory, options, scan_options)| {
+            draw_contact_sheet_screen(directory, options, scan_options, "Build Contact Sheet", false, format)
+        }),
+        ("autorotate", [path]) => Some(draw_autorotate_screen(path.clone(), "Auto-rotate Image", false, format)),
+        ("help", []) => {
+            draw_help_screen(false);
+            Some(true)
+        },
+        _ => None
+    };
+
+    let Some(is_succeeded) = is_succeeded else {
+        draw_command_line_usage_message();
+
+        return EXIT_USAGE_ERROR
+    };
+
+    if is_succeeded {
+        return EXIT_SUCCESS
+    } else {
+        return EXIT_FAILURE
+    }
+}
+
+//parses output format name (text, json)
+fn parse_output_format(name: &str) -> Option<OutputFormat> {
+    match name {
+        "text" => Some(OutputFormat::Text),
+        "json" => Some(OutputFormat::Json),
+        _ => None
+    }
+}
+
+//gets output format from captured command ":json" suffix (first regex group)
+fn captured_output_format(captures: &Captures) -> OutputFormat {
+    if captures.get(1).is_some() {
+        return OutputFormat::Json
+    } else {
+        return OutputFormat::Text
+    }
+}
+
+//splits command parameters by ";" (palette=(path; 8) -> [path, 8])
+fn split_parameters(text: &str) -> Vec<String> {
+    return text.split(';').map(|parameter| parameter.trim().to_string()).collect()
+}
+
+//parses palette command parameters: path and optional colors count (1-64)
+fn parse_palette_parameters(parameters: &[String]) -> Option<(String, usize)> {
+    match parameters {
+        [path] => Some((path.clone(), DEFAULT_PALETTE_COLORS_COUNT)),
+        [path, colors_count] => {
+            let colors_count = colors_count.parse::<usize>().ok()?;
+
+            if (1..=MAX_PALETTE_COLORS_COUNT).contains(&colors_count) {
+                Some((path.clone(), colors_count))
+            } else {
+                None
+            }
+        },
+        _ => None
+    }
+}
+
+//parses hist command parameters: path and optional "csv" flag (export histogram bins to csv file)
+fn parse_hist_parameters(parameters: &[String]) -> Option<(String, bool)> {
+    match parameters {
+        [path] => Some((path.clone(), false)),
+        [path, flag] if flag == "csv" => Some((path.clone(), true)),
+        _ => None
+    }
+}
+
+//parses is command parameters: image extensions (jpg,png or * -> all formats), then scan directories and options in any order
+fn parse_is_parameters(parameters: &[String]) -> Option<(Vec<String>, ScanOptions)> {
+    let (extensions, other_parameters) = parameters.split_first()?;
+
+    let extensions: Vec<String> = extensions.split(',').map(|extension| extension.trim().to_string()).collect();
+
+    if extensions.iter().any(|extension| extension.is_empty()) {
+        return None
+    }
+
+    return Some((extensions, parse_scan_options(other_parameters)?))
+}
+
+//parses dups command parameters: scan directories (at least one) and options in any order
+fn parse_dups_parameters(parameters: &[String]) -> Option<ScanOptions> {
+    let scan_options = parse_scan_options(parameters)?;
+
+    if scan_options.roots.is_empty() {
+        return None
+    }
+
+    return Some(scan_options)
+}
+
+//parses similar command parameters: scan directories (at least one), threshold=N (1-64, 10 by default) and options in any order
+//threshold is set by key, so numeric directories names (2024) are scanned as directories
+fn parse_similar_parameters(parameters: &[String]) -> Option<(ScanOptions, u32)> {
+    let mut threshold = None;
+    let mut other_parameters = Vec::new();
+
+    for parameter in parameters {
+        match parameter.strip_prefix("threshold=") {
+            Some(value) => {
+                let value = value.parse::<u32>().ok().filter(|value| (1..=MAX_SIMILARITY_THRESHOLD).contains(value))?;
+
+                //threshold can't be set twice
+                if threshold.replace(value).is_some() {
+                    return None
+                }
+            },
+            None => other_parameters.push(parameter.clone())
+        }
+    }
+
+    let threshold = threshold.unwrap_or(DEFAULT_SIMILARITY_THRESHOLD);
+
+    return Some((parse_dups_parameters(&other_parameters)?, threshold))
+}
+
+//parses strip command parameters: image path, then metadata groups to strip (gps, serial), no groups -> all metadata
+fn parse_strip_parameters(parameters: &[String]) -> Option<(String, Vec<ExifStripTarget>)> {
+    let (path, target_names) = parameters.split_first()?;
+    let mut targets = Vec::new();
+
+    for target_name in target_names {
+        let target = ExifStripTarget::from_name(target_name)?;
+
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+    }
+
+    return Some((path.clone(), targets))
+}
+
+//parses exif-set command parameters: image path, then tags values (Tag=Value, at least one) and copy flag in any order
+fn parse_exif_set_parameters(parameters: &[String]) -> Option<ExifSetParameters> {
+    let (path, other_parameters) = parameters.split_first()?;
+
+    let mut edits = Vec::new();
+    let mut save_copy = false;
+
+    for parameter in other_parameters {
+        if parameter == "copy" {
+            save_copy = true;
+            continue
+        }
+
+        let (tag, value) = parameter.split_once('=')?;
+        edits.push((tag.trim().to_string(), value.trim().to_string()));
+    }
+
+    if edits.is_empty() {
+        return None
+    }
+
+    return Some((path.clone(), edits, save_copy))
+}
+
+//parses scan directories and options: depth=N (max directory depth), symlinks (follow symbolic links), hidden (scan hidden files)
+fn parse_scan_options(parameters: &[String]) -> Option<ScanOptions> {
+    let mut scan_options = ScanOptions::default();
+
+    for parameter in parameters {
+        if let Some(depth) = parameter.strip_prefix("depth=") {
+            scan_options.max_depth = Some(depth.parse::<usize>().ok()?);
+        } else if parameter == "symlinks" {
+            scan_options.follow_symlinks = true;
+        } else if parameter == "hidden" {
+            scan_options.include_hidden = true;
+        } else if !parameter.is_empty() {
+            scan_options.roots.push(expand_home_dir(parameter));
+        } else {
+            return None
+        }
+    }
+
+    return Some(scan_options)
+}
+
+//expands "~" at path start to home directory (~/shared -> /home/user/shared)
+fn expand_home_dir(path: &str) -> PathBuf {
+    if let Some(home_dir) = dirs::home_dir() {
+        if path == "~" {
+            return home_dir
+        }
+
+        if let Some(relative_path) = path.strip_prefix("~/") {
+            return home_dir.join(relative_path)
+        }
+    }
+
+    return PathBuf::from(path)
+}
+
+//parses fem command parameters: image path, then export format (txt, json, csv, xmp), output directory (dir=DIR)
+//and filename template (name=TEMPLATE) in any order, directory and template can be set only with export format
+fn parse_fem_parameters(parameters: &[String]) -> Option<(String, Option<ExifExportOptions>)> {
+    let (path, other_parameters) = parameters.split_first()?;
+
+    let mut format = None;
+    let mut directory = None;
+    let mut filename_template = None;
+
+    for parameter in other_parameters {
+        if let Some(directory_path) = parameter.strip_prefix("dir=") {
+            if directory_path.trim().is_empty() || directory.is_some() {
+                return None
+            }
+
+            directory = Some(expand_home_dir(directory_path.trim()));
+        } else if let Some(template) = parameter.strip_prefix("name=") {
+            //template is filename, not path
+            if template.trim().is_empty() || template.contains(['/', '\\']) || filename_template.is_some() {
+                return None
+            }
+
+            filename_template = Some(template.trim().to_string());
+        } else if format.is_none() {
+            format = Some(ExifExportFormat::from_name(parameter)?);
+        } else {
+            return None
+        }
+    }
+
+    let export_options = match format {
+        Some(format) => Some(ExifExportOptions { format, directory, filename_template }),
+        None if directory.is_none() && filename_template.is_none() => None,
+        None => return None
+    };
+
+    return Some((path.clone(), export_options))
+}
+
+//parses gii command parameters: path, then options in any order: "icc" flag (save embedded ICC profile to .icc file)
+//and frames=SELECTION (extract animation frames to png files)
+fn parse_gii_parameters(parameters: &[String]) -> Option<GiiParameters> {
+    let (path, options) = parameters.split_first()?;
+
+    let mut save_icc_profile = false;
+    let mut frames_selection = None;
+
+    for option in options {
+        if option == "icc" && !save_icc_profile {
+            save_icc_profile = true;
+        } else if let Some(selection) = option.strip_prefix("frames=") {
+            if frames_selection.is_some() {
+                return None
+            }
+
+            frames_selection = Some(parse_frame_selection(selection)?);
+        } else {
+            return None
+        }
+    }
+
+    return Some((path.clone(), save_icc_profile, frames_selection))
+}
+
+//parses animation frames selection: "all" or frames numbers and ranges (1,3,5-8), numbers start from 1
+fn parse_frame_selection(text: &str) -> Option<FrameSelection> {
+    if text.trim() == "all" {
+        return Some(FrameSelection::All)
+    }
+
+    let mut numbers = Vec::new();
+
+    for item in text.split(',') {
+        let (first, last) = match item.split_once('-') {
+            Some((first, last)) => (first.trim().parse::<usize>().ok()?, last.trim().parse::<usize>().ok()?),
+            None => {
+                let number = item.trim().parse::<usize>().ok()?;
+
+                (number, number)
+            }
+        };
+
+        if first == 0 || first > last || last > MAX_SELECTED_FRAME_NUMBER {
+            return None
+        }
+
+        numbers.extend(first..=last);
+    }
+
+    numbers.sort_unstable();
+    numbers.dedup();
+
+    return Some(FrameSelection::Numbers(numbers))
+}
+
+//parses conv command parameters: path, target format (png, jpeg, webp, bmp, tiff, gif, ico, avif), then options
+//in any order: quality=N (1-100, JPEG and AVIF only) and exif=keep|strip (strip by default)
+fn parse_conv_parameters(parameters: &[String]) -> Option<ConvParameters> {
+    let [path, format_name, options @ ..] = parameters else {
+        return None
+    };
+
+    let target_format = ConversionFormat::from_name(format_name)?;
+
+    let mut quality = None;
+    let mut keep_exif = None;
+
+    for option in options {
+        if let Some(value) = option.strip_prefix("quality=") {
+            let value = value.trim().parse::<u8>().ok()?;
+
+            if quality.is_some() || target_format.default_quality().is_none() || !(1..=100).contains(&value) {
+                return None
+            }
+
+            quality = Some(value);
+        } else if let Some(value) = option.strip_prefix("exif=") {
+            if keep_exif.is_some() {
+                return None
+            }
+
+            keep_exif = match value.trim() {
+                "keep" => Some(true),
+                "strip" => Some(false),
+                _ => return None
+            };
+        } else {
+            return None
+        }
+    }
+
+    return Some((path.clone(), target_format, ConversionOptions { quality, keep_exif: keep_exif.unwrap_or(false) }))
+}
+
+//parses batch command parameters: input directory, output directory, then options in any order: format=FORMAT (target format),
+//resize=WxH, mode=fit|fill|exact (fit by default), filter=nearest|triangle|catmullrom|gaussian|lanczos (lanczos by default),
+//quality=N (1-100, JPEG and AVIF only), types=jpg,png (converted images extensions), update (skip up to date images)
+//and scan options (depth=N; symlinks; hidden), target format or resize size is required
+fn parse_batch_parameters(parameters: &[String]) -> Option<BatchParameters> {
+    let [input_dir, output_dir, options @ ..] = parameters else {
+        return None
+    };
+
+    if input_dir.is_empty() || output_dir.is_empty() {
+        return None
+    }
+
+    let mut format = None;
+    let mut resize_size = None;
+    let mut mode = None;
+    let mut filter = None;
+    let mut quality = None;
+    let mut extensions = Vec::new();
+    let mut skip_up_to_date = false;
+    let mut scan_parameters = Vec::new();
+
+    for option in options {
+        if let Some(value) = option.strip_prefix("format=") {
+            if format.is_some() {
+                return None
+            }
+
+            format = Some(ConversionFormat::from_name(value)?);
+        } else if let Some(value) = option.strip_prefix("resize=") {
+            if resize_size.is_some() {
+                return None
+            }
+
+            resize_size = Some(parse_resize_size(value)?);
+        } else if let Some(value) = option.strip_prefix("mode=") {
+            if mode.is_some() {
+                return None
+            }
+
+            mode = Some(ResizeMode::from_name(value)?);
+        } else if let Some(value) = option.strip_prefix("filter=") {
+            if filter.is_some() {
+                return None
+            }
+
+            filter = Some(ResizeFilter::from_name(value)?);
+        } else if let Some(value) = option.strip_prefix("quality=") {
+            let value = value.trim().parse::<u8>().ok()?;
+
+            if quality.is_some() || !(1..=100).contains(&value) {
+                return None
+            }
+
+            quality = Some(value);
+        } else if let Some(value) = option.strip_prefix("types=") {
+            extensions = value.split(',').map(|extension| extension.trim().to_string()).collect();
+
+            if extensions.iter().any(|extension| extension.is_empty()) {
+                return None
+            }
+        } else if option == "update" {
+            skip_up_to_date = true;
+        } else if option.starts_with("depth=") || option == "symlinks" || option == "hidden" {
+            scan_parameters.push(option.clone());
+        } else {
+            return None
+        }
+    }
+
+    //mode and filter are resize options, quality is ignored by lossless target format
+    let resize = match resize_size {
+        Some((width, height)) => Some(
+            ResizeOptions {
+                width,
+                height,
+                mode: mode.unwrap_or(ResizeMode::Fit),
+                filter: filter.unwrap_or(ResizeFilter::Lanczos)
+            }
+        ),
+        None if mode.is_none() && filter.is_none() => None,
+        None => return None
+    };
+
+    if (format.is_none() && resize.is_none()) || (quality.is_some() && format.is_some_and(|format| format.default_quality().is_none())) {
+        return None
+    }
+
+    return Some(
+        (
+            expand_home_dir(input_dir),
+            expand_home_dir(output_dir),
+            BatchOptions { format, resize, quality, extensions, skip_up_to_date },
+            parse_scan_options(&scan_parameters)?
+        )
+    )
+}
+
+//parses resize size: width and height (800x600), 1-16384 pixels
+fn parse_resize_size(text: &str) -> Option<(u32, u32)> {
+    let text = text.trim().to_lowercase();
+    let (width, height) = text.split_once('x')?;
+
+    let width = width.trim().parse::<u32>().ok()?;
+    let height = height.trim().parse::<u32>().ok()?;
+
+    if !(1..=MAX_RESIZE_SIZE).contains(&width) || !(1..=MAX_RESIZE_SIZE).contains(&height) {
+        return None
+    }
+
+    return Some((width, height))
+}
+
+//parses sheet command parameters: directory, then optional columns count (1-32, 6 by default) and thumbnails size
+//(32-1024 pixels, 160 by default) numbers in this order, and scan options (depth=N; symlinks; hidden)
+fn parse_sheet_parameters(parameters: &[String]) -> Option<SheetParameters> {
+    let (directory, other_parameters) = parameters.split_first()?;
+
+    if directory.is_empty() {
+        return None
+    }
+
+    let mut numbers = Vec::new();
+    let mut scan_parameters = Vec::new();
+
+    for parameter in other_parameters {
+        match parameter.parse::<u32>() {
+            Ok(number) => numbers.push(number),
+            Err(_) if parameter.starts_with("depth=") || parameter == "symlinks" || parameter == "hidden" => scan_parameters.push(parameter.clone()),
+            Err(_) => return None
+        }
+    }
+
+    let (columns, thumbnail_size) = match numbers[..] {
+        [] => (DEFAULT_SHEET_COLUMNS, DEFAULT_SHEET_THUMBNAIL_SIZE),
+        [columns] => (columns, DEFAULT_SHEET_THUMBNAIL_SIZE),
+        [columns, thumbnail_size] => (columns, thumbnail_size),
+        _ => return None
+    };
+
+    if !(1..=MAX_SHEET_COLUMNS).contains(&columns) || !(MIN_SHEET_THUMBNAIL_SIZE..=MAX_SHEET_THUMBNAIL_SIZE).contains(&thumbnail_size) {
+        return None
+    }
+
+    return Some(
+        (
+            expand_home_dir(directory),
+            ContactSheetOptions { columns, thumbnail_size },
+            parse_scan_options(&scan_parameters)?
+        )
+    )
+}
@@ -1,3 +1,3 @@
 //main_mechanics app directory public modules
 pub mod command_parser;
-pub mod image_manager;
\ No newline at end of file
+pub mod output_files_manager;
\ No newline at end of file
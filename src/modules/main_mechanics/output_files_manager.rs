@@ -0,0 +1,624 @@
+/*
+======================================================================
+| This module creates app output files (command results saved to     |
+| files). Files are created in output directory: entered directory,  |
+| IMG_INFO_OUTPUT_DIR environment variable directory, downloads      |
+| directory or current directory (first available). Existing files   |
+| aren't overwritten, "_2", "_3"... suffix is added to filename.     |
+| used libs: [dirs-downloads directory, serde_json-JSON export]      |
+| {Structs:}                                                         |
+| 1) ExifExportOptions - fem command export format and destination   |
+| {Enums:}                                                           |
+| 1) ExifExportFormat - Exif metadata export file formats            |
+| 2) XmpValueKind - XMP sidecar property value kind                  |
+| {Functions:}                                                       |
+| 1) generate_filename() - generates filename from theme and name    |
+| 2) get_file_stem() - gets filename without extension               |
+| 3) sanitize_filename() - replaces not allowed filename characters  |
+| 4) expand_filename_template() - expands export filename template   |
+| 5) save_exif_report() - saves Exif metadata to txt, json, csv or   |
+| xmp file                                                           |
+| 5.1) get_exif_txt() - formats Exif entries as text lines           |
+| 5.2) get_exif_csv() - formats Exif entries as csv rows             |
+| 5.3) get_exif_xmp() - formats Exif metadata as XMP sidecar packet  |
+| 6) escape_csv_field() - quotes csv field with special characters   |
+| 7) escape_xml_text() - escapes XML special characters              |
+| 8) save_histogram_to_csv() - saves histogram bins to csv file      |
+| 9) save_heatmap_to_png() - saves images difference heatmap to png  |
+| 10) save_stripped_image() - saves image copy without Exif metadata |
+| 11) save_edited_image() - saves image copy with edited Exif tags   |
+| 12) save_icc_profile() - saves embedded ICC profile to .icc file   |
+| 13) save_animation_frame() - saves animation frame to png file     |
+| 14) save_converted_image() - saves image converted to other format |
+| 15) save_contact_sheet() - saves directory contact sheet to png    |
+| 16) save_autorotated_image() - saves image copy with applied Exif  |
+| orientation                                                        |
+| 17) overwrite_image_file() - writes edited image to original file  |
+| 18) get_output_directory() - gets directory for output files       |
+| 19) create_output_file() - creates output file with unique name    |
+| and writes contents to it                                          |
+======================================================================
+*/
+
+use dirs;
+
+use image::{ ImageFormat, RgbImage, RgbaImage };
+
+use std::env;
+use std::fs::{ self, OpenOptions };
+use std::io::{ self, Cursor, ErrorKind, Write };
+use std::path::{ Path, PathBuf };
+
+use img_info::image_analysis::image_reports::*;
+
+//default fem export filename template ({name} -> image filename without extension)
+const DEFAULT_EXIF_FILENAME_TEMPLATE: &str = "Exif_meta_{name}";
+
+//environment variable with output files directory (used if directory isn't entered)
+const OUTPUT_DIR_VARIABLE: &str = "IMG_INFO_OUTPUT_DIR";
+
+//Exif entries exported to XMP sidecar: (tag number, XMP property, value kind)
+const XMP_PROPERTIES: &[(u16, &str, XmpValueKind)] = &[
+    (0x010E, "tiff:ImageDescription", XmpValueKind::Text),
+    (0x010F, "tiff:Make", XmpValueKind::Text),
+    (0x0110, "tiff:Model", XmpValueKind::Text),
+    (0x0112, "tiff:Orientation", XmpValueKind::Text),
+    (0x011A, "tiff:XResolution", XmpValueKind::Text),
+    (0x011B, "tiff:YResolution", XmpValueKind::Text),
+    (0x0128, "tiff:ResolutionUnit", XmpValueKind::Text),
+    (0x0131, "tiff:Software", XmpValueKind::Text),
+    (0x0132, "xmp:ModifyDate", XmpValueKind::Date),
+    (0x013B, "tiff:Artist", XmpValueKind::Text),
+    (0x8298, "tiff:Copyright", XmpValueKind::Text),
+    (0x829A, "exif:ExposureTime", XmpValueKind::Text),
+    (0x829D, "exif:FNumber", XmpValueKind::Text),
+    (0x8822, "exif:ExposureProgram", XmpValueKind::Text),
+    (0x8827, "exif:ISOSpeedRatings", XmpValueKind::Sequence),
+    (0x9003, "exif:DateTimeOriginal", XmpValueKind::Date),
+    (0x9004, "exif:DateTimeDigitized", XmpValueKind::Date),
+    (0x9201, "exif:ShutterSpeedValue", XmpValueKind::Text),
+    (0x9202, "exif:ApertureValue", XmpValueKind::Text),
+    (0x9204, "exif:ExposureBiasValue", XmpValueKind::Text),
+    (0x9205, "exif:MaxApertureValue", XmpValueKind::Text),
+    (0x9207, "exif:MeteringMode", XmpValueKind::Text),
+    (0x9208, "exif:LightSource", XmpValueKind::Text),
+    (0x920A, "exif:FocalLength", XmpValueKind::Text),
+    (0xA001, "exif:ColorSpace", XmpValueKind::Text),
+    (0xA002, "exif:PixelXDimension", XmpValueKind::Text),
+    (0xA003, "exif:PixelYDimension", XmpValueKind::Text),
+    (0xA402, "exif:ExposureMode", XmpValueKind::Text),
+    (0xA403, "exif:WhiteBalance", XmpValueKind::Text),
+    (0xA404, "exif:DigitalZoomRatio", XmpValueKind::Text),
+    (0xA405, "exif:FocalLengthIn35mmFilm", XmpValueKind::Text),
+    (0xA406, "exif:SceneCaptureType", XmpValueKind::Text),
+    (0xA420, "exif:ImageUniqueID", XmpValueKind::Text)
+];
+
+//XMP property value kind
+#[derive(Clone, Copy)]
+enum XmpValueKind {
+    Text,
+    Date, //Exif date (YYYY:MM:DD HH:MM:SS) -> ISO 8601 date (YYYY-MM-DDTHH:MM:SS)
+    Sequence //ordered array (rdf:Seq) of space separated values
+}
+
+//Exif metadata export file formats
+#[derive(Clone, Copy, PartialEq)]
+pub enum ExifExportFormat {
+    Txt,
+    Json,
+    Csv,
+    Xmp
+}
+
+impl ExifExportFormat {
+    //export format name, also used as file extension
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExifExportFormat::Txt => "txt",
+            ExifExportFormat::Json => "json",
+            ExifExportFormat::Csv => "csv",
+            ExifExportFormat::Xmp => "xmp"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ExifExportFormat> {
+        return [ExifExportFormat::Txt, ExifExportFormat::Json, ExifExportFormat::Csv, ExifExportFormat::Xmp]
+            .into_iter()
+            .find(|format| format.name() == name.to_lowercase())
+    }
+}
+
+//fem command export format and destination
+pub struct ExifExportOptions {
+    pub format: ExifExportFormat,
+    pub directory: Option<PathBuf>, //None -> default output directory (see get_output_directory)
+    pub filename_template: Option<String> //None -> DEFAULT_EXIF_FILENAME_TEMPLATE
+}
+
+//generates filename from theme and name (Exif_meta + photo.jpg -> Exif_meta_photo.txt)
+fn generate_filename(
+    theme: &str,
+    name: &str,
+    extension: &str
+) -> String {
+    let result = format!(
+        "{}_{}{}",
+        theme,
+        sanitize_filename(name),
+        extension
+    );
+
+    return result
+}
+
+//gets filename without extension (photo.jpg -> photo)
+fn get_file_stem(file_name: &str) -> &str {
+    return Path::new(file_name)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or(file_name)
+}
+
+//replaces path separators, spaces and other not allowed filename characters with "_"
+fn sanitize_filename(name: &str) -> String {
+    return name
+        .chars()
+        .map(|character| {
+            if character.is_control() || character.is_whitespace() || "/\\:*?\"<>|".contains(character) {
+                '_'
+            } else {
+                character
+            }
+        })
+        .collect()
+}
+
+//expands export filename template: {name} -> image filename without extension, {ext} -> image extension, {format} -> export format
+fn expand_filename_template(
+    template: &str,
+    exif_report: &ExifReport,
+    format: ExifExportFormat
+) -> String {
+    let filename = template
+        .replace("{name}", get_file_stem(&exif_report.file_name))
+        .replace("{ext}", &exif_report.file_extension)
+        .replace("{format}", format.name());
+
+    return format!("{}.{}", sanitize_filename(&filename), format.name())
+}
+
+//saves Exif metadata to txt, json, csv or xmp file in output directory, returns output file path
+pub fn save_exif_report(
+    exif_report: &ExifReport,
+    options: &ExifExportOptions
+) -> Result<PathBuf, ImageManagerError> {
+    let exif_data_to_save = match options.format {
+        ExifExportFormat::Txt => get_exif_txt(exif_report),
+        ExifExportFormat::Json => serde_json::to_string_pretty(exif_report).map_err(|error| ImageManagerError::OutputFile(io::Error::other(error)))? + "\n",
+        ExifExportFormat::Csv => get_exif_csv(exif_report),
+        ExifExportFormat::Xmp => get_exif_xmp(exif_report)
+    };
+
+    let template = options.filename_template.as_deref().unwrap_or(DEFAULT_EXIF_FILENAME_TEMPLATE);
+
+    return create_output_file(
+        options.directory.as_deref(),
+        expand_filename_template(template, exif_report, options.format),
+        exif_data_to_save.as_bytes()
+    )
+}
+
+//formats Exif entries as text lines (|-[number]->|tag|\tvalue)
+fn get_exif_txt(exif_report: &ExifReport) -> String {
+    let mut exif_data_to_save = String::new();
+
+    //configuring entry info string
+    for (index, entry) in exif_report.entries.iter().enumerate() {
+        exif_data_to_save.push_str(
+            &format!(
+                "|-[{}]->|{}|\t{}\n",
+                index + 1,
+                entry.tag,
+                entry.readable_value
+            )
+        );
+    }
+
+    return exif_data_to_save
+}
+
+//formats Exif entries as csv rows (ifd,tag_id,tag,raw_value,readable_value)
+fn get_exif_csv(exif_report: &ExifReport) -> String {
+    let mut exif_data_to_save = String::from("ifd,tag_id,tag,raw_value,readable_value\n");
+
+    for entry in &exif_report.entries {
+        exif_data_to_save.push_str(
+            &format!(
+                "{},0x{:04X},{},{},{}\n",
+                escape_csv_field(&entry.ifd),
+                entry.tag_id,
+                escape_csv_field(&entry.tag),
+                escape_csv_field(&entry.raw_value),
+                escape_csv_field(&entry.readable_value)
+            )
+        );
+    }
+
+    return exif_data_to_save
+}
+
+//formats Exif metadata as XMP sidecar packet (tiff, exif and xmp namespaces properties, GPS location from decoded location)
+fn get_exif_xmp(exif_report: &ExifReport) -> String {
+    let mut properties = String::new();
+
+    for (tag_id, property, value_kind) in XMP_PROPERTIES {
+        let Some(entry) = exif_report.entries.iter().find(|entry| entry.tag_id == *tag_id) else {
+            continue
+        };
+
+        let value = entry.raw_value.trim_matches(|character: char| character == '\0' || character.is_whitespace());
+
+        if value.is_empty() {
+            continue
+        }
+
+        let property_value = match value_kind {
+            XmpValueKind::Text => escape_xml_text(value),
+            XmpValueKind::Date => escape_xml_text(&value.replacen(':', "-", 2).replacen(' ', "T", 1)),
+            //sequence items come from typed values (Display string of multi-value entry has no separators)
+            XmpValueKind::Sequence => format!(
+                "<rdf:Seq>{}</rdf:Seq>",
+                entry.raw_values.iter().map(|item| format!("<rdf:li>{}</rdf:li>", escape_xml_text(item))).collect::<String>()
+            )
+        };
+
+        properties.push_str(&format!("   <{}>{}</{}>\n", property, property_value, property));
+    }
+
+    //XMP GPS coordinate format: DDD,MM.mmmmmmK (degrees, decimal minutes, direction)
+    if let Some(location) = &exif_report.location {
+        let coordinate = |degrees: f64, positive_ref: char, negative_ref: char| {
+            let direction = if degrees < 0.0 { negative_ref } else { positive_ref };
+            let degrees = degrees.abs();
+
+            format!("{},{:.6}{}", degrees.trunc(), degrees.fract() * 60.0, direction)
+        };
+
+        properties.push_str(&format!("   <exif:GPSLatitude>{}</exif:GPSLatitude>\n", coordinate(location.latitude, 'N', 'S')));
+        properties.push_str(&format!("   <exif:GPSLongitude>{}</exif:GPSLongitude>\n", coordinate(location.longitude, 'E', 'W')));
+
+        if let Some(altitude) = location.altitude {
+            properties.push_str(
+                &format!(
+                    "   <exif:GPSAltitudeRef>{}</exif:GPSAltitudeRef>\n   <exif:GPSAltitude>{}/100</exif:GPSAltitude>\n",
+                    if altitude < 0.0 { 1 } else { 0 },
+                    (altitude.abs() * 100.0).round()
+                )
+            );
+        }
+
+        if let Some(direction) = location.direction {
+            let direction_ref = if location.direction_ref.as_deref() == Some("magnetic north") { "M" } else { "T" };
+
+            properties.push_str(
+                &format!(
+                    "   <exif:GPSImgDirectionRef>{}</exif:GPSImgDirectionRef>\n   <exif:GPSImgDirection>{}/100</exif:GPSImgDirection>\n",
+                    direction_ref,
+                    (direction * 100.0).round()
+                )
+            );
+        }
+
+        if let Some(timestamp) = location.timestamp.as_ref().filter(|timestamp| timestamp.contains('T')) {
+            properties.push_str(&format!("   <exif:GPSTimeStamp>{}</exif:GPSTimeStamp>\n", timestamp));
+        }
+    }
+
+    let xmp_packet = format!(
+        "<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>
+<x:xmpmeta xmlns:x=\"adobe:ns:meta/\">
+ <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">
+  <rdf:Description rdf:about=\"\"
+    xmlns:tiff=\"http://ns.adobe.com/tiff/1.0/\"
+    xmlns:exif=\"http://ns.adobe.com/exif/1.0/\"
+    xmlns:xmp=\"http://ns.adobe.com/xap/1.0/\">
+{}  </rdf:Description>
+ </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end=\"w\"?>
+",
+        properties
+    );
+
+    return xmp_packet
+}
+
+//quotes csv field if it contains separator, quotes or line breaks (quotes are doubled)
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", field.replace('"', "\"\""))
+    }
+
+    return field.to_string()
+}
+
+//escapes XML special characters (&, <, >, "), control characters (not allowed in XML) are removed
+fn escape_xml_text(text: &str) -> String {
+    return text
+        .chars()
+        .filter(|character| !character.is_control() || *character == '\t' || *character == '\n')
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+//saves histogram bins to csv file in output directory (bin,red,green,blue,luminance rows), returns output file path
+pub fn save_histogram_to_csv(histogram_report: &HistogramReport) -> Result<PathBuf, ImageManagerError> {
+    let mut histogram_data_to_save = String::from("bin,red,green,blue,luminance\n");
+
+    for bin in 0..histogram_report.red.len() {
+        histogram_data_to_save.push_str(
+            &format!(
+                "{},{},{},{},{}\n",
+                bin,
+                histogram_report.red[bin],
+                histogram_report.green[bin],
+                histogram_report.blue[bin],
+                histogram_report.luminance[bin]
+            )
+        );
+    }
+
+    return create_output_file(
+        None,
+        generate_filename(
+            "Histogram",
+            get_file_stem(&histogram_report.file_name),
+            ".csv"
+        ), //filename (csv file)
+        histogram_data_to_save.as_bytes()
+    )
+}
+
+//saves images difference heatmap to png file in output directory, returns output file path
+pub fn save_heatmap_to_png(
+    comparison_report: &ComparisonReport,
+    heatmap: &RgbImage
+) -> Result<PathBuf, ImageManagerError> {
+    let mut png_data = Cursor::new(Vec::new());
+
+    heatmap
+        .write_to(&mut png_data, ImageFormat::Png)
+        .map_err(|error| ImageManagerError::OutputFile(io::Error::other(error)))?;
+
+    return create_output_file(
+        None,
+        generate_filename(
+            "Diff_heatmap",
+            &format!(
+                "{}_vs_{}",
+                get_file_stem(&comparison_report.first_image.file_name),
+                get_file_stem(&comparison_report.second_image.file_name)
+            ),
+            ".png"
+        ), //filename (png file)
+        png_data.get_ref()
+    )
+}
+
+//saves image copy without Exif metadata (same extension as original) in output directory, returns output file path
+pub fn save_stripped_image(
+    strip_report: &ExifStripReport,
+    image_data: &[u8]
+) -> Result<PathBuf, ImageManagerError> {
+    return create_output_file(
+        None,
+        generate_filename(
+            "Stripped",
+            get_file_stem(&strip_report.file_name),
+            &format!(".{}", strip_report.file_extension)
+        ), //filename (original image extension)
+        image_data
+    )
+}
+
+//saves image copy with edited Exif metadata (same extension as original) in output directory, returns output file path
+pub fn save_edited_image(
+    set_report: &ExifSetReport,
+    image_data: &[u8]
+) -> Result<PathBuf, ImageManagerError> {
+    return create_output_file(
+        None,
+        generate_filename(
+            "Exif_edited",
+            get_file_stem(&set_report.file_name),
+            &format!(".{}", set_report.file_extension)
+        ), //filename (original image extension)
+        image_data
+    )
+}
+
+//saves image embedded ICC color profile to .icc file in output directory, returns output file path
+pub fn save_icc_profile(
+    image_info: &ImageInfo,
+    profile_data: &[u8]
+) -> Result<PathBuf, ImageManagerError> {
+    return create_output_file(
+        None,
+        generate_filename(
+            "Icc_profile",
+            get_file_stem(&image_info.file_name),
+            ".icc"
+        ), //filename (icc file)
+        profile_data
+    )
+}
+
+//saves animation frame to png file in output directory (frame number is padded to frames count digits, so files are sorted)
+//returns output file path
+pub fn save_animation_frame(
+    image_info: &ImageInfo,
+    frame_number: usize,
+    frame: &RgbaImage
+) -> Result<PathBuf, ImageManagerError> {
+    let mut png_data = Cursor::new(Vec::new());
+
+    frame
+        .write_to(&mut png_data, ImageFormat::Png)
+        .map_err(|error| ImageManagerError::OutputFile(io::Error::other(error)))?;
+
+    let frames_count = image_info.animation.as_ref().map(|animation| animation.frames_count).unwrap_or(frame_number);
+
+    return create_output_file(
+        None,
+        generate_filename(
+            "Frame",
+            &format!(
+                "{}_{:0width$}",
+                get_file_stem(&image_info.file_name),
+                frame_number,
+                width = frames_count.to_string().len()
+            ),
+            ".png"
+        ), //filename (png file)
+        png_data.get_ref()
+    )
+}
+
+//saves image converted to other format (target format extension) in output directory, returns output file path
+pub fn save_converted_image(
+    conversion_report: &ConversionReport,
+    image_data: &[u8]
+) -> Result<PathBuf, ImageManagerError> {
+    return create_output_file(
+        None,
+        generate_filename(
+            "Converted",
+            get_file_stem(&conversion_report.file_name),
+            &format!(".{}", conversion_report.target_format.extension())
+        ), //filename (target format extension)
+        image_data
+    )
+}
+
+//saves directory images contact sheet to png file in output directory (named after directory), returns output file path
+pub fn save_contact_sheet(
+    contact_sheet_report: &ContactSheetReport,
+    sheet: &RgbaImage
+) -> Result<PathBuf, ImageManagerError> {
+    let mut png_data = Cursor::new(Vec::new());
+
+    sheet
+        .write_to(&mut png_data, ImageFormat::Png)
+        .map_err(|error| ImageManagerError::OutputFile(io::Error::other(error)))?;
+
+    let directory_name = contact_sheet_report.directory
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "root".to_string());
+
+    return create_output_file(
+        None,
+        generate_filename(
+            "Contact_sheet",
+            &directory_name,
+            ".png"
+        ), //filename (png file)
+        png_data.get_ref()
+    )
+}
+
+//saves image copy with Exif orientation applied to pixels (same extension as original) in output directory, returns output file path
+pub fn save_autorotated_image(
+    auto_rotate_report: &AutoRotateReport,
+    image_data: &[u8]
+) -> Result<PathBuf, ImageManagerError> {
+    return create_output_file(
+        None,
+        generate_filename(
+            "Autorotated",
+            get_file_stem(&auto_rotate_report.file_name),
+            &format!(".{}", auto_rotate_report.file_extension)
+        ), //filename (original image extension)
+        image_data
+    )
+}
+
+//writes edited image to original image file (temporary file is renamed, so image isn't broken on write error)
+pub fn overwrite_image_file(
+    path: &str,
+    image_data: &[u8]
+) -> Result<PathBuf, ImageManagerError> {
+    let image_path = PathBuf::from(path.trim());
+    let mut temporary_path = image_path.clone().into_os_string();
+    temporary_path.push(".img_info.tmp");
+
+    let write_result = std::fs::write(&temporary_path, image_data)
+        .and_then(|_| std::fs::set_permissions(&temporary_path, std::fs::metadata(&image_path)?.permissions()))
+        .and_then(|_| std::fs::rename(&temporary_path, &image_path));
+
+    if let Err(error) = write_result {
+        let _ = std::fs::remove_file(&temporary_path);
+        return Err(ImageManagerError::OutputFile(error))
+    }
+
+    return Ok(image_path)
+}
+
+//gets directory for output files: entered directory, IMG_INFO_OUTPUT_DIR directory, downloads directory
+//or current directory (first available), entered and IMG_INFO_OUTPUT_DIR directories are created if they don't exist
+fn get_output_directory(directory: Option<&Path>) -> Result<PathBuf, ImageManagerError> {
+    let configured_directory = directory
+        .map(Path::to_path_buf)
+        .or_else(|| env::var_os(OUTPUT_DIR_VARIABLE).filter(|value| !value.is_empty()).map(PathBuf::from));
+
+    if let Some(output_directory) = configured_directory {
+        fs::create_dir_all(&output_directory).map_err(ImageManagerError::OutputFile)?;
+
+        return Ok(output_directory)
+    }
+
+    if let Some(download_directory) = dirs::download_dir().filter(|download_directory| download_directory.is_dir()) {
+        return Ok(download_directory)
+    }
+
+    return env::current_dir().map_err(ImageManagerError::OutputFile)
+}
+
+//creates output file in output directory (None -> default output directory) and writes contents to it
+//existing files aren't overwritten: "_2", "_3"... suffix is added to filename, returns output file path
+fn create_output_file(
+    directory: Option<&Path>,
+    filename: String,
+    contents: &[u8]
+) -> Result<PathBuf, ImageManagerError> {
+    let output_directory = get_output_directory(directory)?;
+
+    let filename_path = Path::new(&filename);
+    let stem = filename_path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+    let extension = filename_path.extension().map(|extension| format!(".{}", extension.to_string_lossy())).unwrap_or_default();
+
+    let mut index = 1;
+
+    loop {
+        let output_file_path = if index == 1 {
+            output_directory.join(&filename)
+        } else {
+            output_directory.join(format!("{}_{}{}", stem, index, extension))
+        };
+
+        //creating output file (only if it doesn't exist) and writing contents
+        match OpenOptions::new().write(true).create_new(true).open(&output_file_path) {
+            Ok(mut output_file) => {
+                output_file.write_all(contents).map_err(ImageManagerError::OutputFile)?;
+
+                return Ok(output_file_path)
+            },
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => index += 1,
+            Err(error) => return Err(ImageManagerError::OutputFile(error))
+        }
+    }
+}